@@ -0,0 +1,32 @@
+//! Compares lookups with the default SipHash `HashMap` against the `FxHashMap` used by `DSAPI`.
+//! Keys mimic the class/member names of a large UE dump.
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rustc_hash::FxHashMap;
+
+fn member_keys() -> Vec<String> {
+    (0..2000)
+        .flat_map(|class| (0..40).map(move |member| format!("UFortClass{}MemberProperty{}", class, member)))
+        .collect()
+}
+
+fn bench_member_lookup(c: &mut Criterion) {
+    let keys = member_keys();
+    let sip: HashMap<String, u64> = keys.iter().cloned().zip(0..).collect();
+    let fx: FxHashMap<String, u64> = keys.iter().cloned().zip(0..).collect();
+    let probes: Vec<String> = keys.iter().step_by(997).cloned().collect();
+
+    let mut group = c.benchmark_group("member_lookup");
+    group.bench_function("siphash", |b| {
+        b.iter(|| probes.iter().map(|key| sip.get(black_box(key.as_str())).copied()).sum::<Option<u64>>())
+    });
+    group.bench_function("fxhash", |b| {
+        b.iter(|| probes.iter().map(|key| fx.get(black_box(key.as_str())).copied()).sum::<Option<u64>>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_member_lookup);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+fn main() {
+    // `network` is set when there is a blocking HTTP client to download with.
+    println!("cargo::rustc-check-cfg=cfg(network)");
+    let wasm32 = std::env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|arch| arch == "wasm32");
+    let client = std::env::var_os("CARGO_FEATURE_BLOCKING").is_some() || std::env::var_os("CARGO_FEATURE_MINIMAL_HTTP").is_some();
+    if client && !wasm32 {
+        println!("cargo::rustc-cfg=network");
+    }
+
+    // Generates the C header into `OUT_DIR`, and into `include/` only when asked to with
+    // `DUMPSPACE_API_UPDATE_HEADER=1`, so building never writes into the source tree.
+    #[cfg(feature = "ffi")]
+    {
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        println!("cargo:rerun-if-env-changed=DUMPSPACE_API_UPDATE_HEADER");
+        let mut config = cbindgen::Config::from_file(std::path::Path::new(&crate_dir).join("cbindgen.toml"))
+            .expect("Failed to read cbindgen.toml");
+        if client && !wasm32 {
+            config.after_includes = Some("\n#define DUMPSPACE_API_NETWORK".to_string());
+        }
+        let bindings = cbindgen::Builder::new()
+            .with_crate(&crate_dir)
+            .with_config(config)
+            .generate()
+            .expect("Failed to generate the C header");
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+        bindings.write_to_file(std::path::Path::new(&out_dir).join("dumpspace_api.h"));
+        if std::env::var_os("DUMPSPACE_API_UPDATE_HEADER").is_some() {
+            bindings.write_to_file(std::path::Path::new(&crate_dir).join("include/dumpspace_api.h"));
+        }
+    }
+}
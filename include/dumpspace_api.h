@@ -0,0 +1,119 @@
+#ifndef DUMPSPACE_API_H
+#define DUMPSPACE_API_H
+
+/* Generated by cbindgen from src/ffi.rs with the `ffi` feature, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define DUMPSPACE_API_NETWORK
+
+/**
+ * The main struct for the Dumpspace API, which provides methods to interact with the Dumpspace data.
+ * It must be initialized with a specific game ID (hash) and then must call `download_content` to fetch and parse the data.
+ * # Example:
+ * ```
+ * use dumpspace_api::DSAPI;
+ * let game_id = "6b77eceb"; // Example game ID, replace with actual game hash
+ * let mut dsapi = DSAPI::new(game_id, None); // Optional cache path
+ * dsapi.download_content().unwrap(); // Download and parse the content (if this fails you're screwed anyways so might as well unwrap)
+ * println!("{:?}", dsapi.get_member_offset("UWorld", "OwningGameInstance"));
+ * println!("{:?}", dsapi.get_enum_name("EFortRarity", 4));
+ * println!("0x{:x?}", dsapi.get_class_size("AActor").unwrap());
+ * println!("0x{:x?}", dsapi.get_offset("OFFSET_GWORLD").unwrap());
+ * ```
+ * `DSAPI` is `Send + Sync`, so a parsed dump can be shared between threads behind an `Arc`.
+ * To swap in newer dumps while other threads keep reading, use [`SharedDsApi`].
+ */
+typedef struct DsApi DsApi;
+
+/**
+ * The offset info of a class member, see `ds_get_member_offset`.
+ */
+typedef struct DsOffsetInfo {
+  int64_t offset;
+  int64_t size;
+  bool is_bit;
+  int32_t bit_offset;
+} DsOffsetInfo;
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns the error of the last call that failed on this thread, or null.
+ * The string is valid until the next failing call on this thread.
+ */
+const char *ds_last_error(void);
+
+#if defined(DUMPSPACE_API_NETWORK)
+/**
+ * Creates a `DSAPI` for a game ID, with an optional cache directory (null for no caching).
+ * Returns null on failure. Free it with `ds_free`.
+ *
+ * # Safety
+ * `game_id` must be a NUL-terminated string, `cache_dir` null or a NUL-terminated string.
+ */
+struct DsApi *ds_new(const char *game_id, const char *cache_dir);
+#endif
+
+#if defined(DUMPSPACE_API_NETWORK)
+/**
+ * Downloads (or restores from the cache) the dump, see `DSAPI::download_content`.
+ *
+ * # Safety
+ * `ds` must come from `ds_new` and not be freed.
+ */
+bool ds_download(struct DsApi *ds);
+#endif
+
+/**
+ * Looks up a class member and writes its offset info to `out`. Returns `false` if it is not found.
+ *
+ * # Safety
+ * `ds` must come from `ds_new` and not be freed, the names must be NUL-terminated strings
+ * and `out` must point to a writable `DsOffsetInfo`.
+ */
+bool ds_get_member_offset(const struct DsApi *ds,
+                          const char *class_name,
+                          const char *member_name,
+                          struct DsOffsetInfo *out);
+
+/**
+ * Returns the size of a class, or -1 if it is not found.
+ *
+ * # Safety
+ * `ds` must come from `ds_new` and not be freed, `class_name` must be a NUL-terminated string.
+ */
+int32_t ds_get_class_size(const struct DsApi *ds, const char *class_name);
+
+/**
+ * Looks up a global offset (e.g. `OFFSET_GWORLD`) and writes it to `out`. Returns `false` if it is not found.
+ *
+ * # Safety
+ * `ds` must come from `ds_new` and not be freed, `offset_name` must be a NUL-terminated string
+ * and `out` must point to a writable `uint64_t`.
+ */
+bool ds_get_offset(const struct DsApi *ds,
+                   const char *offset_name,
+                   uint64_t *out);
+
+/**
+ * Frees a `DSAPI` created with `ds_new`. Does nothing for null.
+ *
+ * # Safety
+ * `ds` must be null or come from `ds_new`, and must not be used afterwards.
+ */
+void ds_free(struct DsApi *ds);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DUMPSPACE_API_H */
@@ -0,0 +1,58 @@
+use crate::DSAPI;
+
+impl DSAPI {
+    /// Registers other names a class member is known by, so [`DSAPI::get_member_offset`] finds it under any of them,
+    /// e.g. when a game renames a member between versions.
+    /// Aliases are kept across `download_content` and `refresh`.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.add_member_alias("UWorld", "OwningGameInstance", &["GameInstance", "OwningGI"]);
+    /// // Works whichever of the three names the current dump uses.
+    /// println!("{:?}", dsapi.get_member_offset("UWorld", "GameInstance"));
+    /// ```
+    pub fn add_member_alias(&mut self, class_name: &str, member_name: &str, aliases: &[&str]) {
+        let groups = self.member_aliases.entry(class_name.to_string()).or_default();
+        let names = std::iter::once(member_name).chain(aliases.iter().copied());
+        let group = match groups.iter().position(|group| names.clone().any(|name| group.iter().any(|known| known == name))) {
+            Some(index) => &mut groups[index],
+            None => {
+                groups.push(Vec::new());
+                groups.last_mut().unwrap()
+            }
+        };
+        for name in names {
+            if !group.iter().any(|known| known == name) {
+                group.push(name.to_string());
+            }
+        }
+    }
+    /// Returns the other names registered for a class member with [`DSAPI::add_member_alias`].
+    pub fn member_aliases(&self, class_name: &str, member_name: &str) -> impl Iterator<Item = &str> {
+        self.member_aliases.get(class_name)
+            .and_then(|groups| groups.iter().find(|group| group.iter().any(|name| name == member_name)))
+            .into_iter()
+            .flatten()
+            .map(|name| name.as_str())
+            .filter(move |name| *name != member_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_member_alias() {
+        let mut dsapi = fixture_dsapi();
+        assert!(dsapi.get_member_offset("UWorld", "GameInstance").is_none());
+        dsapi.add_member_alias("UWorld", "GameInstance", &["OwningGI"]);
+        dsapi.add_member_alias("UWorld", "OwningGI", &["OwningGameInstance"]);
+        assert_eq!(dsapi.get_member_offset("UWorld", "GameInstance").unwrap().offset, 0x228);
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGI").unwrap().offset, 0x228);
+        assert_eq!(dsapi.member_aliases("UWorld", "OwningGI").collect::<Vec<_>>(), ["GameInstance", "OwningGameInstance"]);
+        assert!(dsapi.get_member_offset("AActor", "GameInstance").is_none());
+    }
+}
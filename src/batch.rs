@@ -0,0 +1,63 @@
+//! Resolving many members in one call.
+
+use crate::{DSAPI, OffsetInfo};
+
+impl DSAPI {
+    /// Looks up several members at once, returning the results in the same order as `(class, member)` pairs.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let offsets = dsapi.get_member_offsets(&[("UWorld", "OwningGameInstance"), ("UGameInstance", "LocalPlayers")]);
+    /// println!("{:?}", offsets);
+    /// ```
+    pub fn get_member_offsets(&self, members: &[(&str, &str)]) -> Vec<Option<OffsetInfo>> {
+        members.iter().map(|(class_name, member_name)| self.get_member_offset(class_name, member_name)).collect()
+    }
+    /// Looks up several members at once for filling a struct of offsets during initialization.
+    /// Fails if any of them is missing, naming all missing members instead of just the first.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// struct Offsets { game_instance: i64, local_players: i64 }
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let [game_instance, local_players] = dsapi.require_member_offsets([
+    ///     ("UWorld", "OwningGameInstance"),
+    ///     ("UGameInstance", "LocalPlayers"),
+    /// ]).unwrap();
+    /// let offsets = Offsets { game_instance: game_instance.offset, local_players: local_players.offset };
+    /// ```
+    pub fn require_member_offsets<const N: usize>(&self, members: [(&str, &str); N]) -> Result<[OffsetInfo; N], String> {
+        let mut missing = Vec::new();
+        let found: Vec<OffsetInfo> = members.iter()
+            .filter_map(|(class_name, member_name)| {
+                let info = self.get_member_offset(class_name, member_name);
+                if info.is_none() {
+                    missing.push(format!("{}::{}", class_name, member_name));
+                }
+                info
+            })
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!("Members not found: {}", missing.join(", ")));
+        }
+        Ok(found.try_into().unwrap_or_else(|_| unreachable!("every member was found")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_batched_lookups() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let offsets = dsapi.get_member_offsets(&[("UWorld", "OwningGameInstance"), ("UWorld", "Nope"), ("FVector", "Z")]);
+        assert_eq!(offsets.iter().map(|info| info.as_ref().map(|info| info.offset)).collect::<Vec<_>>(), [Some(0x228), None, Some(16)]);
+
+        let [game_instance, level] = dsapi.require_member_offsets([("UWorld", "OwningGameInstance"), ("UWorld", "PersistentLevel")]).unwrap();
+        assert_eq!((game_instance.offset, level.offset), (0x228, 0x30));
+        let error = dsapi.require_member_offsets([("UWorld", "Nope"), ("FVector", "Z"), ("UMissing", "Member")]).unwrap_err();
+        assert_eq!(error, "Members not found: UWorld::Nope, UMissing::Member");
+    }
+}
@@ -0,0 +1,107 @@
+//! `cargo dumpspace`, built with the `cli` and `toml` features. Regenerates a project's offsets module
+//! from the `dumpspace.toml` next to its `Cargo.toml`:
+//! ```toml
+//! game = "6b77eceb"
+//! output = "src/offsets.rs"
+//! # Optional, relative to dumpspace.toml like `output`.
+//! cache_dir = "target/dumpspace"
+//!
+//! [codegen]
+//! classes = ["UGameInstance"]
+//! offsets = ["OFFSET_GWORLD"]
+//!
+//! [codegen.members]
+//! UWorld = ["PersistentLevel", "OwningGameInstance"]
+//! ```
+
+#[cfg(not(network))]
+compile_error!("cargo-dumpspace needs the `reqwest` or `minimal-http` feature to download dumps");
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser};
+use dumpspace_api::{CodegenSpec, DSAPI};
+use serde_derive::Deserialize;
+
+/// Cargo runs `cargo-dumpspace dumpspace <args>` for `cargo dumpspace <args>`.
+#[derive(Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum Cargo {
+    /// Regenerate the offsets module described by dumpspace.toml from the game's latest dump.
+    #[command(version)]
+    Dumpspace(DumpspaceArgs),
+}
+
+#[derive(Args)]
+struct DumpspaceArgs {
+    /// Path to the config, by default the first dumpspace.toml in the current directory or its parents.
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Don't write anything, fail if the output is not up to date (e.g. in CI).
+    #[arg(long)]
+    check: bool,
+}
+
+#[derive(Deserialize)]
+struct Config {
+    /// Game ID (the `hash` in the URL of the game's dumpspace page).
+    game: String,
+    /// The generated module.
+    output: PathBuf,
+    cache_dir: Option<PathBuf>,
+    #[serde(default)]
+    codegen: CodegenSpec,
+}
+
+fn find_config() -> Result<PathBuf, String> {
+    let current = std::env::current_dir().map_err(|e| format!("Failed to get current directory: {}", e))?;
+    current.ancestors()
+        .map(|dir| dir.join("dumpspace.toml"))
+        .find(|path| path.is_file())
+        .ok_or_else(|| "No dumpspace.toml found in the current directory or its parents".to_string())
+}
+
+fn run(args: &DumpspaceArgs) -> Result<(), String> {
+    let config_path = match &args.config {
+        Some(path) => path.clone(),
+        None => find_config()?,
+    };
+    let serialized = std::fs::read_to_string(&config_path).map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
+    let config: Config = toml::from_str(&serialized).map_err(|e| format!("Failed to parse {}: {}", config_path.display(), e))?;
+    let root = config_path.parent().unwrap_or(Path::new("."));
+
+    let mut builder = DSAPI::builder().game_id(&config.game);
+    if let Some(cache_dir) = &config.cache_dir {
+        builder = builder.cache_dir(root.join(cache_dir));
+    }
+    let mut dsapi = builder.build()?;
+    dsapi.download_content()?;
+    let module = dsapi.export_offsets_module(&config.codegen)?;
+
+    let output = root.join(&config.output);
+    if std::fs::read_to_string(&output).is_ok_and(|current| current == module) {
+        println!("{} is up to date", output.display());
+        return Ok(());
+    }
+    if args.check {
+        return Err(format!("{} is out of date, run `cargo dumpspace` to regenerate it", output.display()));
+    }
+    if let Some(parent) = output.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(&output, module).map_err(|e| format!("Failed to write {}: {}", output.display(), e))?;
+    println!("Wrote {} from {} ({})", output.display(), dsapi.game().name, dsapi.game().uploaded);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let Cargo::Dumpspace(args) = Cargo::parse();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
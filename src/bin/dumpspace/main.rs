@@ -0,0 +1,464 @@
+//! The `dumpspace` command line tool, built with the `cli` feature.
+
+#[cfg(not(network))]
+compile_error!("The dumpspace CLI needs the `reqwest` or `minimal-http` feature to download dumps");
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use dumpspace_api::{CSharpStyle, DSAPI, DumpDiff, Game, GameList, Webhook};
+use serde_json::json;
+
+mod output;
+
+use output::{Format, Record, env_name, query_record, shell_quote};
+
+#[derive(Parser)]
+#[command(name = "dumpspace", version, about = "Query Unreal Engine offsets from dumpspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How to print results, for scripts and build systems, e.g. `eval $(dumpspace get ... --format env)`.
+    #[arg(long, global = true, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Browse the classes, enums and offsets of a game interactively (needs the `tui` feature).
+    Browse(GameArgs),
+    /// Export global offsets and classes as C# source for external tools.
+    Csharp(CsharpArgs),
+    /// List the games on dumpspace with their IDs, to find the `--game` to pass to other commands.
+    ListGames(ListGamesArgs),
+    /// Look up `Class::Member`, `Class::Function`, `Enum::4`, `Enum::Name`, a class size or a global offset.
+    Get(GetArgs),
+    /// Download and cache the dumps of several games, e.g. before going offline.
+    Prefetch(PrefetchArgs),
+    /// Load a game once and answer lookups and searches typed at a prompt.
+    Repl(GameArgs),
+    /// Answer lookups over HTTP as JSON, e.g. `/member/UWorld/OwningGameInstance` (needs the `serve` feature).
+    Serve(ServeArgs),
+    /// Wait for new dumps of a game and run a command whenever one is uploaded.
+    Watch(WatchArgs),
+}
+
+/// Selects the game and where its dump is cached.
+#[derive(Args)]
+struct GameArgs {
+    /// Game ID (the `hash` in the URL of the game's dumpspace page).
+    #[arg(long, short)]
+    game: String,
+    /// Directory to cache the dump in, so later runs start instantly.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+impl GameArgs {
+    fn load(&self) -> Result<DSAPI, String> {
+        let mut builder = DSAPI::builder().game_id(&self.game);
+        if let Some(cache_dir) = &self.cache_dir {
+            builder = builder.cache_dir(cache_dir);
+        }
+        let mut dsapi = builder.build()?;
+        dsapi.download_content()?;
+        Ok(dsapi)
+    }
+}
+
+#[derive(Args)]
+struct CsharpArgs {
+    #[command(flatten)]
+    game: GameArgs,
+    /// Class to export, can be repeated.
+    #[arg(long = "class")]
+    classes: Vec<String>,
+    /// Emit `[StructLayout(LayoutKind.Explicit)]` structs instead of static classes of offsets.
+    #[arg(long)]
+    structs: bool,
+    /// File to write the source to instead of stdout.
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct GetArgs {
+    #[command(flatten)]
+    game: GameArgs,
+    /// What to look up, e.g. `UWorld::OwningGameInstance`, `EFortRarity::4`, `UWorld` or `OFFSET_GWORLD`.
+    #[arg(required = true)]
+    queries: Vec<String>,
+}
+
+#[derive(Args)]
+struct ListGamesArgs {
+    /// Only games using this engine, e.g. `Unreal-Engine-5`.
+    #[arg(long)]
+    engine: Option<String>,
+    /// Only games whose name matches, ignoring case, spaces and punctuation.
+    #[arg(long)]
+    name: Option<String>,
+    /// Only games with a dump uploaded within this time, e.g. `7d`, `12h` or `30m`.
+    #[arg(long, value_parser = parse_duration)]
+    updated_within: Option<Duration>,
+    /// Order of the list.
+    #[arg(long, value_enum, default_value_t = SortBy::Uploaded)]
+    sort: SortBy,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SortBy {
+    /// Newest dump first.
+    Uploaded,
+    Name,
+    Engine,
+}
+
+/// Parses a duration like `90s`, `30m`, `12h`, `7d` or `2w`.
+fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let split = duration.find(|c: char| !c.is_ascii_digit()).unwrap_or(duration.len());
+    let (count, unit) = duration.split_at(split);
+    let count: u64 = count.parse().map_err(|_| format!("Invalid duration {}, expected e.g. 7d", duration))?;
+    let unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("Invalid unit in {}, expected s, m, h, d or w", duration)),
+    };
+    let secs = count.checked_mul(unit).ok_or_else(|| format!("Invalid duration {}, expected e.g. 7d", duration))?;
+    Ok(Duration::from_secs(secs))
+}
+
+/// Formats an age in its largest whole unit, e.g. `3d ago`.
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", secs / 60),
+        3600..86400 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86400),
+    }
+}
+
+#[derive(Args)]
+struct PrefetchArgs {
+    /// Game IDs (the `hash` in the URL of the games' dumpspace pages).
+    #[arg(required = true)]
+    games: Vec<String>,
+    /// Directory to cache the dumps in, with a subdirectory per game.
+    #[arg(long)]
+    cache_dir: PathBuf,
+    /// Number of games to download at the same time.
+    #[arg(long, default_value_t = 4)]
+    concurrency: usize,
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    #[command(flatten)]
+    game: GameArgs,
+    /// Port to listen on.
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+    /// Address to listen on. Only this machine can connect by default.
+    #[arg(long, default_value = "127.0.0.1")]
+    bind: String,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Game ID (the `hash` in the URL of the game's dumpspace page).
+    #[arg(long, short)]
+    game: String,
+    /// Command to run through the shell when a new dump is uploaded, e.g. "cargo build".
+    /// It gets the game ID and upload timestamp in `DUMPSPACE_GAME` and `DUMPSPACE_UPLOADED`.
+    #[arg(long)]
+    exec: Option<String>,
+    /// Discord or Slack incoming webhook URL to post a message to when a new dump is uploaded.
+    #[arg(long)]
+    webhook: Option<String>,
+    /// Download the dump and report what changed in each new one, in the output and the webhook message.
+    #[arg(long)]
+    diff: bool,
+    /// Seconds between checks of the game list.
+    #[arg(long, default_value_t = 300)]
+    interval: u64,
+}
+
+fn browse(args: &GameArgs, format: Format) -> Result<(), String> {
+    format.require_plain("browse")?;
+    #[cfg(feature = "tui")]
+    return args.load()?.browse();
+    #[cfg(not(feature = "tui"))]
+    {
+        let _ = args;
+        Err("dumpspace was built without the `tui` feature".to_string())
+    }
+}
+
+fn csharp(args: &CsharpArgs, format: Format) -> Result<(), String> {
+    format.require_plain("csharp")?;
+    let dsapi = args.game.load()?;
+    let classes: Vec<&str> = args.classes.iter().map(String::as_str).collect();
+    let style = if args.structs { CSharpStyle::ExplicitStructs } else { CSharpStyle::StaticOffsets };
+    let source = dsapi.export_csharp(&classes, style);
+    match &args.output {
+        Some(output) => std::fs::write(output, source).map_err(|e| format!("Failed to write {}: {}", output.display(), e)),
+        None => {
+            print!("{}", source);
+            Ok(())
+        }
+    }
+}
+
+fn get(args: &GetArgs, format: Format) -> Result<(), String> {
+    let dsapi = args.game.load()?;
+    for query in &args.queries {
+        format.print(&query_record(&dsapi.query(query)?))?;
+    }
+    Ok(())
+}
+
+fn list_games(args: &ListGamesArgs, format: Format) -> Result<(), String> {
+    let list = GameList::init()?;
+    let mut games: Vec<&Game> = match &args.name {
+        Some(name) => list.search(name),
+        None => list.iter().collect(),
+    };
+    games.retain(|game| args.engine.as_ref().is_none_or(|engine| game.engine.eq_ignore_ascii_case(engine)));
+    games.retain(|game| args.updated_within.is_none_or(|within| game.upload_age() <= within));
+    match args.sort {
+        SortBy::Uploaded => games.sort_by_key(|game| std::cmp::Reverse(game.uploaded_secs())),
+        SortBy::Name => games.sort_by_key(|game| game.name.to_lowercase()),
+        SortBy::Engine => games.sort_by(|a, b| (&a.engine, a.name.to_lowercase()).cmp(&(&b.engine, b.name.to_lowercase()))),
+    }
+    for game in games {
+        format.print(&Record {
+            plain: format!("{:<10} {:<40} {:<20} {}", game.hash, game.name, game.engine, format_age(game.upload_age())),
+            json: json!({ "hash": game.hash, "name": game.name, "engine": game.engine, "uploaded": game.uploaded_secs() }),
+            env: vec![(env_name(&[&game.name]), shell_quote(&game.hash))],
+            number: None,
+        })?;
+    }
+    Ok(())
+}
+
+fn prefetch(args: &PrefetchArgs, format: Format) -> Result<(), String> {
+    let games: Vec<&str> = args.games.iter().map(String::as_str).collect();
+    let manager = DSAPI::builder().cache_dir(&args.cache_dir).build_manager()?;
+    manager.prefetch_all_with_progress(&games, args.concurrency, |progress| {
+        let status = progress.error.as_deref().unwrap_or("ok");
+        eprintln!("[{}/{}] {}: {}", progress.completed, progress.total, progress.game_id, status);
+    })?;
+    let cache_dir = args.cache_dir.display().to_string();
+    format.print(&Record {
+        plain: format!("Cached {} games in {}", games.len(), cache_dir),
+        json: json!({ "games": games, "cache_dir": cache_dir }),
+        env: vec![("DUMPSPACE_CACHE_DIR".to_string(), shell_quote(&cache_dir))],
+        number: Some(games.len() as i128),
+    })
+}
+
+const REPL_HELP: &str = "\
+UWorld::OwningGameInstance   member or function offset
+EFortRarity::4               enum value by number or name
+UWorld                       class size
+OFFSET_GWORLD                global offset
+search <pattern>             wildcard search, e.g. `search UWorld::*` or `search *GameInstance`
+help                         show this help
+quit                         exit (or Ctrl+D)";
+
+/// Answers one line typed into the REPL, returning `false` to exit.
+fn repl_line(dsapi: &DSAPI, line: &str, format: Format) -> Result<bool, String> {
+    let line = line.trim();
+    match line.split_once(char::is_whitespace) {
+        _ if line.is_empty() => {}
+        _ if line == "quit" || line == "exit" => return Ok(false),
+        _ if line == "help" => println!("{}", REPL_HELP),
+        Some(("search", pattern)) => {
+            let found = dsapi.search(pattern);
+            for found in &found {
+                format.print(&query_record(found))?;
+            }
+            eprintln!("{} matches", found.len());
+        }
+        _ => format.print(&query_record(&dsapi.query(line)?))?,
+    }
+    Ok(true)
+}
+
+fn repl(args: &GameArgs, format: Format) -> Result<(), String> {
+    let dsapi = args.load()?;
+    eprintln!("Loaded {}, type `help` for the query syntax", dsapi.game().name);
+    let mut line = String::new();
+    loop {
+        // The prompt goes to stderr, so stdout only has results in the selected format.
+        eprint!("> ");
+        line.clear();
+        let read = std::io::stdin().read_line(&mut line).map_err(|e| format!("Failed to read input: {}", e))?;
+        if read == 0 {
+            return Ok(());
+        }
+        match repl_line(&dsapi, &line, format) {
+            Ok(true) => {}
+            Ok(false) => return Ok(()),
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+}
+
+/// Answers lookups for the game over HTTP until the process is stopped.
+fn serve(args: &ServeArgs, format: Format) -> Result<(), String> {
+    format.require_plain("serve")?;
+    #[cfg(feature = "serve")]
+    {
+        let dsapi = args.game.load()?;
+        let addr = format!("{}:{}", args.bind, args.port);
+        eprintln!("Serving {} on http://{}, e.g. /offset/OFFSET_GWORLD", dsapi.game().name, addr);
+        dsapi.serve(&addr)
+    }
+    #[cfg(not(feature = "serve"))]
+    {
+        let _ = args;
+        Err("dumpspace was built without the `serve` feature".to_string())
+    }
+}
+
+/// Runs `command` through the platform's shell.
+fn shell(command: &str) -> std::process::Command {
+    let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+    let mut process = std::process::Command::new(shell);
+    process.args([flag, command]);
+    process
+}
+
+fn watch(args: &WatchArgs, format: Format) -> Result<(), String> {
+    let mut dsapi = DSAPI::builder().game_id(&args.game).build()?;
+    let webhook = args.webhook.as_deref().map(Webhook::new).transpose()?;
+    let exec = args.exec.clone();
+    let on_dump = move |game: &Game, diff: Option<&DumpDiff>| {
+        let mut plain = format!("New dump of {} uploaded at {}", game.name, game.uploaded);
+        if let Some(diff) = diff {
+            plain += &format!(
+                ": {} members, {} class sizes and {} global offsets changed",
+                diff.members.len(),
+                diff.class_sizes.len(),
+                diff.offsets.len()
+            );
+        }
+        let record = Record {
+            plain,
+            json: json!({ "game": game.hash, "name": game.name, "uploaded": game.uploaded, "diff": diff }),
+            env: vec![
+                ("DUMPSPACE_GAME".to_string(), shell_quote(&game.hash)),
+                ("DUMPSPACE_UPLOADED".to_string(), game.uploaded.to_string()),
+            ],
+            number: Some(game.uploaded.into()),
+        };
+        if let Err(e) = format.print(&record) {
+            eprintln!("error: {}", e);
+        }
+        if let Some(webhook) = &webhook
+            && let Err(e) = webhook.notify(game, diff)
+        {
+            eprintln!("error: Failed to notify the webhook: {}", e);
+        }
+        let Some(command) = &exec else {
+            return;
+        };
+        let status = shell(command)
+            .env("DUMPSPACE_GAME", &game.hash)
+            .env("DUMPSPACE_UPLOADED", game.uploaded.to_string())
+            .status();
+        match status {
+            Ok(status) if status.success() => {}
+            Ok(status) => eprintln!("error: `{}` failed with {}", command, status),
+            Err(e) => eprintln!("error: Failed to run `{}`: {}", command, e),
+        }
+    };
+    eprintln!("Watching {} for new dumps, checking every {}s", dsapi.game().name, args.interval);
+    let interval = Duration::from_secs(args.interval);
+    if args.diff {
+        dsapi.download_content()?;
+        let _watcher = dsapi.watch(interval, move |dsapi, diff| on_dump(dsapi.game(), Some(diff)));
+        park_forever();
+    }
+    let _notifier = dsapi.on_new_dump(interval, move |game| on_dump(game, None));
+    park_forever();
+}
+
+/// Keeps the main thread alive while a background thread watches for dumps.
+fn park_forever() -> ! {
+    loop {
+        std::thread::park();
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Browse(args) => browse(args, cli.format),
+        Command::Csharp(args) => csharp(args, cli.format),
+        Command::Get(args) => get(args, cli.format),
+        Command::ListGames(args) => list_games(args, cli.format),
+        Command::Prefetch(args) => prefetch(args, cli.format),
+        Command::Repl(args) => repl(args, cli.format),
+        Command::Serve(args) => serve(args, cli.format),
+        Command::Watch(args) => watch(args, cli.format),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_dsapi() -> DSAPI {
+        DSAPI::from_json_blobs(
+            include_str!("../../../tests/fixtures/ClassesInfo.json"),
+            include_str!("../../../tests/fixtures/StructsInfo.json"),
+            include_str!("../../../tests/fixtures/EnumsInfo.json"),
+            include_str!("../../../tests/fixtures/OffsetsInfo.json"),
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_duration("2w"), Ok(Duration::from_secs(2 * 7 * 24 * 60 * 60)));
+        assert_eq!(parse_duration("7y").unwrap_err(), "Invalid unit in 7y, expected s, m, h, d or w");
+        assert_eq!(parse_duration("d").unwrap_err(), "Invalid duration d, expected e.g. 7d");
+        let huge = format!("{}w", u64::MAX / 2);
+        assert_eq!(parse_duration(&huge).unwrap_err(), format!("Invalid duration {}, expected e.g. 7d", huge));
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(Duration::from_secs(59)), "just now");
+        assert_eq!(format_age(Duration::from_secs(60)), "1m ago");
+        assert_eq!(format_age(Duration::from_secs(2 * 3600 + 59)), "2h ago");
+        assert_eq!(format_age(Duration::from_secs(3 * 86400)), "3d ago");
+    }
+
+    #[test]
+    fn test_repl_line() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(repl_line(&dsapi, "\n", Format::Plain), Ok(true));
+        assert_eq!(repl_line(&dsapi, "UWorld::OwningGameInstance\n", Format::Json), Ok(true));
+        assert_eq!(repl_line(&dsapi, "search UWorld::*", Format::Env), Ok(true));
+        assert_eq!(repl_line(&dsapi, "UWorld::Nope", Format::Plain).unwrap_err(), "Nothing named UWorld::Nope in the dump");
+        assert_eq!(repl_line(&dsapi, " quit ", Format::Plain), Ok(false));
+        assert_eq!(repl_line(&dsapi, "exit", Format::Plain), Ok(false));
+    }
+}
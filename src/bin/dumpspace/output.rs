@@ -0,0 +1,129 @@
+//! Printing results in the format selected with `--format`.
+
+use clap::ValueEnum;
+use dumpspace_api::QueryMatch;
+use serde_json::json;
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable text.
+    #[default]
+    Plain,
+    /// One JSON object per result.
+    Json,
+    /// Only the number of each result, in hexadecimal.
+    Hex,
+    /// `NAME=value` lines for `eval $(dumpspace ...)`.
+    Env,
+}
+
+/// One result of a command, in every format.
+pub struct Record {
+    pub plain: String,
+    pub json: serde_json::Value,
+    pub env: Vec<(String, String)>,
+    pub number: Option<i128>,
+}
+
+impl Format {
+    /// Fails for formats a command that only produces text (source code, a UI) can't honour.
+    pub fn require_plain(self, command: &str) -> Result<(), String> {
+        match self {
+            Format::Plain => Ok(()),
+            _ => Err(format!("`{}` only supports --format plain", command)),
+        }
+    }
+    pub fn print(self, record: &Record) -> Result<(), String> {
+        match self {
+            Format::Plain => println!("{}", record.plain),
+            Format::Json => println!("{}", record.json),
+            Format::Hex => println!("{}", hex(record.number.ok_or("This result has no number to print with --format hex")?)),
+            Format::Env => {
+                for (name, value) in &record.env {
+                    println!("{}={}", name, value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Turns a name like `UWorld::OwningGameInstance` into an environment variable name, `UWORLD_OWNINGGAMEINSTANCE`.
+pub fn env_name(parts: &[&str]) -> String {
+    parts.iter()
+        .map(|part| part.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' }).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Quotes a value for `sh`, so it survives `eval`.
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+fn hex(number: i128) -> String {
+    match number < 0 {
+        true => format!("-0x{:x}", -number),
+        false => format!("0x{:x}", number),
+    }
+}
+
+/// The record of a lookup or search result.
+pub fn query_record(found: &QueryMatch) -> Record {
+    let (name, number) = match found {
+        QueryMatch::Member { class, member, info } => (env_name(&[class, member]), info.offset as i128),
+        QueryMatch::Function { class, function, info } => (env_name(&[class, function]), info.offset as i128),
+        // Value names usually carry their enum's name already, e.g. `EFortRarity__Legendary`.
+        QueryMatch::EnumValue { enum_name, value, value_name } if value_name.starts_with(enum_name.as_str()) => {
+            (env_name(&[value_name]), *value as i128)
+        }
+        QueryMatch::EnumValue { enum_name, value, value_name } => (env_name(&[enum_name, value_name]), *value as i128),
+        QueryMatch::ClassSize { class, size } => (env_name(&["SIZEOF", class]), *size as i128),
+        QueryMatch::Offset { name, offset } => (env_name(&[name]), *offset as i128),
+    };
+    Record {
+        plain: found.to_string(),
+        json: json!(found),
+        env: vec![(name, hex(number))],
+        number: Some(number),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dumpspace_api::OffsetInfo;
+
+    use super::*;
+
+    #[test]
+    fn test_query_record() {
+        let info = OffsetInfo::member(0x228, 8);
+        let record = query_record(&QueryMatch::Member { class: "UWorld".to_string(), member: "OwningGameInstance".to_string(), info });
+        assert_eq!(record.plain, "UWorld::OwningGameInstance = 0x228 (size 0x8)");
+        assert_eq!(record.env, [("UWORLD_OWNINGGAMEINSTANCE".to_string(), "0x228".to_string())]);
+        assert_eq!(record.number, Some(0x228));
+        assert_eq!((record.json["kind"].as_str(), record.json["class"].as_str()), (Some("member"), Some("UWorld")));
+
+        let value = QueryMatch::EnumValue { enum_name: "EFortRarity".to_string(), value: -1, value_name: "EFortRarity__Max".to_string() };
+        assert_eq!(query_record(&value).env, [("EFORTRARITY__MAX".to_string(), "-0x1".to_string())]);
+        let value = QueryMatch::EnumValue { enum_name: "EFortRarity".to_string(), value: 4, value_name: "Legendary".to_string() };
+        assert_eq!(query_record(&value).env[0].0, "EFORTRARITY_LEGENDARY");
+        let size = QueryMatch::ClassSize { class: "UWorld".to_string(), size: 0x8a0 };
+        assert_eq!(query_record(&size).env, [("SIZEOF_UWORLD".to_string(), "0x8a0".to_string())]);
+    }
+
+    #[test]
+    fn test_formatting_helpers() {
+        assert_eq!(env_name(&["UWorld", "Bit-Field 2"]), "UWORLD_BIT_FIELD_2");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+        assert_eq!(hex(0), "0x0");
+        assert_eq!(hex(-0x10), "-0x10");
+        assert_eq!(Format::Plain.require_plain("csharp"), Ok(()));
+        assert_eq!(Format::Json.require_plain("csharp").unwrap_err(), "`csharp` only supports --format plain");
+
+        let record = Record { plain: String::new(), json: json!(null), env: Vec::new(), number: None };
+        assert_eq!(Format::Hex.print(&record).unwrap_err(), "This result has no number to print with --format hex");
+        assert_eq!(Format::Env.print(&record), Ok(()));
+    }
+}
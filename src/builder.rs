@@ -0,0 +1,269 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::{BlobKind, Config, DEFAULT_BASE_URL, LookupMode, NameMatch, ParseMode};
+#[cfg(network)]
+use crate::{DSAPI, DsManager, GameList};
+#[cfg(network)]
+use crate::http::{Body, HttpClient, LazyClient};
+
+/// Builder for [`DSAPI`], for when the defaults of [`DSAPI::new`] are not enough.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::DSAPI;
+/// use std::time::Duration;
+/// let mut dsapi = DSAPI::builder()
+///     .game_id("6b77eceb")
+///     .cache_dir("dumpspace_cache")
+///     .timeout(Duration::from_secs(30))
+///     .retries(2)
+///     .build()
+///     .unwrap();
+/// dsapi.download_content().unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct DSAPIBuilder {
+    game_id: Option<String>,
+    game_name: Option<(String, NameMatch)>,
+    cache_path: Option<PathBuf>,
+    /// Directory with a subdirectory per game, from [`Config::cache_dir`].
+    cache_root: Option<PathBuf>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    game_list_ttl: Option<Duration>,
+    retries: u32,
+    #[cfg(network)]
+    client: Option<HttpClient>,
+    blobs: Option<Vec<BlobKind>>,
+    parse_mode: ParseMode,
+    lookup_mode: LookupMode,
+    uncompressed_blobs: bool,
+}
+
+impl DSAPIBuilder {
+    /// Starts from the defaults set with [`crate::configure`].
+    pub(crate) fn with_config(mut self, config: Config) -> Self {
+        self.cache_root = config.cache_dir;
+        self.base_url = config.base_url.map(|base_url| base_url.trim_end_matches('/').to_string());
+        self.timeout = config.timeout;
+        self.retries = config.retries;
+        self.game_list_ttl = config.game_list_ttl;
+        self
+    }
+    /// Sets the game ID (hash) to load. Either this or `game_name` is required.
+    pub fn game_id(mut self, game_id: &str) -> Self {
+        self.game_id = Some(game_id.to_string());
+        self
+    }
+    /// Selects the game by name instead of its ID, see [`GameList::find_game_by_name`].
+    /// Ignored if a game ID is set.
+    pub fn game_name(mut self, game_name: &str, matching: NameMatch) -> Self {
+        self.game_name = Some((game_name.to_string(), matching));
+        self
+    }
+    /// Enables caching of the downloaded content in the given directory.
+    pub fn cache_dir(mut self, cache_path: impl Into<PathBuf>) -> Self {
+        self.cache_path = Some(cache_path.into());
+        self
+    }
+    /// Sets the server to download from, e.g. a self-hosted mirror. Defaults to [`DEFAULT_BASE_URL`].
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.trim_end_matches('/').to_string());
+        self
+    }
+    /// Sets the timeout of every HTTP request. Ignored if a custom client is set.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Reuses a game list fetched less than `ttl` ago by this process, or stored in the cache directory,
+    /// instead of fetching it again. Useful when creating several instances in a row.
+    pub fn game_list_ttl(mut self, ttl: Duration) -> Self {
+        self.game_list_ttl = Some(ttl);
+        self
+    }
+    /// Sets how many times a failed request is retried before giving up. Defaults to 0.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+    /// Uses a preconfigured HTTP client, e.g. one with a proxy or custom headers.
+    #[cfg(all(network, feature = "blocking"))]
+    pub fn client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.client = Some(HttpClient::Reqwest(client));
+        self
+    }
+    /// Uses a preconfigured `ureq` agent, e.g. one with a proxy. Requires the `minimal-http` feature.
+    #[cfg(all(network, feature = "minimal-http"))]
+    pub fn agent(mut self, agent: ureq::Agent) -> Self {
+        self.client = Some(HttpClient::Ureq(agent));
+        self
+    }
+    /// Restricts `download_content` to the given blobs. All blobs are downloaded by default.
+    pub fn blobs(mut self, blobs: &[BlobKind]) -> Self {
+        self.blobs = Some(blobs.to_vec());
+        self
+    }
+    /// Requests blobs as `.json` instead of `.json.gz`, for self-hosted mirrors that serve plain JSON.
+    /// Either way, responses are only decompressed if they start with the gzip magic bytes, so proxies
+    /// that decompress `.json.gz` payloads on the way are handled without this.
+    pub fn uncompressed_blobs(mut self, uncompressed_blobs: bool) -> Self {
+        self.uncompressed_blobs = uncompressed_blobs;
+        self
+    }
+    /// Sets how malformed blob entries are handled. Defaults to [`ParseMode::Lenient`].
+    pub fn parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+    /// Sets how `get_member_offset`, `get_class_size` and `get_offset` match names.
+    /// Defaults to [`LookupMode::Exact`]; [`LookupMode::CaseInsensitive`] helps with hand-typed configs.
+    pub fn lookup_mode(mut self, lookup_mode: LookupMode) -> Self {
+        self.lookup_mode = lookup_mode;
+        self
+    }
+    /// Fetches the game list and creates the `DSAPI`.
+    /// Fails if no game ID or name was set, the game list can't be fetched or the game does not exist.
+    #[cfg(network)]
+    pub fn build(mut self) -> Result<DSAPI, String> {
+        if self.game_id.is_none() && self.game_name.is_none() {
+            return Err("No game ID set".to_string());
+        }
+        let options = self.download_options()?;
+        let game_list = self.fetch_game_list(&options)?;
+        let game_id = match (self.game_id, self.game_name) {
+            (Some(game_id), _) => game_id,
+            (None, Some((game_name, matching))) => game_list.find_game_by_name(&game_name, matching)
+                .ok_or_else(|| format!("Game not found: {}", game_name))?
+                .hash
+                .clone(),
+            (None, None) => unreachable!(),
+        };
+        let cache_path = self.cache_path.or_else(|| self.cache_root.map(|root| root.join(&game_id)));
+        DSAPI::with_game_list(&game_id, cache_path, game_list, options)
+    }
+    /// Fetches the game list once and creates a [`DsManager`] that hands out `DSAPI`s for any game
+    /// with these settings. The game ID and name are ignored.
+    #[cfg(network)]
+    pub fn build_manager(mut self) -> Result<DsManager, String> {
+        let options = self.download_options()?;
+        let game_list = self.fetch_game_list(&options)?;
+        Ok(DsManager::with_game_list(game_list, options, self.cache_path.or(self.cache_root)))
+    }
+    /// Creates a [`CacheServer`](crate::CacheServer) that mirrors the configured server (`base_url`) into the
+    /// cache directory, with the timeout and retries of this builder. Requires the `serve` feature.
+    /// Fails if no cache directory was set. The game ID and name are ignored.
+    #[cfg(all(feature = "serve", network))]
+    pub fn build_server(mut self) -> Result<crate::CacheServer, String> {
+        let cache_path = self.cache_path.take().or(self.cache_root.take()).ok_or("No cache directory set")?;
+        let options = self.download_options()?;
+        Ok(crate::CacheServer::new(cache_path, options, self.game_list_ttl))
+    }
+    /// The download settings of [`crate::configure`], for requests made without a builder.
+    #[cfg(network)]
+    pub(crate) fn configured_options() -> Result<DownloadOptions, String> {
+        DSAPI::builder().download_options()
+    }
+    #[cfg(network)]
+    fn download_options(&mut self) -> Result<DownloadOptions, String> {
+        let client = match self.client.take() {
+            Some(client) => LazyClient::ready(client),
+            None => LazyClient::new(self.timeout),
+        };
+        Ok(DownloadOptions {
+            client,
+            base_url: self.base_url.take().unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            retries: self.retries,
+            blobs: self.blobs.take().unwrap_or_else(|| BlobKind::ALL.to_vec()),
+            parse_mode: self.parse_mode,
+            lookup_mode: self.lookup_mode,
+            uncompressed_blobs: self.uncompressed_blobs,
+        })
+    }
+    #[cfg(network)]
+    fn fetch_game_list(&self, options: &DownloadOptions) -> Result<GameList, String> {
+        match self.game_list_ttl {
+            Some(ttl) => GameList::fetch_cached(options, ttl, self.cache_path.as_deref()),
+            None => GameList::fetch(options),
+        }
+    }
+}
+
+/// Network settings a `DSAPI` keeps around for `download_content`.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(network), allow(dead_code))]
+pub(crate) struct DownloadOptions {
+    #[cfg(network)]
+    pub(crate) client: LazyClient,
+    pub(crate) base_url: String,
+    pub(crate) retries: u32,
+    pub(crate) blobs: Vec<BlobKind>,
+    pub(crate) parse_mode: ParseMode,
+    pub(crate) lookup_mode: LookupMode,
+    /// Request `.json` instead of `.json.gz` blobs.
+    pub(crate) uncompressed_blobs: bool,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        DownloadOptions {
+            #[cfg(network)]
+            client: LazyClient::default(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            retries: 0,
+            blobs: BlobKind::ALL.to_vec(),
+            parse_mode: ParseMode::default(),
+            lookup_mode: LookupMode::default(),
+            uncompressed_blobs: false,
+        }
+    }
+}
+
+#[cfg_attr(not(network), allow(dead_code))]
+impl DownloadOptions {
+    /// Sends a GET request, retrying failed attempts up to `retries` times.
+    #[cfg(network)]
+    pub(crate) fn get(&self, url: &str) -> Result<Body, String> {
+        self.retry(|| self.client.get_or_create()?.get(url))
+    }
+    /// Like [`DownloadOptions::get`], also returning the `ETag` or `Last-Modified` header of the response.
+    #[cfg(network)]
+    pub(crate) fn get_validated(&self, url: &str) -> Result<(Body, Option<String>), String> {
+        self.retry(|| self.client.get_or_create()?.get_validated(url))
+    }
+    /// Sends a HEAD request for the `ETag` or `Last-Modified` header, retrying like [`DownloadOptions::get`].
+    #[cfg(network)]
+    pub(crate) fn validator(&self, url: &str) -> Result<Option<String>, String> {
+        self.retry(|| self.client.get_or_create()?.validator(url))
+    }
+    /// Sends a POST request with a JSON body, retrying like [`DownloadOptions::get`].
+    #[cfg(network)]
+    pub(crate) fn post_json(&self, url: &str, body: &str) -> Result<(), String> {
+        self.retry(|| self.client.get_or_create()?.post_json(url, body))
+    }
+    #[cfg(network)]
+    fn retry<T>(&self, mut request: impl FnMut() -> Result<T, String>) -> Result<T, String> {
+        let mut attempt = 0;
+        loop {
+            let error = match request() {
+                Ok(response) => return Ok(response),
+                Err(e) => e,
+            };
+            if attempt >= self.retries {
+                return Err(error);
+            }
+            attempt += 1;
+        }
+    }
+    /// URL of a blob for the given game on the configured server.
+    pub(crate) fn blob_url(&self, engine: &str, location: &str, blob: BlobKind) -> String {
+        format!("{}/Games/{}/{}/{}", self.base_url, engine, location, self.blob_file(blob))
+    }
+    /// File name of a blob on the server, e.g. `ClassesInfo.json.gz`.
+    pub(crate) fn blob_file(&self, blob: BlobKind) -> String {
+        match self.uncompressed_blobs {
+            true => format!("{}.json", blob.file_name()),
+            false => format!("{}.json.gz", blob.file_name()),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Offline bundles: the raw blobs of a dump plus a manifest, as a directory or a single `.tar.zst` file.
+
+use std::collections::HashMap;
+use std::path::Path;
+#[cfg(network)]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{BlobKind, DSAPI, Game};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const FORMAT_VERSION: u32 = 1;
+
+/// `manifest.json` of a bundle.
+#[derive(Debug, Deserialize, Serialize)]
+struct BundleManifest {
+    format: u32,
+    game: Game,
+    /// Upload timestamp of the dump the blobs are from.
+    downloaded_at: u64,
+    /// Unix timestamp of when the bundle was written.
+    created_at: u64,
+    blobs: Vec<BundleBlob>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BundleBlob {
+    kind: BlobKind,
+    file: String,
+    bytes: u64,
+    /// FNV-1a hash of the file, as 16 hex digits.
+    checksum: String,
+    /// `updated_at` of the blob as reported by dumpspace.
+    updated_at: Option<String>,
+}
+
+fn checksum(bytes: &[u8]) -> String {
+    // FNV-1a, unlike std's hashers its output is specified and never changes.
+    let hash = bytes.iter().fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3));
+    format!("{:016x}", hash)
+}
+
+fn is_tar_zst(path: &Path) -> bool {
+    path.to_str().is_some_and(|path| path.ends_with(".tar.zst"))
+}
+
+impl DSAPI {
+    /// Downloads the blobs of the current dump again and writes them, uncompressed, with a manifest of the game,
+    /// timestamps and checksums into `path`: a directory, or a single file if `path` ends in `.tar.zst`
+    /// (requires the `bundle` feature). Load it with [`DSAPI::from_bundle`], e.g. to pin the exact dump
+    /// a build was tested against or to share it with machines that have no network access.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.export_bundle("fortnite.tar.zst").unwrap();
+    /// let pinned = DSAPI::from_bundle("fortnite.tar.zst").unwrap();
+    /// ```
+    #[cfg(network)]
+    pub fn export_bundle(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        use std::io::Read;
+        let mut files = Vec::new();
+        let mut blobs = Vec::new();
+        for kind in &self.options.blobs {
+            let (mut reader, _, _) = self.download_blob(*kind).map_err(|e| format!("Failed to download {}: {}", kind.file_name(), e))?;
+            let mut blob = Vec::new();
+            reader.read_to_end(&mut blob).map_err(|e| format!("Failed to download {}: {}", kind.file_name(), e))?;
+            let file = format!("{}.json", kind.file_name());
+            blobs.push(BundleBlob {
+                kind: *kind,
+                file: file.clone(),
+                bytes: blob.len() as u64,
+                checksum: checksum(&blob),
+                updated_at: self.blob_meta(*kind).map(|meta| meta.updated_at.clone()),
+            });
+            files.push((file, blob));
+        }
+        let manifest = BundleManifest {
+            format: FORMAT_VERSION,
+            game: self.game.clone(),
+            downloaded_at: self.downloaded_at,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+            blobs,
+        };
+        let manifest = serde_json::to_vec_pretty(&manifest).map_err(|e| format!("Failed to serialize bundle manifest: {}", e))?;
+        files.push((MANIFEST_FILE.to_string(), manifest));
+        write_bundle(path.as_ref(), files)
+    }
+    /// Loads a bundle written by [`DSAPI::export_bundle`], from a directory or a `.tar.zst` file (requires the
+    /// `bundle` feature). Fails if a blob doesn't match its checksum. Nothing is downloaded, so this works
+    /// without the `reqwest` and `minimal-http` features.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let dsapi = DSAPI::from_bundle("fortnite.tar.zst").unwrap();
+    /// println!("{} uploaded at {}", dsapi.game().name, dsapi.game().uploaded);
+    /// ```
+    pub fn from_bundle(path: impl AsRef<Path>) -> Result<Self, String> {
+        let mut files = read_bundle(path.as_ref())?;
+        let manifest = files.remove(MANIFEST_FILE).ok_or("Bundle has no manifest.json")?;
+        let manifest: BundleManifest = serde_json::from_slice(&manifest).map_err(|e| format!("Failed to parse bundle manifest: {}", e))?;
+        if manifest.format > FORMAT_VERSION {
+            return Err(format!("Unsupported bundle format {}", manifest.format));
+        }
+        let mut dsapi = DSAPI::from_game(manifest.game);
+        dsapi.downloaded_at = manifest.downloaded_at;
+        for blob in &manifest.blobs {
+            let contents = files.get(&blob.file).ok_or_else(|| format!("Bundle is missing {}", blob.file))?;
+            if checksum(contents) != blob.checksum {
+                return Err(format!("Checksum mismatch for {}", blob.file));
+            }
+            dsapi.load_blob(blob.kind, contents)?;
+        }
+        Ok(dsapi)
+    }
+}
+
+#[cfg(network)]
+fn write_bundle(path: &Path, files: Vec<(String, Vec<u8>)>) -> Result<(), String> {
+    if is_tar_zst(path) {
+        #[cfg(feature = "bundle")]
+        {
+            let file = std::fs::File::create(path).map_err(|e| format!("Failed to create bundle: {}", e))?;
+            let encoder = zstd::Encoder::new(file, 0).map_err(|e| format!("Failed to create bundle: {}", e))?;
+            let mut archive = tar::Builder::new(encoder);
+            for (name, contents) in files {
+                let mut header = tar::Header::new_gnu();
+                header.set_size(contents.len() as u64);
+                header.set_mode(0o644);
+                archive.append_data(&mut header, name, contents.as_slice()).map_err(|e| format!("Failed to write bundle: {}", e))?;
+            }
+            let encoder = archive.into_inner().map_err(|e| format!("Failed to write bundle: {}", e))?;
+            encoder.finish().map_err(|e| format!("Failed to write bundle: {}", e))?;
+            return Ok(());
+        }
+        #[cfg(not(feature = "bundle"))]
+        return Err("`.tar.zst` bundles require the `bundle` feature".to_string());
+    }
+    std::fs::create_dir_all(path).map_err(|e| format!("Failed to create bundle directory: {}", e))?;
+    for (name, contents) in files {
+        std::fs::write(path.join(&name), contents).map_err(|e| format!("Failed to write {}: {}", name, e))?;
+    }
+    Ok(())
+}
+
+/// Reads every file of a bundle into memory, keyed by file name.
+fn read_bundle(path: &Path) -> Result<HashMap<String, Vec<u8>>, String> {
+    let mut files = HashMap::new();
+    if is_tar_zst(path) {
+        #[cfg(feature = "bundle")]
+        {
+            use std::io::Read;
+            let file = std::fs::File::open(path).map_err(|e| format!("Failed to open bundle: {}", e))?;
+            let decoder = zstd::Decoder::new(file).map_err(|e| format!("Failed to read bundle: {}", e))?;
+            let mut archive = tar::Archive::new(decoder);
+            for entry in archive.entries().map_err(|e| format!("Failed to read bundle: {}", e))? {
+                let mut entry = entry.map_err(|e| format!("Failed to read bundle: {}", e))?;
+                let name = entry.path().map_err(|e| format!("Failed to read bundle: {}", e))?.to_string_lossy().into_owned();
+                let mut contents = Vec::new();
+                entry.read_to_end(&mut contents).map_err(|e| format!("Failed to read {}: {}", name, e))?;
+                files.insert(name, contents);
+            }
+            return Ok(files);
+        }
+        #[cfg(not(feature = "bundle"))]
+        return Err("`.tar.zst` bundles require the `bundle` feature".to_string());
+    }
+    let entries = std::fs::read_dir(path).map_err(|e| format!("Failed to open bundle: {}", e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read bundle: {}", e))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let contents = std::fs::read(entry.path()).map_err(|e| format!("Failed to read {}: {}", name, e))?;
+        files.insert(name, contents);
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(b""), "cbf29ce484222325");
+        assert_eq!(checksum(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    #[cfg(network)]
+    fn test_bundle_roundtrip() {
+        let mut dsapi = DSAPI::builder().base_url(&crate::tests::fixture_server()).game_id("fixture").build().unwrap();
+        dsapi.download_content().unwrap();
+        let mut paths = vec![Path::new("temp/test_bundle").to_path_buf()];
+        if cfg!(feature = "bundle") {
+            paths.push(Path::new("temp/test_bundle.tar.zst").to_path_buf());
+        }
+        for path in &paths {
+            dsapi.export_bundle(path).unwrap();
+            let loaded = DSAPI::from_bundle(path).unwrap();
+            assert_eq!(loaded.game(), dsapi.game());
+            assert_eq!(loaded.get_member_offset("UWorld", "OwningGameInstance"), dsapi.get_member_offset("UWorld", "OwningGameInstance"));
+            assert_eq!(loaded.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+            assert_eq!(loaded.get_function_info("AActor", "K2_GetActorLocation"), dsapi.get_function_info("AActor", "K2_GetActorLocation"));
+        }
+
+        let path = &paths[0];
+        std::fs::write(path.join("OffsetsInfo.json"), "{}").unwrap();
+        assert_eq!(DSAPI::from_bundle(path).err(), Some("Checksum mismatch for OffsetsInfo.json".to_string()));
+        std::fs::remove_dir_all(path).unwrap();
+        if let Some(archive) = paths.get(1) {
+            std::fs::remove_file(archive).unwrap();
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Managing the cache directory of a [`DsManager`](crate::DsManager) or [`DSAPI::prefetch`](crate::DSAPI::prefetch).
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const DUMP_FILE: &str = "dsapi_cache.json";
+
+/// A cache directory with a subdirectory per game, as written by [`DsManager`](crate::DsManager) and
+/// [`DSAPI::prefetch`](crate::DSAPI::prefetch). Keeps long-running tools from growing it without bound.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::CacheStore;
+/// use std::time::Duration;
+/// let cache = CacheStore::new("dumpspace_cache");
+/// for game in cache.list().unwrap() {
+///     println!("{}: {} bytes", game.game_id, game.size);
+/// }
+/// cache.purge_older_than(Duration::from_secs(60 * 60 * 24 * 30)).unwrap();
+/// println!("{} bytes left", cache.size_on_disk().unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CacheStore {
+    dir: PathBuf,
+}
+
+/// A game in a [`CacheStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedGame {
+    pub game_id: String,
+    /// When the dump was last written to the cache.
+    pub cached_at: SystemTime,
+    /// Bytes the game's subdirectory takes up.
+    pub size: u64,
+}
+
+/// Total size of the files in `path`, recursively.
+fn dir_size(path: &Path) -> Result<u64, String> {
+    let mut size = 0;
+    for entry in std::fs::read_dir(path).map_err(|e| format!("Failed to read cache directory: {}", e))? {
+        let entry = entry.map_err(|e| format!("Failed to read cache directory: {}", e))?;
+        let metadata = entry.metadata().map_err(|e| format!("Failed to read cache directory: {}", e))?;
+        size += if metadata.is_dir() { dir_size(&entry.path())? } else { metadata.len() };
+    }
+    Ok(size)
+}
+
+impl CacheStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        CacheStore { dir: dir.into() }
+    }
+    /// The cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+    /// Returns every cached game, sorted by game ID. A missing cache directory is empty.
+    pub fn list(&self) -> Result<Vec<CachedGame>, String> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(format!("Failed to read cache directory: {}", e)),
+        };
+        let mut games = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read cache directory: {}", e))?;
+            let Ok(metadata) = std::fs::metadata(entry.path().join(DUMP_FILE)) else {
+                continue;
+            };
+            games.push(CachedGame {
+                game_id: entry.file_name().to_string_lossy().into_owned(),
+                cached_at: metadata.modified().map_err(|e| format!("Failed to read cache directory: {}", e))?,
+                size: dir_size(&entry.path())?,
+            });
+        }
+        games.sort_by(|a, b| a.game_id.cmp(&b.game_id));
+        Ok(games)
+    }
+    /// Bytes the whole cache directory takes up, including the cached game list.
+    pub fn size_on_disk(&self) -> Result<u64, String> {
+        match self.dir.exists() {
+            true => dir_size(&self.dir),
+            false => Ok(0),
+        }
+    }
+    /// Removes a game from the cache. Returns `false` if it was not cached.
+    pub fn evict(&self, game_id: &str) -> Result<bool, String> {
+        if !self.list()?.iter().any(|game| game.game_id == game_id) {
+            return Ok(false);
+        }
+        std::fs::remove_dir_all(self.dir.join(game_id)).map_err(|e| format!("Failed to remove {} from the cache: {}", game_id, e))?;
+        Ok(true)
+    }
+    /// Removes every game cached more than `age` ago and returns their IDs.
+    pub fn purge_older_than(&self, age: Duration) -> Result<Vec<String>, String> {
+        let now = SystemTime::now();
+        let mut purged = Vec::new();
+        for game in self.list()? {
+            if now.duration_since(game.cached_at).unwrap_or_default() > age {
+                self.evict(&game.game_id)?;
+                purged.push(game.game_id);
+            }
+        }
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_store() {
+        let cache = CacheStore::new("temp/test_cache_store");
+        assert!(cache.list().unwrap().is_empty());
+        assert_eq!(cache.size_on_disk().unwrap(), 0);
+        for (game_id, contents) in [("old", "12345"), ("new", "123")] {
+            std::fs::create_dir_all(cache.dir().join(game_id)).unwrap();
+            std::fs::write(cache.dir().join(game_id).join(DUMP_FILE), contents).unwrap();
+        }
+        std::fs::write(cache.dir().join("gamelist_cache.json"), "12").unwrap();
+        std::fs::create_dir_all(cache.dir().join("not_a_game")).unwrap();
+        let old = std::fs::File::options().write(true).open(cache.dir().join("old").join(DUMP_FILE)).unwrap();
+        old.set_modified(SystemTime::now() - Duration::from_secs(3600)).unwrap();
+
+        let games = cache.list().unwrap();
+        assert_eq!(games.iter().map(|game| (game.game_id.as_str(), game.size)).collect::<Vec<_>>(), [("new", 3), ("old", 5)]);
+        assert_eq!(cache.size_on_disk().unwrap(), 10);
+        assert_eq!(cache.purge_older_than(Duration::from_secs(60)).unwrap(), ["old"]);
+        assert!(!cache.evict("old").unwrap());
+        assert!(cache.evict("new").unwrap());
+        assert!(cache.list().unwrap().is_empty());
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
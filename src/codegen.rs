@@ -0,0 +1,536 @@
+//! Generation of Rust source code from a dump, for offsets that are known at compile time.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde_derive::Deserialize;
+
+use crate::{DSAPI, MemberType, OffsetInfo};
+use crate::scripts::identifier;
+
+/// What to generate with [`DSAPI::export_offsets_module`], the `[codegen]` part of a `dumpspace.toml`:
+/// ```toml
+/// classes = ["UGameInstance"]
+/// offsets = ["OFFSET_GWORLD"]
+///
+/// [members]
+/// UWorld = ["PersistentLevel", "OwningGameInstance"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CodegenSpec {
+    /// Classes to generate offset structs with all members for.
+    #[serde(default)]
+    pub classes: Vec<String>,
+    /// Classes to generate offset structs with only the listed members for.
+    #[serde(default)]
+    pub members: BTreeMap<String, Vec<String>>,
+    /// Global offsets to generate constants for.
+    #[serde(default)]
+    pub offsets: Vec<String>,
+}
+
+/// Converts a member name to a snake_case field name, e.g. `OwningGameInstance` to `owning_game_instance`.
+fn field_name(member: &str) -> String {
+    let mut field = String::new();
+    let chars: Vec<char> = identifier(member).chars().collect();
+    for (i, c) in chars.iter().enumerate() {
+        // A new word starts at an uppercase letter after a lowercase letter or digit,
+        // or at the last uppercase letter of an acronym (`HTTPRequest` to `http_request`).
+        let boundary = i > 0 && c.is_ascii_uppercase() && match chars[i - 1] {
+            '_' => false,
+            previous if previous.is_ascii_uppercase() => chars.get(i + 1).is_some_and(|next| next.is_ascii_lowercase()),
+            _ => true,
+        };
+        if boundary {
+            field.push('_');
+        }
+        field.push(c.to_ascii_lowercase());
+    }
+    escape_keyword(field)
+}
+
+/// Prefixes an identifier that starts with a digit with `_`, and escapes Rust keywords.
+fn escape_keyword(mut ident: String) -> String {
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    match ident.as_str() {
+        "as" | "break" | "const" | "continue" | "else" | "enum" | "extern" | "false" | "fn" | "for" | "if"
+        | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move" | "mut" | "pub" | "ref" | "return" | "static"
+        | "struct" | "trait" | "true" | "type" | "unsafe" | "use" | "where" | "while" | "async" | "await" | "dyn"
+        | "abstract" | "become" | "box" | "do" | "final" | "macro" | "override" | "priv" | "typeof" | "unsized"
+        | "virtual" | "yield" | "try" | "gen" => format!("r#{}", ident),
+        "self" | "super" | "_" | "Self" | "crate" => format!("{}_", ident),
+        _ => ident,
+    }
+}
+
+impl DSAPI {
+    /// A comment naming the game and dump the generated code was created from, and the dumper credit if known.
+    pub(crate) fn codegen_header(&self) -> String {
+        let mut header = format!(
+            "// Generated by dumpspace-api from {} ({}), uploaded at {}. Do not edit.\n",
+            self.game.name, self.game.hash, self.game.uploaded
+        );
+        if !self.offsets_credit.is_empty() {
+            let credit: BTreeMap<&String, &String> = self.offsets_credit.iter().collect();
+            let credit: Vec<String> = credit.into_iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+            writeln!(header, "// Credit: {}", credit.join(", ")).unwrap();
+        }
+        header
+    }
+    /// Generates a Rust module with static [`phf`](https://docs.rs/phf) perfect-hash maps of the members and sizes
+    /// of `classes` and all global offsets, for lookups without parsing or allocating anything at runtime.
+    /// The generating crate needs the `codegen` feature, the crate that includes the module only needs `phf`.
+    ///
+    /// The module contains `MEMBER_OFFSETS` keyed by `"Class::Member"`, `CLASS_SIZES` and `OFFSETS`,
+    /// all `phf::Map<&'static str, usize>`. Unknown classes are skipped.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("src/offsets.rs", dsapi.export_phf_module(&["UWorld", "UGameInstance"])).unwrap();
+    /// // In the game tool: offsets::MEMBER_OFFSETS["UWorld::OwningGameInstance"]
+    /// ```
+    #[cfg(feature = "codegen")]
+    pub fn export_phf_module(&self, classes: &[&str]) -> String {
+        let mut members = BTreeMap::new();
+        let mut class_sizes = BTreeMap::new();
+        for class in classes {
+            let Some(size) = self.get_class_size(class) else {
+                continue;
+            };
+            class_sizes.insert(class.to_string(), format!("0x{:x}", size));
+            for (member, info) in self.class_members(class) {
+                members.insert(format!("{}::{}", class, member), format!("0x{:x}", info.offset));
+            }
+        }
+        let offsets: BTreeMap<String, String> = self.sorted_offsets().into_iter()
+            .map(|(name, offset)| (name.to_string(), format!("0x{:x}", offset)))
+            .collect();
+
+        let mut module = self.codegen_header();
+        for (doc, name, entries) in [
+            ("Member offsets, keyed by `\"Class::Member\"`.", "MEMBER_OFFSETS", members),
+            ("Class and struct sizes.", "CLASS_SIZES", class_sizes),
+            ("Global offsets from OffsetsInfo.", "OFFSETS", offsets),
+        ] {
+            let map: phf_codegen::Map<String> = entries.into_iter().collect();
+            write!(module, "\n/// {}\npub static {}: phf::Map<&'static str, usize> = {};\n", doc, name, map.build()).unwrap();
+        }
+        module
+    }
+    /// Generates Rust source with a struct of member offsets for each of `classes`, e.g. `UWorldOffsets`
+    /// with a `pub owning_game_instance: usize` field for `UWorld::OwningGameInstance`, and a
+    /// `load(&DSAPI) -> Result<Self, String>` constructor that reads them from a dump and fails naming every
+    /// missing member. Downstream code then gets compiler-checked fields instead of string lookups.
+    ///
+    /// Member names are converted to snake_case; names that collide get a numbered suffix. Unknown classes are skipped.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("src/offsets.rs", dsapi.export_offset_structs(&["UWorld", "UGameInstance"])).unwrap();
+    /// // In the game tool: let world = offsets::UWorldOffsets::load(&dsapi)?;
+    /// ```
+    pub fn export_offset_structs(&self, classes: &[&str]) -> String {
+        let mut module = self.codegen_header();
+        module.push_str("\nuse dumpspace_api::DSAPI;\n");
+        let mut seen = Vec::new();
+        for class in classes {
+            let Some(size) = self.get_class_size(class).filter(|_| !seen.contains(class)) else {
+                continue;
+            };
+            seen.push(*class);
+            write_offset_struct(&mut module, class, size, self.class_members(class));
+        }
+        module
+    }
+    /// Generates Rust source with a `GameOffsets` struct holding every global offset of the dump (GWorld,
+    /// GNames, GObjects, ProcessEvent, ...) as a `usize` field, named after the offset without its `OFFSET_` prefix,
+    /// e.g. `gworld` for `OFFSET_GWORLD`. Its `load(&DSAPI) -> Result<Self, String>` constructor fails naming every
+    /// missing offset, and its `Debug` output shows the offsets in hex.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("src/game_offsets.rs", dsapi.export_game_offsets()).unwrap();
+    /// // In the game tool: let offsets = game_offsets::GameOffsets::load(&dsapi)?;
+    /// ```
+    pub fn export_game_offsets(&self) -> String {
+        let mut fields: Vec<(String, &str)> = Vec::new();
+        for (name, _) in self.sorted_offsets() {
+            let base = field_name(name.strip_prefix("OFFSET_").unwrap_or(name));
+            let mut field = base.clone();
+            let mut suffix = 2;
+            while fields.iter().any(|(other, _)| *other == field) {
+                field = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            fields.push((field, name));
+        }
+
+        let mut module = self.codegen_header();
+        writeln!(module, "\nuse dumpspace_api::DSAPI;\n").unwrap();
+        writeln!(module, "/// Global offsets of {}.\n#[derive(Clone, Copy, PartialEq, Eq)]\npub struct GameOffsets {{", self.game.name).unwrap();
+        for (field, name) in &fields {
+            writeln!(module, "    /// `{}`, 0x{:x} when generated.\n    pub {}: usize,", name, self.get_offset(name).unwrap_or_default(), field).unwrap();
+        }
+        writeln!(module, "}}\n\nimpl GameOffsets {{").unwrap();
+        writeln!(module, "    /// Reads the offsets from `dsapi`, failing with every offset that is missing.").unwrap();
+        writeln!(module, "    pub fn load(dsapi: &DSAPI) -> Result<Self, String> {{").unwrap();
+        writeln!(module, "        let mut missing = Vec::new();").unwrap();
+        writeln!(module, "        let mut get = |name: &'static str| dsapi.get_offset(name).map(|offset| offset as usize).unwrap_or_else(|| {{").unwrap();
+        writeln!(module, "            missing.push(name);\n            0\n        }});").unwrap();
+        writeln!(module, "        let offsets = GameOffsets {{").unwrap();
+        for (field, name) in &fields {
+            writeln!(module, "            {}: get({:?}),", field, name).unwrap();
+        }
+        writeln!(module, "        }};").unwrap();
+        writeln!(module, "        match missing.is_empty() {{").unwrap();
+        writeln!(module, "            true => Ok(offsets),").unwrap();
+        writeln!(module, "            false => Err(format!(\"Offsets not found: {{}}\", missing.join(\", \"))),").unwrap();
+        writeln!(module, "        }}\n    }}\n}}\n").unwrap();
+        writeln!(module, "impl std::fmt::Debug for GameOffsets {{").unwrap();
+        writeln!(module, "    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{").unwrap();
+        writeln!(module, "        f.debug_struct(\"GameOffsets\")").unwrap();
+        for (field, _) in &fields {
+            writeln!(module, "            .field({:?}, &format_args!(\"0x{{:x}}\", self.{}))", field.trim_start_matches("r#"), field).unwrap();
+        }
+        writeln!(module, "            .finish()\n    }}\n}}").unwrap();
+        module
+    }
+    /// Generates `#[repr(C)]` Rust structs for `classes`, so code running inside the game can cast pointers
+    /// instead of adding offsets by hand. Gaps (including the inherited part of a class) become
+    /// `_padXX: [u8; N]` fields named after their offset, and a compile-time assertion checks each struct's size.
+    /// Structs get the [alignment](DSAPI::get_alignment) of the engine's and end with the padding up to the
+    /// [aligned size](DSAPI::get_aligned_size), so `size_of` and arrays of inline structs match the game's memory.
+    ///
+    /// Numbers, enums and pointers get their Rust type (pointers to other generated classes are typed,
+    /// others are `*mut c_void`), everything else is a byte array. Bitfields sharing a byte become one `u8`
+    /// named after the first bit, and overlapping members are left out. Unknown classes are skipped.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("src/sdk.rs", dsapi.export_repr_c_structs(&["UWorld", "UGameInstance"])).unwrap();
+    /// // In the game: let world = unsafe { &*(gworld as *const sdk::UWorld) };
+    /// ```
+    pub fn export_repr_c_structs(&self, classes: &[&str]) -> String {
+        let mut module = self.codegen_header();
+        module.push_str("\n#![allow(non_camel_case_types)]\n");
+        let generated: Vec<&str> = classes.iter().copied().filter(|class| self.get_class_size(class).is_some()).collect();
+        let mut seen = Vec::new();
+        for class in classes {
+            let Some(size) = self.get_class_size(class).filter(|_| !seen.contains(class)) else {
+                continue;
+            };
+            seen.push(*class);
+            // The struct ends with the padding up to the aligned size, so arrays of it have the engine's stride.
+            let max_align = self.get_alignment(class).unwrap_or(1) as i64;
+            let size = (size as u64).next_multiple_of(max_align as u64) as i64;
+            let name = identifier(class);
+            let repr = match max_align {
+                1 => "C".to_string(),
+                _ => format!("C, align({})", max_align),
+            };
+            writeln!(module, "\n/// `{}` (0x{:x} bytes).\n#[repr({})]\npub struct {} {{", class, size, repr, name).unwrap();
+            let mut fields: Vec<String> = Vec::new();
+            let mut cursor = 0;
+            let members = self.class_members(class);
+            for (i, (member, info)) in members.iter().enumerate() {
+                if info.offset < cursor || info.offset >= size {
+                    continue;
+                }
+                if info.offset > cursor {
+                    writeln!(module, "    _pad{:x}: [u8; 0x{:x}],", cursor, info.offset - cursor).unwrap();
+                }
+                let mut field = field_name(member);
+                let mut suffix = 2;
+                while fields.contains(&field) {
+                    field = format!("{}_{}", field_name(member), suffix);
+                    suffix += 1;
+                }
+                let member_size = if info.is_bit { 1 } else { info.size.clamp(1, size - info.offset) };
+                let ty = self.get_member_type(class, member);
+                let doc = match ty {
+                    _ if info.is_bit => {
+                        let bits: Vec<&str> = members[i..].iter()
+                            .take_while(|(_, other)| other.is_bit && other.offset == info.offset)
+                            .map(|(bit, _)| *bit)
+                            .collect();
+                        format!("Bits {}", bits.join(", "))
+                    }
+                    Some(ty) => ty.to_string(),
+                    None => "Unknown type".to_string(),
+                };
+                let rust_type = match ty.and_then(|ty| rust_type(ty, member_size, &generated)) {
+                    Some((rust_type, align)) if !info.is_bit && align <= max_align && info.offset % align == 0 => rust_type,
+                    _ if info.is_bit => "u8".to_string(),
+                    _ => format!("[u8; 0x{:x}]", member_size),
+                };
+                writeln!(module, "    /// 0x{:x}: {}\n    pub {}: {},", info.offset, doc, field, rust_type).unwrap();
+                fields.push(field);
+                cursor = info.offset + member_size;
+            }
+            if cursor < size {
+                writeln!(module, "    _pad{:x}: [u8; 0x{:x}],", cursor, size - cursor).unwrap();
+            }
+            writeln!(module, "}}\n\nconst _: () = assert!(std::mem::size_of::<{}>() == 0x{:x});", name, size).unwrap();
+        }
+        module
+    }
+    /// Generates Rust source with an enum of the members of each of `classes`, e.g. `UWorldMember` with an
+    /// `OwningGameInstance` variant, implementing [`MemberKey`](crate::MemberKey). Look members up with
+    /// [`DSAPI::get_member_offset_keyed`], or resolve a whole enum once with [`DSAPI::member_table`]
+    /// so hot paths don't hash strings. Misspelled members are then compile errors.
+    ///
+    /// Variants are named like the members, with characters that aren't valid in identifiers replaced.
+    /// Unknown classes are skipped.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("src/keys.rs", dsapi.export_member_keys(&["UWorld", "UGameInstance"])).unwrap();
+    /// // In the game tool: dsapi.get_member_offset_keyed(keys::UWorldMember::OwningGameInstance)
+    /// ```
+    pub fn export_member_keys(&self, classes: &[&str]) -> String {
+        let mut module = self.codegen_header();
+        module.push_str("\nuse dumpspace_api::MemberKey;\n");
+        let mut seen = Vec::new();
+        for class in classes {
+            if self.get_class_size(class).is_none() || seen.contains(class) {
+                continue;
+            }
+            seen.push(*class);
+            let name = format!("{}Member", identifier(class));
+            let mut variants: Vec<(String, &str)> = Vec::new();
+            for (member, _) in self.class_members(class) {
+                let mut variant = escape_keyword(identifier(member));
+                let mut suffix = 2;
+                while variants.iter().any(|(other, _)| *other == variant) {
+                    variant = format!("{}_{}", escape_keyword(identifier(member)), suffix);
+                    suffix += 1;
+                }
+                variants.push((variant, member));
+            }
+
+            writeln!(module, "\n/// The members of `{}`.", class).unwrap();
+            writeln!(module, "#[allow(non_camel_case_types)]\n#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum {} {{", name).unwrap();
+            for (variant, _) in &variants {
+                writeln!(module, "    {},", variant).unwrap();
+            }
+            writeln!(module, "}}\n\nimpl MemberKey for {} {{", name).unwrap();
+            writeln!(module, "    const CLASS: &'static str = {:?};", class).unwrap();
+            let all: Vec<String> = variants.iter().map(|(variant, _)| format!("Self::{}", variant)).collect();
+            writeln!(module, "    const ALL: &'static [Self] = &[{}];", all.join(", ")).unwrap();
+            writeln!(module, "    fn name(self) -> &'static str {{\n        match self {{").unwrap();
+            for (variant, member) in &variants {
+                writeln!(module, "            Self::{} => {:?},", variant, member).unwrap();
+            }
+            writeln!(module, "        }}\n    }}\n    fn index(self) -> usize {{\n        self as usize\n    }}\n}}").unwrap();
+        }
+        module
+    }
+    /// Generates the offsets module described by `spec`, as `cargo dumpspace` does from a `dumpspace.toml`:
+    /// an [offset struct](DSAPI::export_offset_structs) for each class, restricted to the listed members if
+    /// the class is in `spec.members`, and a constant with the current value of each global offset in `spec.offsets`.
+    /// Unlike `export_offset_structs`, unknown classes, members and offsets are an error, naming all of them.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{CodegenSpec, DSAPI};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let mut spec = CodegenSpec::default();
+    /// spec.members.insert("UWorld".to_string(), vec!["OwningGameInstance".to_string()]);
+    /// spec.offsets.push("OFFSET_GWORLD".to_string());
+    /// std::fs::write("src/offsets.rs", dsapi.export_offsets_module(&spec).unwrap()).unwrap();
+    /// ```
+    pub fn export_offsets_module(&self, spec: &CodegenSpec) -> Result<String, String> {
+        let mut missing = Vec::new();
+        let mut module = self.codegen_header();
+        let mut classes: Vec<&str> = spec.classes.iter().map(String::as_str).collect();
+        classes.extend(spec.members.keys().map(String::as_str).filter(|class| !spec.classes.iter().any(|other| other == class)));
+        if !classes.is_empty() {
+            module.push_str("\nuse dumpspace_api::DSAPI;\n");
+        }
+        for class in classes {
+            let Some(size) = self.get_class_size(class) else {
+                missing.push(class.to_string());
+                continue;
+            };
+            let mut members = self.class_members(class);
+            if let Some(wanted) = spec.members.get(class).filter(|_| !spec.classes.iter().any(|other| other == class)) {
+                for member in wanted.iter().filter(|member| !members.iter().any(|(name, _)| name == member)) {
+                    missing.push(format!("{}::{}", class, member));
+                }
+                members.retain(|(name, _)| wanted.iter().any(|member| member == name));
+            }
+            write_offset_struct(&mut module, class, size, members);
+        }
+        for name in &spec.offsets {
+            match self.get_offset(name) {
+                Some(offset) => writeln!(module, "\n/// `{}` in the dump this module was generated from.\npub const {}: usize = 0x{:x};", name, identifier(name), offset).unwrap(),
+                None => missing.push(name.clone()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(format!("Not found in the dump: {}", missing.join(", ")));
+        }
+        Ok(module)
+    }
+}
+
+/// The Rust type and its alignment for a member of type `ty` that is `size` bytes large,
+/// or `None` if it has no matching Rust type. Pointers to `classes` point to their generated struct.
+fn rust_type(ty: &MemberType, size: i64, classes: &[&str]) -> Option<(String, i64)> {
+    if ty.is_pointer() {
+        let pointee = match classes.contains(&ty.name.as_str()) && ty.modifier == "*" {
+            true => identifier(&ty.name),
+            false => "std::ffi::c_void".to_string(),
+        };
+        return (size == 8).then(|| (format!("*mut {}", pointee), 8));
+    }
+    let rust_type = match (ty.name.as_str(), size) {
+        ("int8", 1) => "i8",
+        ("uint8" | "bool" | "char", 1) => "u8",
+        ("int16", 2) => "i16",
+        ("uint16", 2) => "u16",
+        ("int32" | "int", 4) => "i32",
+        ("uint32", 4) => "u32",
+        ("int64", 8) => "i64",
+        ("uint64", 8) => "u64",
+        ("float", 4) => "f32",
+        ("double", 8) => "f64",
+        (_, 1) if ty.kind == "E" => "u8",
+        (_, 2) if ty.kind == "E" => "u16",
+        (_, 4) if ty.kind == "E" => "u32",
+        (_, 8) if ty.kind == "E" => "u64",
+        _ => return None,
+    };
+    Some((rust_type.to_string(), size))
+}
+
+/// Writes the offset struct of `class` with `members` and its `load` constructor, see [`DSAPI::export_offset_structs`].
+fn write_offset_struct(module: &mut String, class: &str, size: i32, members: Vec<(&str, &OffsetInfo)>) {
+    let name = format!("{}Offsets", identifier(class));
+    let mut fields: Vec<(String, &str, i64)> = Vec::new();
+    for (member, info) in members {
+        let mut field = field_name(member);
+        let mut suffix = 2;
+        while fields.iter().any(|(other, _, _)| *other == field) {
+            field = format!("{}_{}", field_name(member), suffix);
+            suffix += 1;
+        }
+        fields.push((field, member, info.size));
+    }
+
+    writeln!(module, "\n/// Offsets of the members of `{}` (0x{:x} bytes).", class, size).unwrap();
+    writeln!(module, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub struct {} {{", name).unwrap();
+    for (field, member, size) in &fields {
+        writeln!(module, "    /// `{}`, {} bytes.\n    pub {}: usize,", member, size, field).unwrap();
+    }
+    writeln!(module, "}}\n\nimpl {} {{", name).unwrap();
+    writeln!(module, "    /// Reads the offsets from `dsapi`, failing with every member that is missing.").unwrap();
+    writeln!(module, "    pub fn load(dsapi: &DSAPI) -> Result<Self, String> {{").unwrap();
+    let bindings: Vec<&str> = fields.iter().map(|(field, _, _)| field.as_str()).collect();
+    writeln!(module, "        let [{}] = dsapi.require_member_offsets([", bindings.join(", ")).unwrap();
+    for (_, member, _) in &fields {
+        writeln!(module, "            ({:?}, {:?}),", class, member).unwrap();
+    }
+    writeln!(module, "        ])?;").unwrap();
+    let values: Vec<String> = bindings.iter().map(|field| format!("{}: {}.offset as usize", field, field)).collect();
+    writeln!(module, "        Ok({} {{ {} }})\n    }}\n}}", name, values.join(", ")).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_name() {
+        assert_eq!(field_name("OwningGameInstance"), "owning_game_instance");
+        assert_eq!(field_name("bIsWorldInitialized"), "b_is_world_initialized");
+        assert_eq!(field_name("HTTPRequest"), "http_request");
+        assert_eq!(field_name("Health_3_ABCD"), "health_3_abcd");
+        assert_eq!(field_name("Type"), "r#type");
+        assert_eq!(field_name("Self"), "self_");
+        assert_eq!(field_name("2DPos"), "_2_d_pos");
+    }
+
+    #[test]
+    fn test_export_offset_structs() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let module = dsapi.export_offset_structs(&["UWorld", "UWorld", "Missing"]);
+        assert!(module.contains("pub struct UWorldOffsets {\n    /// `PersistentLevel`, 8 bytes.\n    pub persistent_level: usize,"));
+        assert!(module.contains("        let [persistent_level, owning_game_instance, b_is_world_initialized] = dsapi.require_member_offsets([\n            (\"UWorld\", \"PersistentLevel\"),"));
+        assert!(module.contains("Ok(UWorldOffsets { persistent_level: persistent_level.offset as usize, "));
+        assert_eq!(module.matches("pub struct").count(), 1);
+    }
+
+    #[test]
+    fn test_export_game_offsets() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let module = dsapi.export_game_offsets();
+        assert!(module.contains("\n// Credit: dumper_link: https://github.com/Encryqed/Dumper-7, dumper_used: Dumper-7\n"));
+        assert!(module.contains("pub struct GameOffsets {\n    /// `OFFSET_GNAMES`, 0x147fe680 when generated.\n    pub gnames: usize,"));
+        assert!(module.contains("    /// `OFFSET_GWORLD`, 0x14942840 when generated.\n    pub gworld: usize,\n}"));
+        assert!(module.contains("            gworld: get(\"OFFSET_GWORLD\"),\n"));
+        assert!(module.contains("            .field(\"gworld\", &format_args!(\"0x{:x}\", self.gworld))\n"));
+    }
+
+    #[test]
+    fn test_export_repr_c_structs() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let module = dsapi.export_repr_c_structs(&["UWorld", "UWorld", "AActor", "Missing"]);
+        assert!(module.contains("#[repr(C, align(8))]\npub struct UWorld {\n    _pad0: [u8; 0x30],\n    /// 0x30: ULevel*\n    pub persistent_level: *mut std::ffi::c_void,\n"));
+        assert!(module.contains("    /// 0x230: Bits bIsWorldInitialized\n    pub b_is_world_initialized: u8,\n    _pad231: [u8; 0x7b7],\n}"));
+        assert!(module.contains("const _: () = assert!(std::mem::size_of::<UWorld>() == 0x9e8);"));
+        assert!(module.contains("pub struct AActor {"));
+        assert_eq!(module.matches("#[repr(C, align(8))]").count(), 2);
+    }
+
+    #[test]
+    fn test_export_member_keys() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let module = dsapi.export_member_keys(&["UWorld", "UWorld", "Missing"]);
+        assert!(module.contains("pub enum UWorldMember {\n    PersistentLevel,\n    OwningGameInstance,\n    bIsWorldInitialized,\n}"));
+        assert!(module.contains("    const CLASS: &'static str = \"UWorld\";\n    const ALL: &'static [Self] = &[Self::PersistentLevel, Self::OwningGameInstance, Self::bIsWorldInitialized];"));
+        assert!(module.contains("            Self::OwningGameInstance => \"OwningGameInstance\",\n"));
+        assert_eq!(module.matches("pub enum").count(), 1);
+        assert_eq!(escape_keyword("Self".to_string()), "Self_");
+    }
+
+    #[test]
+    fn test_export_offsets_module() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let mut spec = CodegenSpec { offsets: vec!["OFFSET_GWORLD".to_string()], ..Default::default() };
+        spec.members.insert("UWorld".to_string(), vec!["OwningGameInstance".to_string()]);
+        let module = dsapi.export_offsets_module(&spec).unwrap();
+        assert!(module.contains("pub struct UWorldOffsets {\n    /// `OwningGameInstance`, 8 bytes.\n    pub owning_game_instance: usize,\n}"));
+        assert!(module.contains("pub const OFFSET_GWORLD: usize = 0x14942840;"));
+
+        spec.members.insert("UWorld".to_string(), vec!["Removed".to_string()]);
+        spec.classes.push("Missing".to_string());
+        spec.offsets.push("OFFSET_GONE".to_string());
+        assert_eq!(dsapi.export_offsets_module(&spec).unwrap_err(), "Not found in the dump: Missing, UWorld::Removed, OFFSET_GONE");
+    }
+
+    #[test]
+    #[cfg(feature = "codegen")]
+    fn test_export_phf_module() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let module = dsapi.export_phf_module(&["UWorld", "UWorld", "Missing"]);
+        assert!(module.starts_with("// Generated by dumpspace-api from Fixture (fixture), uploaded at 1718035200."));
+        assert!(module.contains("pub static MEMBER_OFFSETS: phf::Map<&'static str, usize> = ::phf::Map {"));
+        assert!(module.contains("(\"UWorld::OwningGameInstance\", 0x228)"));
+        assert!(module.contains("(\"UWorld\", 0x9e8)"));
+        assert!(module.contains("(\"OFFSET_GWORLD\", 0x14942840)"));
+        assert!(!module.contains("Missing") && !module.contains("AActor"));
+    }
+}
@@ -0,0 +1,71 @@
+//! Process-wide defaults for every [`DSAPIBuilder`](crate::DSAPIBuilder), see [`configure`].
+
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+
+static CONFIG: RwLock<Option<Config>> = RwLock::new(None);
+
+/// Defaults for [`DSAPI::new`](crate::DSAPI::new), [`DSAPI::builder`](crate::DSAPI::builder),
+/// [`GameList::init`](crate::GameList::init) and everything built on them, set with [`configure`].
+/// Settings made on a builder take precedence.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// The server to download from, [`DEFAULT_BASE_URL`](crate::DEFAULT_BASE_URL) if `None`.
+    pub base_url: Option<String>,
+    /// Cache directory with a subdirectory per game, like [`DsManager`](crate::DsManager) uses.
+    /// Used unless a builder or `DSAPI::new` sets a cache directory for the game itself.
+    pub cache_dir: Option<PathBuf>,
+    /// Timeout of every HTTP request.
+    pub timeout: Option<Duration>,
+    /// How many times a failed request is retried.
+    pub retries: u32,
+    /// Reuse game lists fetched less than this long ago, see [`DSAPIBuilder::game_list_ttl`](crate::DSAPIBuilder::game_list_ttl).
+    pub game_list_ttl: Option<Duration>,
+}
+
+/// Sets the defaults of this process, so libraries built on this crate use the host application's server,
+/// cache and timeouts without passing a builder through every layer. Replaces an earlier configuration.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{Config, DSAPI};
+/// use std::time::Duration;
+/// dumpspace_api::configure(Config {
+///     base_url: Some("https://dumpspace-mirror.example.com".to_string()),
+///     cache_dir: Some("dumpspace_cache".into()),
+///     timeout: Some(Duration::from_secs(30)),
+///     ..Default::default()
+/// });
+/// // Downloads from the mirror and caches in `dumpspace_cache/6b77eceb`.
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// ```
+pub fn configure(config: Config) {
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = Some(config);
+}
+
+/// Returns the defaults set with [`configure`], or the built-in defaults.
+pub fn config() -> Config {
+    CONFIG.read().unwrap_or_else(|e| e.into_inner()).clone().unwrap_or_default()
+}
+
+#[cfg(all(test, network))]
+mod tests {
+    use super::*;
+    use crate::DSAPIBuilder;
+
+    #[test]
+    fn test_config_defaults() {
+        let cache_dir = std::env::temp_dir().join(format!("dsapi_config_{}", std::process::id()));
+        let config = Config { base_url: Some(crate::tests::fixture_server() + "/"), cache_dir: Some(cache_dir.clone()), ..Default::default() };
+        let mut dsapi = DSAPIBuilder::default().with_config(config.clone()).game_id("fixture").build().unwrap();
+        dsapi.download_content().unwrap();
+        assert!(cache_dir.join("fixture").join("dsapi_cache.json").exists());
+
+        let own_dir = cache_dir.join("own");
+        let dsapi = DSAPIBuilder::default().with_config(config).cache_dir(&own_dir).game_id("fixture").build().unwrap();
+        dsapi.cache_self().unwrap();
+        assert!(own_dir.join("dsapi_cache.json").exists());
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,38 @@
+use std::io::Read;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Counts the bytes read through it. The count can be read while the reader is owned by someone else.
+pub(crate) struct CountingReader<R> {
+    inner: R,
+    count: Arc<AtomicU64>,
+}
+
+impl<R: Read> CountingReader<R> {
+    pub(crate) fn new(inner: R) -> (Self, Arc<AtomicU64>) {
+        let count = Arc::new(AtomicU64::new(0));
+        (CountingReader { inner, count: count.clone() }, count)
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.count.fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+/// How much data a blob download moved and how long it took.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BlobTransfer {
+    pub(crate) compressed_bytes: u64,
+    pub(crate) decompressed_bytes: u64,
+    /// Until the response headers arrived.
+    pub(crate) request_time: Duration,
+    /// Streaming, decompressing and deserializing the body.
+    pub(crate) decode_time: Duration,
+    /// The `ETag` or `Last-Modified` header of the response.
+    pub(crate) validator: Option<String>,
+}
@@ -0,0 +1,145 @@
+//! Comparing two independently obtained dumps of the same game.
+
+use crate::{DSAPI, OffsetInfo};
+
+/// The result of [`DSAPI::cross_check`].
+#[derive(Debug, Clone, Default)]
+pub struct CrossCheckReport {
+    /// Number of entries present in both dumps.
+    pub compared: usize,
+    pub disagreements: Vec<Disagreement>,
+}
+
+/// An entry both dumps have, but with different values. `ours` is from the dump `cross_check` was called on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Disagreement {
+    /// The member differs in offset, size or bit.
+    Member { class: String, member: String, ours: OffsetInfo, theirs: OffsetInfo },
+    ClassSize { class: String, ours: i32, theirs: i32 },
+    EnumValue { enum_name: String, value: i64, ours: String, theirs: String },
+    Offset { name: String, ours: u64, theirs: u64 },
+}
+
+impl CrossCheckReport {
+    /// Returns `true` if the dumps agree on every entry they share.
+    pub fn agrees(&self) -> bool {
+        self.disagreements.is_empty()
+    }
+}
+
+impl std::fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Disagreement::Member { class, member, ours, theirs } => write!(
+                f, "{}::{}: 0x{:x} ({} bytes) vs 0x{:x} ({} bytes)",
+                class, member, ours.offset, ours.size, theirs.offset, theirs.size
+            ),
+            Disagreement::ClassSize { class, ours, theirs } => write!(f, "{}: size 0x{:x} vs 0x{:x}", class, ours, theirs),
+            Disagreement::EnumValue { enum_name, value, ours, theirs } => write!(f, "{}::{}: {} vs {}", enum_name, value, ours, theirs),
+            Disagreement::Offset { name, ours, theirs } => write!(f, "{}: 0x{:x} vs 0x{:x}", name, ours, theirs),
+        }
+    }
+}
+
+/// Whether two member infos describe the same member. `valid` is bookkeeping and not compared.
+fn same_member(ours: &OffsetInfo, theirs: &OffsetInfo) -> bool {
+    (ours.offset, ours.size, ours.is_bit) == (theirs.offset, theirs.size, theirs.is_bit)
+        && (!ours.is_bit || ours.bit_offset == theirs.bit_offset)
+}
+
+impl DSAPI {
+    /// Compares the entries this dump shares with `other`, e.g. the official dumpspace upload against your own
+    /// Dumper-7 output, and reports every member, class size, enum value and global offset they disagree on.
+    /// Entries that only one of them has are ignored, use [`DSAPI::diff`] for those.
+    /// Disagreements are sorted by kind, then by name.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut official = DSAPI::new("6b77eceb", None);
+    /// official.download_content().unwrap();
+    /// let mut own = DSAPI::builder().game_id("6b77eceb").base_url("http://localhost:8080").build().unwrap();
+    /// own.download_content().unwrap();
+    /// let report = official.cross_check(&own);
+    /// for disagreement in &report.disagreements {
+    ///     eprintln!("{}", disagreement);
+    /// }
+    /// println!("{} entries compared", report.compared);
+    /// ```
+    pub fn cross_check(&self, other: &DSAPI) -> CrossCheckReport {
+        let mut report = CrossCheckReport::default();
+        let mut members = Vec::new();
+        for (class, member, ours) in self.members() {
+            let Some(theirs) = other.get_member_offset(class, member) else { continue };
+            report.compared += 1;
+            if !same_member(ours, &theirs) {
+                members.push(Disagreement::Member { class: class.to_string(), member: member.to_string(), ours: ours.clone(), theirs });
+            }
+        }
+        let mut class_sizes = Vec::new();
+        for (class, ours) in self.class_sizes() {
+            let Some(theirs) = other.get_class_size(class) else { continue };
+            report.compared += 1;
+            if ours != theirs {
+                class_sizes.push(Disagreement::ClassSize { class: class.to_string(), ours, theirs });
+            }
+        }
+        let mut enum_values = Vec::new();
+        for ((enum_name, value), ours) in &self.enum_name_map {
+            let (enum_name, ours) = (self.names.resolve(*enum_name), self.names.resolve(*ours));
+            let Some(theirs) = other.get_enum_name(enum_name, *value) else { continue };
+            report.compared += 1;
+            if ours != theirs {
+                enum_values.push(Disagreement::EnumValue { enum_name: enum_name.to_string(), value: *value, ours: ours.to_string(), theirs });
+            }
+        }
+        let mut offsets = Vec::new();
+        for (name, ours) in &self.offset_map {
+            let Some(theirs) = other.get_offset(name) else { continue };
+            report.compared += 1;
+            if *ours != theirs {
+                offsets.push(Disagreement::Offset { name: name.clone(), ours: *ours, theirs });
+            }
+        }
+
+        let key = |disagreement: &Disagreement| match disagreement {
+            Disagreement::Member { class, member, .. } => (class.clone(), member.clone()),
+            Disagreement::ClassSize { class, .. } => (class.clone(), String::new()),
+            Disagreement::EnumValue { enum_name, value, .. } => (enum_name.clone(), format!("{:020}", value)),
+            Disagreement::Offset { name, .. } => (name.clone(), String::new()),
+        };
+        for mut group in [members, class_sizes, enum_values, offsets] {
+            group.sort_by_key(key);
+            report.disagreements.extend(group);
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DumpOverlay;
+
+    #[test]
+    fn test_cross_check() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let report = dsapi.cross_check(&crate::tests::fixture_dsapi());
+        assert!(report.agrees());
+        assert!(report.compared > 0);
+
+        let mut other = crate::tests::fixture_dsapi();
+        other.overlay(DumpOverlay::new()
+            .member("UWorld", "OnlyTheirs", OffsetInfo { offset: 0x9e0, size: 8, valid: true, ..Default::default() })
+            .member("UWorld", "PersistentLevel", OffsetInfo { offset: 0x38, size: 8, valid: true, ..Default::default() })
+            .class_size("UWorld", 0x9f0)
+            .offset("OFFSET_GWORLD", 0x10));
+        let report = dsapi.cross_check(&other);
+        assert_eq!(report.compared, dsapi.cross_check(&dsapi).compared);
+        let lines: Vec<String> = report.disagreements.iter().map(|disagreement| disagreement.to_string()).collect();
+        assert_eq!(lines, [
+            "UWorld::PersistentLevel: 0x30 (8 bytes) vs 0x38 (8 bytes)",
+            "UWorld: size 0x9e8 vs 0x9f0",
+            "OFFSET_GWORLD: 0x14942840 vs 0x10",
+        ]);
+    }
+}
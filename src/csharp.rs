@@ -0,0 +1,165 @@
+//! Export of the dump as C# source, for external tools written in C#.
+
+use std::fmt::Write;
+
+use crate::{DSAPI, MemberType};
+use crate::scripts::identifier;
+
+/// How [`DSAPI::export_csharp`] represents classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CSharpStyle {
+    /// A `static class` per class with a `const int` per member offset and `ClassSize`.
+    #[default]
+    StaticOffsets,
+    /// A `[StructLayout(LayoutKind.Explicit)]` struct per class with a `[FieldOffset]` field per member,
+    /// for reading whole objects with `ReadProcessMemory` or `Marshal.PtrToStructure`.
+    ExplicitStructs,
+}
+
+/// A C# identifier for `name`, escaping keywords with `@`.
+fn csharp_identifier(name: &str) -> String {
+    let mut ident = identifier(name);
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    match ident.as_str() {
+        "abstract" | "as" | "base" | "bool" | "break" | "byte" | "case" | "catch" | "char" | "checked" | "class"
+        | "const" | "continue" | "decimal" | "default" | "delegate" | "do" | "double" | "else" | "enum" | "event"
+        | "explicit" | "extern" | "false" | "finally" | "fixed" | "float" | "for" | "foreach" | "goto" | "if"
+        | "implicit" | "in" | "int" | "interface" | "internal" | "is" | "lock" | "long" | "namespace" | "new"
+        | "null" | "object" | "operator" | "out" | "override" | "params" | "private" | "protected" | "public"
+        | "readonly" | "ref" | "return" | "sbyte" | "sealed" | "short" | "sizeof" | "stackalloc" | "static"
+        | "string" | "struct" | "switch" | "this" | "throw" | "true" | "try" | "typeof" | "uint" | "ulong"
+        | "unchecked" | "unsafe" | "ushort" | "using" | "virtual" | "void" | "volatile" | "while" => format!("@{}", ident),
+        _ => ident,
+    }
+}
+
+/// The C# type for a member of type `ty` that is `size` bytes large, or `None` if it has no matching C# type.
+fn csharp_type(ty: &MemberType, size: i64) -> Option<&'static str> {
+    if ty.is_pointer() {
+        return (size == 8).then_some("IntPtr");
+    }
+    Some(match (ty.name.as_str(), size) {
+        ("int8", 1) => "sbyte",
+        ("uint8" | "bool" | "char", 1) => "byte",
+        ("int16", 2) => "short",
+        ("uint16", 2) => "ushort",
+        ("int32" | "int", 4) => "int",
+        ("uint32", 4) => "uint",
+        ("int64", 8) => "long",
+        ("uint64", 8) => "ulong",
+        ("float", 4) => "float",
+        ("double", 8) => "double",
+        (_, 1) if ty.kind == "E" => "byte",
+        (_, 2) if ty.kind == "E" => "ushort",
+        (_, 4) if ty.kind == "E" => "uint",
+        (_, 8) if ty.kind == "E" => "ulong",
+        _ => return None,
+    })
+}
+
+impl DSAPI {
+    /// Generates C# source in the `Dumpspace` namespace for external tools written in C#: a static `Offsets`
+    /// class with every global offset, and a class or struct for each of `classes` depending on `style`.
+    ///
+    /// In [`CSharpStyle::ExplicitStructs`], numbers, enums and pointers (`IntPtr`) get a field, other members
+    /// are left as comments. Bitfields sharing a byte become one `byte` named after the first bit.
+    /// Unknown classes are skipped.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{CSharpStyle, DSAPI};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("Offsets.cs", dsapi.export_csharp(&["UWorld", "AActor"], CSharpStyle::StaticOffsets)).unwrap();
+    /// ```
+    pub fn export_csharp(&self, classes: &[&str], style: CSharpStyle) -> String {
+        let mut source = self.codegen_header();
+        if style == CSharpStyle::ExplicitStructs {
+            source.push_str("\nusing System;\nusing System.Runtime.InteropServices;\n");
+        }
+        source.push_str("\nnamespace Dumpspace\n{\n    public static class Offsets\n    {\n");
+        for (name, offset) in self.sorted_offsets() {
+            writeln!(source, "        public const ulong {} = 0x{:x};", csharp_identifier(name), offset).unwrap();
+        }
+        source.push_str("    }\n");
+
+        let mut seen = Vec::new();
+        for class in classes {
+            let Some(size) = self.get_class_size(class).filter(|_| !seen.contains(class)) else {
+                continue;
+            };
+            seen.push(*class);
+            let name = csharp_identifier(class);
+            writeln!(source, "\n    /// <summary>{} (0x{:x} bytes)</summary>", class, size).unwrap();
+            match style {
+                CSharpStyle::StaticOffsets => writeln!(source, "    public static class {}\n    {{\n        public const int ClassSize = 0x{:x};", name, size).unwrap(),
+                CSharpStyle::ExplicitStructs => writeln!(source, "    [StructLayout(LayoutKind.Explicit, Size = 0x{:x})]\n    public struct {}\n    {{", size, name).unwrap(),
+            }
+            // C# members can't be named after their class, and `ClassSize` is taken in static classes.
+            let mut fields = vec![name.clone(), "ClassSize".to_string()];
+            let mut last_offset = None;
+            for (member, info) in self.class_members(class) {
+                if info.is_bit && last_offset == Some(info.offset) {
+                    continue;
+                }
+                last_offset = Some(info.offset);
+                let mut field = csharp_identifier(member);
+                let mut suffix = 2;
+                while fields.contains(&field) {
+                    field = format!("{}_{}", csharp_identifier(member), suffix);
+                    suffix += 1;
+                }
+                match style {
+                    CSharpStyle::StaticOffsets => writeln!(source, "        public const int {} = 0x{:x};", field, info.offset).unwrap(),
+                    CSharpStyle::ExplicitStructs => {
+                        let ty = match self.get_member_type(class, member) {
+                            _ if info.is_bit => Some("byte"),
+                            Some(ty) => csharp_type(ty, info.size),
+                            None => None,
+                        };
+                        match ty {
+                            Some(ty) => writeln!(source, "        [FieldOffset(0x{:x})] public {} {};", info.offset, ty, field).unwrap(),
+                            None => {
+                                let ty = self.get_member_type(class, member).map(|ty| ty.to_string()).unwrap_or_default();
+                                writeln!(source, "        // 0x{:x}: {} {} ({} bytes)", info.offset, ty, member, info.size).unwrap();
+                            }
+                        }
+                    }
+                }
+                fields.push(field);
+            }
+            source.push_str("    }\n");
+        }
+        source.push_str("}\n");
+        source
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csharp_identifier() {
+        assert_eq!(csharp_identifier("OwningGameInstance"), "OwningGameInstance");
+        assert_eq!(csharp_identifier("class"), "@class");
+        assert_eq!(csharp_identifier("2DPos"), "_2DPos");
+        assert_eq!(csharp_identifier("Health 2"), "Health_2");
+    }
+
+    #[test]
+    fn test_export_csharp() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let source = dsapi.export_csharp(&["UWorld", "Missing"], CSharpStyle::StaticOffsets);
+        assert!(source.contains("namespace Dumpspace\n{\n    public static class Offsets\n    {\n"));
+        assert!(source.contains("        public const ulong OFFSET_GWORLD = 0x14942840;\n"));
+        assert!(source.contains("    public static class UWorld\n    {\n        public const int ClassSize = 0x9e8;\n        public const int PersistentLevel = 0x30;\n"));
+        assert!(!source.contains("Missing"));
+
+        let source = dsapi.export_csharp(&["UWorld", "UObject"], CSharpStyle::ExplicitStructs);
+        assert!(source.contains("    [StructLayout(LayoutKind.Explicit, Size = 0x9e8)]\n    public struct UWorld\n    {\n        [FieldOffset(0x30)] public IntPtr PersistentLevel;\n"));
+        assert!(source.contains("        [FieldOffset(0x230)] public byte bIsWorldInitialized;\n"));
+        assert!(source.contains("        // 0x18: FName NamePrivate (8 bytes)\n"));
+    }
+}
@@ -0,0 +1,200 @@
+//! Loading offsets from other dumpers or manual reversing in a simple JSON schema.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserializer;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{DSAPI, OffsetInfo};
+
+/// A dump in a schema simple enough to write by hand or convert to from other dumpers, see [`DSAPI::extend_from_custom`].
+/// Numbers can be written as JSON numbers or as `"0x..."` strings:
+/// ```json
+/// {
+///     "classes": {
+///         "UWorld": {
+///             "size": "0x9e8",
+///             "supers": ["UObject"],
+///             "members": {
+///                 "OwningGameInstance": { "offset": "0x228", "size": 8 },
+///                 "bIsTearingDown": { "offset": "0x10b", "size": 1, "bit": 3 }
+///             }
+///         }
+///     },
+///     "enums": { "EFortRarity": { "EFortRarity__Common": 0, "EFortRarity__Legendary": 4 } },
+///     "offsets": { "OFFSET_GWORLD": "0x14942840" }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserDump {
+    pub classes: BTreeMap<String, UserClass>,
+    /// Enum value names to their values, like the Enums blob.
+    pub enums: BTreeMap<String, BTreeMap<String, i64>>,
+    #[serde(deserialize_with = "number_map")]
+    pub offsets: BTreeMap<String, u64>,
+}
+
+/// A class or struct of a [`UserDump`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserClass {
+    /// Replaces the class size if set.
+    #[serde(deserialize_with = "optional_number")]
+    pub size: Option<i32>,
+    /// Super classes, starting with the direct parent. Replaces the known super classes if not empty.
+    pub supers: Vec<String>,
+    pub members: BTreeMap<String, UserMember>,
+}
+
+/// A member of a [`UserClass`].
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct UserMember {
+    #[serde(deserialize_with = "number")]
+    pub offset: i64,
+    #[serde(default, deserialize_with = "number")]
+    pub size: i64,
+    /// The bit within the bytes at `offset` for bit fields.
+    #[serde(default)]
+    pub bit: Option<i32>,
+}
+
+impl UserDump {
+    /// Loads a dump from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let serialized = std::fs::read_to_string(path).map_err(|e| format!("Failed to read custom dump: {}", e))?;
+        serde_json::from_str(&serialized).map_err(|e| format!("Failed to parse custom dump: {}", e))
+    }
+}
+
+/// A JSON number, or a decimal or `0x` hexadecimal string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawNumber {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl RawNumber {
+    fn parse<T: TryFrom<i128>>(self) -> Result<T, String> {
+        let value = match self {
+            RawNumber::Signed(value) => value as i128,
+            RawNumber::Unsigned(value) => value as i128,
+            RawNumber::Text(text) => {
+                let trimmed = text.trim();
+                let parsed = match trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+                    Some(hex) => i128::from_str_radix(hex, 16),
+                    None => trimmed.parse(),
+                };
+                parsed.map_err(|_| format!("Invalid number: {}", text))?
+            }
+        };
+        T::try_from(value).map_err(|_| format!("Number out of range: {}", value))
+    }
+}
+
+fn number<'de, D: Deserializer<'de>, T: TryFrom<i128>>(deserializer: D) -> Result<T, D::Error> {
+    <RawNumber as serde::Deserialize>::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+}
+
+fn optional_number<'de, D: Deserializer<'de>, T: TryFrom<i128>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    <Option<RawNumber> as serde::Deserialize>::deserialize(deserializer)?.map(RawNumber::parse).transpose().map_err(serde::de::Error::custom)
+}
+
+fn number_map<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<String, u64>, D::Error> {
+    <BTreeMap<String, RawNumber> as serde::Deserialize>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, value)| Ok((name, value.parse().map_err(serde::de::Error::custom)?)))
+        .collect()
+}
+
+impl DSAPI {
+    /// Adds the classes, members, enums and offsets of a [`UserDump`] to the dump, so offsets from other dumpers
+    /// or manual reversing are found by the same lookups as the dumpspace data. Entries that exist in both are
+    /// replaced. Unlike an [`DSAPI::overlay`], the entries are dropped by `download_content` and `refresh`.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::{DSAPI, Game, UserDump};
+    /// let mut dsapi = DSAPI::from_game(Game::default());
+    /// let dump: UserDump = serde_json::from_str(r#"{
+    ///     "classes": { "UWorld": { "size": "0x9e8", "members": { "OwningGameInstance": { "offset": "0x228", "size": 8 } } } },
+    ///     "offsets": { "OFFSET_GWORLD": "0x14942840" }
+    /// }"#).unwrap();
+    /// dsapi.extend_from_custom(dump);
+    /// assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+    /// assert_eq!(dsapi.get_class_size("UWorld"), Some(0x9e8));
+    /// assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+    /// ```
+    pub fn extend_from_custom(&mut self, dump: UserDump) {
+        for (class_name, class) in dump.classes {
+            // `Package.Class` names extend the class stored under its short name.
+            let class_sym = self.class_sym(&class_name).unwrap_or_else(|| self.names.intern(&class_name));
+            if let Some(size) = class.size {
+                self.class_size_map.insert(class_sym, size);
+            }
+            if !class.supers.is_empty() {
+                let supers = class.supers.iter().map(|super_class| self.names.intern(super_class)).collect();
+                self.class_super_map.insert(class_sym, supers);
+            } else {
+                self.class_super_map.entry(class_sym).or_default();
+            }
+            for (member_name, member) in class.members {
+                let member_sym = self.names.intern(&member_name);
+                let info = OffsetInfo::member(member.offset, member.size);
+                let info = match member.bit {
+                    Some(bit) => info.with_bit(bit),
+                    None => info,
+                };
+                self.class_member_map.insert((class_sym, member_sym), info);
+                // A type from the dumpspace data would describe the replaced member.
+                self.member_type_map.remove(&(class_sym, member_sym));
+            }
+        }
+        for (enum_name, values) in dump.enums {
+            let enum_sym = self.names.intern(&enum_name);
+            for (value_name, value) in values {
+                let value_sym = self.names.intern(&value_name);
+                self.enum_name_map.insert((enum_sym, value), value_sym);
+            }
+        }
+        self.offset_map.extend(dump.offsets);
+        // Keeps the overlay on top, and indexes the new names.
+        self.apply_overlay();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_extend_from_custom() {
+        let mut dsapi = fixture_dsapi();
+        let dump: UserDump = serde_json::from_str(r#"{
+            "classes": {
+                "UWorld": { "members": { "OwningGameInstance": { "offset": 560, "size": "8" } } },
+                "AMyActor": { "size": "0x300", "supers": ["AActor", "UObject"], "members": { "bActive": { "offset": "0x2a8", "size": 1, "bit": 2 } } }
+            },
+            "enums": { "EMyState": { "EMyState__Idle": 0, "EMyState__Busy": 1 } },
+            "offsets": { "OFFSET_GWORLD": 1, "OFFSET_CUSTOM": "0X10" }
+        }"#).unwrap();
+        dsapi.extend_from_custom(dump);
+
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 560);
+        assert!(dsapi.get_member_offset("UWorld", "PersistentLevel").is_some());
+        assert_eq!(dsapi.get_class_size("UWorld"), Some(2536));
+        let active = dsapi.get_member_offset("AMyActor", "bActive").unwrap();
+        assert_eq!((active.offset, active.is_bit, active.bit_offset), (0x2a8, true, 2));
+        assert_eq!(dsapi.get_class_size("AMyActor"), Some(0x300));
+        assert_eq!(dsapi.subclasses_of("AActor"), ["AMyActor"]);
+        assert_eq!(dsapi.get_enum_name_short("EMyState", 1).as_deref(), Some("Busy"));
+        assert_eq!((dsapi.get_offset("OFFSET_GWORLD"), dsapi.get_offset("OFFSET_CUSTOM")), (Some(1), Some(0x10)));
+
+        let error = serde_json::from_str::<UserDump>(r#"{"offsets": {"OFFSET_GWORLD": "0xzz"}}"#).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid number: 0xzz"));
+        assert!(serde_json::from_str::<UserDump>(r#"{"classes": {"A": {"size": "0x100000000"}}}"#).is_err());
+    }
+}
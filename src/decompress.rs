@@ -0,0 +1,69 @@
+//! Telling gzipped blobs from plain JSON by their first bytes, for mirrors and proxies that don't serve `.json.gz` as is.
+
+use std::io::{BufRead, Read};
+
+use flate2::bufread::GzDecoder;
+
+/// The first bytes of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// A blob that is decompressed if it starts with the gzip magic bytes and read as is otherwise,
+/// so a proxy that already decompressed a `.json.gz` or a mirror serving plain `.json` both work.
+pub(crate) enum BlobDecoder<R: BufRead> {
+    Gzip(GzDecoder<R>),
+    Plain(R),
+}
+
+impl<R: BufRead> BlobDecoder<R> {
+    /// Peeks at the start of `reader` to pick the decoder. Nothing is consumed.
+    pub(crate) fn new(mut reader: R) -> std::io::Result<Self> {
+        match reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            true => Ok(BlobDecoder::Gzip(GzDecoder::new(reader))),
+            false => Ok(BlobDecoder::Plain(reader)),
+        }
+    }
+}
+
+impl<R: BufRead> Read for BlobDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            BlobDecoder::Gzip(decoder) => decoder.read(buf),
+            BlobDecoder::Plain(reader) => reader.read(buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_blob_decoder() {
+        let json = include_bytes!("../tests/fixtures/OffsetsInfo.json");
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(json).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        for blob in [compressed.as_slice(), json.as_slice()] {
+            let mut decoded = Vec::new();
+            BlobDecoder::new(blob).unwrap().read_to_end(&mut decoded).unwrap();
+            assert_eq!(decoded, json);
+        }
+        assert!(matches!(BlobDecoder::new(compressed.as_slice()).unwrap(), BlobDecoder::Gzip(_)));
+        let mut empty = Vec::new();
+        BlobDecoder::new([].as_slice()).unwrap().read_to_end(&mut empty).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[cfg(network)]
+    fn test_uncompressed_blobs() {
+        let base_url = crate::tests::fixture_server();
+        let mut dsapi = crate::DSAPI::builder().base_url(&base_url).game_id("fixture").uncompressed_blobs(true).build().unwrap();
+        dsapi.download_content().unwrap();
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Game, GameList, normalize_game_name as normalize};
+
+/// Executable name suffixes UE games commonly append to the project name.
+const EXECUTABLE_SUFFIXES: [&str; 5] = ["win64shipping", "wingdkshipping", "shipping", "client", "game"];
+
+/// Reduces an executable name like `FortniteClient-Win64-Shipping.exe` to `fortnite`.
+fn executable_stem(executable: &str) -> String {
+    let file_name = executable.rsplit(['/', '\\']).next().unwrap_or(executable);
+    let file_name = file_name.strip_suffix(".exe").unwrap_or(file_name);
+    let mut stem = normalize(file_name);
+    while let Some(suffix) = EXECUTABLE_SUFFIXES.iter().find(|suffix| stem.len() > suffix.len() && stem.ends_with(*suffix)) {
+        stem.truncate(stem.len() - suffix.len());
+    }
+    stem
+}
+
+/// Returns `true` if `executable` belongs to `game`, see [`GameList::find_game_by_executable`].
+pub(crate) fn executable_matches(executable: &str, game: &Game) -> bool {
+    let stem = executable_stem(executable);
+    !stem.is_empty() && (normalize(&game.location) == stem || normalize(&game.name) == stem)
+}
+
+impl GameList {
+    /// Finds the game an executable belongs to, e.g. `FortniteClient-Win64-Shipping.exe` -> Fortnite.
+    /// Matches against the game's location and name, ignoring case, punctuation and common UE suffixes.
+    pub fn find_game_by_executable(&self, executable: &str) -> Option<&Game> {
+        self.games.iter().find(|game| executable_matches(executable, game))
+    }
+
+    /// Enumerates the running processes and returns the first one that matches a game in the list.
+    /// Useful for injected tools that need to figure out which dump to load without configuration.
+    #[cfg(feature = "process-detect")]
+    pub fn detect_running_game(&self) -> Option<&Game> {
+        running_executables().iter().find_map(|executable| self.find_game_by_executable(executable))
+    }
+}
+
+/// Returns the executable names of all running processes.
+#[cfg(feature = "process-detect")]
+pub fn running_executables() -> Vec<String> {
+    let mut system = sysinfo::System::new();
+    system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    system.processes()
+        .values()
+        .map(|process| process.name().to_string_lossy().into_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uploader;
+
+    #[test]
+    fn test_find_game_by_executable() {
+        let game = |name: &str, location: &str| Game {
+            hash: String::new(),
+            name: name.to_string(),
+            engine: "Unreal-Engine-5".to_string(),
+            location: location.to_string(),
+            uploaded: 0,
+            uploader: Uploader { name: String::new(), link: String::new() },
+        };
+        let list = GameList { games: vec![game("Fortnite", "Fortnite"), game("Rocket League", "RocketLeague")] };
+        assert_eq!(list.find_game_by_executable("FortniteClient-Win64-Shipping.exe").unwrap().name, "Fortnite");
+        assert_eq!(list.find_game_by_executable("C:\\Games\\RocketLeague.exe").unwrap().name, "Rocket League");
+        assert!(list.find_game_by_executable("explorer.exe").is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use std::hash::BuildHasher;
+
+use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{BlobKind, DSAPI, OffsetInfo};
+
+/// A suspicious entry found while parsing a dump, see [`DSAPI::diagnostics`].
+/// The entry is still loaded, unlike the entries skipped with a [`crate::ParseWarning`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Diagnostic {
+    /// The member was defined more than once; the last definition is used.
+    DuplicateMember { blob: BlobKind, class: String, member: String },
+    /// The member has a size of 0.
+    ZeroSizeMember { blob: BlobKind, class: String, member: String },
+    /// The member has a negative offset.
+    NegativeOffset { blob: BlobKind, class: String, member: String, offset: i64 },
+    /// The enum has more than one name for the value; the last name is used.
+    DuplicateEnumValue { enum_name: String, value: i64, names: (String, String) },
+    /// `class + member` spells the same string as another class and member, so code that looks members up
+    /// by the concatenated name (like the C++ API) can't tell them apart.
+    KeyCollision { class: String, member: String, other_class: String, other_member: String },
+    /// Classes in two packages share the short name; both are only found by their `Package.Class` names.
+    AmbiguousClass { blob: BlobKind, class: String, packages: (String, String) },
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Diagnostic::DuplicateMember { blob, class, member } => write!(f, "{} {}::{}: defined more than once", blob.file_name(), class, member),
+            Diagnostic::ZeroSizeMember { blob, class, member } => write!(f, "{} {}::{}: size is 0", blob.file_name(), class, member),
+            Diagnostic::NegativeOffset { blob, class, member, offset } => write!(f, "{} {}::{}: negative offset {}", blob.file_name(), class, member, offset),
+            Diagnostic::DuplicateEnumValue { enum_name, value, names } => {
+                write!(f, "{}: {} and {} both have the value {}", enum_name, names.0, names.1, value)
+            }
+            Diagnostic::KeyCollision { class, member, other_class, other_member } => {
+                write!(f, "{}::{} and {}::{} have the same concatenated key", class, member, other_class, other_member)
+            }
+            Diagnostic::AmbiguousClass { blob, class, packages } => {
+                write!(f, "{} {}: declared in {} and {}, look it up as {}.{}", blob.file_name(), class, packages.0, packages.1, packages.0, class)
+            }
+        }
+    }
+}
+
+impl DSAPI {
+    /// Returns the suspicious entries found by the last `download_content`: duplicate members and enum values,
+    /// zero-size members, negative offsets and members whose concatenated `class + member` keys collide.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+    /// Checks a member as it is merged into the lookup maps.
+    pub(crate) fn diagnose_member(&mut self, blob: BlobKind, class: &str, member: &str, info: &OffsetInfo, duplicate: bool) {
+        let (class, member) = (class.to_string(), member.to_string());
+        if duplicate {
+            self.diagnostics.push(Diagnostic::DuplicateMember { blob, class: class.clone(), member: member.clone() });
+        }
+        if info.size == 0 {
+            self.diagnostics.push(Diagnostic::ZeroSizeMember { blob, class: class.clone(), member: member.clone() });
+        }
+        if info.offset < 0 {
+            self.diagnostics.push(Diagnostic::NegativeOffset { blob, class, member, offset: info.offset });
+        }
+    }
+    /// Looks for members whose concatenated keys are equal, e.g. `AB::C` and `A::BC`.
+    /// Only the hashes of the keys are kept, the strings are compared when two hashes match.
+    /// Replaces the collisions found by earlier calls, and logs each one as a warning with the `tracing` feature.
+    pub(crate) fn diagnose_key_collisions(&mut self) {
+        self.diagnostics.retain(|diagnostic| !matches!(diagnostic, Diagnostic::KeyCollision { .. }));
+        let mut key = String::new();
+        let mut seen: FxHashMap<u64, Vec<(&str, &str)>> = FxHashMap::default();
+        let mut collisions = Vec::new();
+        for (class, member, _) in self.members() {
+            key.clear();
+            key.push_str(class);
+            key.push_str(member);
+            let candidates = seen.entry(FxBuildHasher.hash_one(&key)).or_default();
+            let collision = candidates.iter().find(|(other_class, other_member)| {
+                key.len() == other_class.len() + other_member.len()
+                    && key.starts_with(other_class)
+                    && key.ends_with(other_member)
+            });
+            if let Some(&other) = collision {
+                let (first, second) = if (class, member) < other { ((class, member), other) } else { (other, (class, member)) };
+                collisions.push(Diagnostic::KeyCollision {
+                    class: first.0.to_string(),
+                    member: first.1.to_string(),
+                    other_class: second.0.to_string(),
+                    other_member: second.1.to_string(),
+                });
+            }
+            candidates.push((class, member));
+        }
+        // The maps iterate in no particular order, ordering each pair and the list keeps the report stable between runs.
+        collisions.sort_by_cached_key(Diagnostic::to_string);
+        #[cfg(feature = "tracing")]
+        for collision in &collisions {
+            tracing::warn!(%collision, "members collide under the concatenated key scheme");
+        }
+        self.diagnostics.extend(collisions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_blob;
+
+    #[test]
+    fn test_diagnostics() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        dsapi.diagnose_key_collisions();
+        assert!(dsapi.diagnostics().is_empty());
+
+        let blob = r#"{"data":[{"AB":[{"C":[["int32","D","",[]],8,0,1]}]},{"A":[{"BC":[["int32","D","",[]],-8,4,1]},{"BC":[["int32","D","",[]],-8,4,1]}]}],"updated_at":"0","version":10202}"#;
+        dsapi.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+        dsapi.diagnose_key_collisions();
+        let diagnostics = dsapi.diagnostics();
+        assert!(diagnostics.contains(&Diagnostic::ZeroSizeMember { blob: BlobKind::Classes, class: "AB".to_string(), member: "C".to_string() }));
+        assert!(diagnostics.contains(&Diagnostic::DuplicateMember { blob: BlobKind::Classes, class: "A".to_string(), member: "BC".to_string() }));
+        assert!(diagnostics.contains(&Diagnostic::NegativeOffset { blob: BlobKind::Classes, class: "A".to_string(), member: "BC".to_string(), offset: -8 }));
+        let collision = Diagnostic::KeyCollision {
+            class: "A".to_string(),
+            member: "BC".to_string(),
+            other_class: "AB".to_string(),
+            other_member: "C".to_string(),
+        };
+        assert!(diagnostics.contains(&collision));
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_derive::Serialize;
+
+use crate::{DSAPI, OffsetInfo};
+
+/// The differences between two parsed dumps of the same game, created with [`DSAPI::diff`].
+/// Every list is sorted by name.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DumpDiff {
+    pub members: Vec<MemberChange>,
+    pub class_sizes: Vec<ClassSizeChange>,
+    pub offsets: Vec<OffsetChange>,
+}
+
+/// A member that was added (`old` is `None`), removed (`new` is `None`) or moved/resized.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberChange {
+    pub class: String,
+    pub member: String,
+    pub old: Option<OffsetInfo>,
+    pub new: Option<OffsetInfo>,
+}
+
+/// A class or struct that was added, removed or changed size.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClassSizeChange {
+    pub class: String,
+    pub old: Option<i32>,
+    pub new: Option<i32>,
+}
+
+/// A global offset from OffsetsInfo that was added, removed or changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct OffsetChange {
+    pub name: String,
+    pub old: Option<u64>,
+    pub new: Option<u64>,
+}
+
+impl DumpDiff {
+    /// Returns `true` if both dumps contain the same data.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty() && self.class_sizes.is_empty() && self.offsets.is_empty()
+    }
+    /// Renders the changes as a Markdown changelog for update notes: global offsets first, then one line per class
+    /// with its size change and the members that moved, were added or were removed.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let old = DsApiMock::new().with_class_size("UWorld", 0x9e8).with_member("UWorld", "OwningGameInstance", 0x228, 8).build();
+    /// let new = DsApiMock::new().with_class_size("UWorld", 0xa08).with_member("UWorld", "OwningGameInstance", 0x230, 8).build();
+    /// assert_eq!(old.diff(&new).to_markdown(), "## Classes\n\n- **UWorld**: grew 0x20 bytes; `OwningGameInstance` 0x228 → 0x230\n");
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        if self.is_empty() {
+            return "No changes.\n".to_string();
+        }
+        let mut markdown = String::new();
+        if !self.offsets.is_empty() {
+            markdown += "## Offsets\n\n";
+            for change in &self.offsets {
+                let line = match (change.old, change.new) {
+                    (Some(old), Some(new)) => format!("0x{:x} → 0x{:x}", old, new),
+                    (None, Some(new)) => format!("added at 0x{:x}", new),
+                    _ => "removed".to_string(),
+                };
+                markdown += &format!("- `{}`: {}\n", change.name, line);
+            }
+        }
+        let mut classes: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for change in &self.class_sizes {
+            let line = match (change.old, change.new) {
+                (Some(old), Some(new)) if new > old => format!("grew 0x{:x} bytes", new - old),
+                (Some(old), Some(new)) => format!("shrank 0x{:x} bytes", old - new),
+                (None, Some(new)) => format!("added (0x{:x} bytes)", new),
+                _ => "removed".to_string(),
+            };
+            classes.entry(&change.class).or_default().push(line);
+        }
+        for change in &self.members {
+            let line = match (&change.old, &change.new) {
+                (Some(old), Some(new)) if old.size != new.size => {
+                    format!("`{}` 0x{:x} → 0x{:x} (size 0x{:x} → 0x{:x})", change.member, old.offset, new.offset, old.size, new.size)
+                }
+                (Some(old), Some(new)) => format!("`{}` 0x{:x} → 0x{:x}", change.member, old.offset, new.offset),
+                (None, Some(new)) => format!("`{}` added at 0x{:x}", change.member, new.offset),
+                _ => format!("`{}` removed", change.member),
+            };
+            classes.entry(&change.class).or_default().push(line);
+        }
+        if !classes.is_empty() {
+            if !markdown.is_empty() {
+                markdown += "\n";
+            }
+            markdown += "## Classes\n\n";
+            for (class, lines) in classes {
+                markdown += &format!("- **{}**: {}\n", class, lines.join("; "));
+            }
+        }
+        markdown
+    }
+}
+
+/// The classes whose fingerprint differs between two registries from [`DSAPI::layout_registry`],
+/// created with [`compare_registries`]. Every list is sorted by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RegistryChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl RegistryChanges {
+    /// Returns `true` if no class was added, removed or changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two registries from [`DSAPI::layout_registry`], e.g. one saved with the generated SDK
+/// and one of the current dump, so only the classes that changed have to be regenerated.
+/// # Example:
+/// ```
+/// use dumpspace_api::{DsApiMock, compare_registries};
+/// let old = DsApiMock::new().with_class_size("UWorld", 2536).with_class_size("AActor", 656).build();
+/// let new = DsApiMock::new().with_class_size("UWorld", 2544).with_class_size("AActor", 656).build();
+/// let changes = compare_registries(&old.layout_registry(), &new.layout_registry());
+/// assert_eq!(changes.changed, ["UWorld"]);
+/// ```
+pub fn compare_registries(old: &HashMap<String, u64>, new: &HashMap<String, u64>) -> RegistryChanges {
+    let sorted = |registry: &HashMap<String, u64>| -> BTreeMap<String, u64> {
+        registry.iter().map(|(class, hash)| (class.clone(), *hash)).collect()
+    };
+    let mut changes = RegistryChanges::default();
+    for (class, old, new) in changed(sorted(old), sorted(new), |a, b| a == b) {
+        match (old, new) {
+            (None, _) => changes.added.push(class),
+            (_, None) => changes.removed.push(class),
+            _ => changes.changed.push(class),
+        }
+    }
+    changes
+}
+
+/// What a patch changed in the class layouts, created with [`DSAPI::change_report`] from the dump before and after.
+/// Every list is sorted by name.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChangeReport {
+    /// Classes and structs whose [`DSAPI::class_fingerprint`] changed, the ones code using them must be checked for.
+    pub changed_classes: Vec<String>,
+    pub added_classes: Vec<String>,
+    pub removed_classes: Vec<String>,
+    /// Members present in both dumps at a different offset or with a different size.
+    pub moved_members: Vec<MemberChange>,
+    /// Classes and structs present in both dumps with a different size.
+    pub resized_classes: Vec<ClassSizeChange>,
+}
+
+impl ChangeReport {
+    /// Returns `true` if the patch changed no class layout.
+    pub fn is_empty(&self) -> bool {
+        self.changed_classes.is_empty() && self.added_classes.is_empty() && self.removed_classes.is_empty()
+    }
+}
+
+fn same_layout(old: &OffsetInfo, new: &OffsetInfo) -> bool {
+    (old.offset, old.size, old.is_bit, old.bit_offset) == (new.offset, new.size, new.is_bit, new.bit_offset)
+}
+
+/// Pairs up the entries of two maps by key and keeps the ones that differ.
+fn changed<K: Ord, V>(old: BTreeMap<K, V>, mut new: BTreeMap<K, V>, same: impl Fn(&V, &V) -> bool) -> Vec<(K, Option<V>, Option<V>)> {
+    let mut changes = Vec::new();
+    for (key, old_value) in old {
+        match new.remove(&key) {
+            Some(new_value) if same(&old_value, &new_value) => {}
+            new_value => changes.push((key, Some(old_value), new_value)),
+        }
+    }
+    changes.extend(new.into_iter().map(|(key, new_value)| (key, None, Some(new_value))));
+    changes.sort_by(|a, b| a.0.cmp(&b.0));
+    changes
+}
+
+impl DSAPI {
+    /// Returns the [`DSAPI::class_fingerprint`] of every class and struct, to be saved and later compared with
+    /// [`compare_registries`] instead of keeping the whole old dump around.
+    pub fn layout_registry(&self) -> HashMap<String, u64> {
+        self.class_sizes()
+            .filter_map(|(class, _)| Some((class.to_string(), self.class_fingerprint(class)?)))
+            .collect()
+    }
+    /// Compares the class layouts of this dump against the dump of the next patch, for release automation
+    /// deciding what to rebuild or re-test. See [`DSAPI::diff`] for global offsets and added or removed members.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let old = DsApiMock::new().with_class_size("UWorld", 2536).with_member("UWorld", "OwningGameInstance", 0x228, 8).build();
+    /// let new = DsApiMock::new().with_class_size("UWorld", 2544).with_member("UWorld", "OwningGameInstance", 0x230, 8).build();
+    /// let report = old.change_report(&new);
+    /// assert_eq!(report.changed_classes, ["UWorld"]);
+    /// assert_eq!(report.moved_members[0].new.as_ref().unwrap().offset, 0x230);
+    /// assert_eq!(report.resized_classes[0].new, Some(2544));
+    /// ```
+    pub fn change_report(&self, newer: &DSAPI) -> ChangeReport {
+        let registry = compare_registries(&self.layout_registry(), &newer.layout_registry());
+        let diff = self.diff(newer);
+        ChangeReport {
+            changed_classes: registry.changed,
+            added_classes: registry.added,
+            removed_classes: registry.removed,
+            moved_members: diff.members.into_iter().filter(|change| change.old.is_some() && change.new.is_some()).collect(),
+            resized_classes: diff.class_sizes.into_iter().filter(|change| change.old.is_some() && change.new.is_some()).collect(),
+        }
+    }
+    /// Compares this dump against a `newer` one and lists every member, class size and global offset that changed.
+    pub fn diff(&self, newer: &DSAPI) -> DumpDiff {
+        let members = |dsapi: &DSAPI| -> BTreeMap<(String, String), OffsetInfo> {
+            dsapi.members().map(|(class, member, info)| ((class.to_string(), member.to_string()), info.clone())).collect()
+        };
+        let class_sizes = |dsapi: &DSAPI| -> BTreeMap<String, i32> {
+            dsapi.class_sizes().map(|(class, size)| (class.to_string(), size)).collect()
+        };
+        let offsets = |dsapi: &DSAPI| -> BTreeMap<String, u64> {
+            dsapi.offset_map.iter().map(|(name, offset)| (name.clone(), *offset)).collect()
+        };
+
+        DumpDiff {
+            members: changed(members(self), members(newer), same_layout)
+                .into_iter()
+                .map(|((class, member), old, new)| MemberChange { class, member, old, new })
+                .collect(),
+            class_sizes: changed(class_sizes(self), class_sizes(newer), |a, b| a == b)
+                .into_iter()
+                .map(|(class, old, new)| ClassSizeChange { class, old, new })
+                .collect(),
+            offsets: changed(offsets(self), offsets(newer), |a, b| a == b)
+                .into_iter()
+                .map(|(name, old, new)| OffsetChange { name, old, new })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_blob;
+
+    #[test]
+    fn test_diff() {
+        let old = crate::tests::fixture_dsapi();
+        assert!(old.diff(&old).is_empty());
+
+        let mut new = crate::tests::fixture_dsapi();
+        let blob = r#"{"data":[{"UWorld":[{"__MDKClassSize":2544},{"OwningGameInstance":[["UGameInstance","C","*",[]],560,8,1]},{"NewMember":[["int32","D","",[]],568,4,1]}]}],"updated_at":"0","version":10202}"#;
+        new.parse_class_info(crate::BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+        new.offset_map.remove("OFFSET_GNAMES");
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.members.len(), 2);
+        assert_eq!(diff.members[0].member, "NewMember");
+        assert!(diff.members[0].old.is_none());
+        assert_eq!(diff.members[1].member, "OwningGameInstance");
+        assert_eq!(diff.members[1].old.as_ref().unwrap().offset, 0x228);
+        assert_eq!(diff.members[1].new.as_ref().unwrap().offset, 0x230);
+        assert_eq!((diff.class_sizes[0].old, diff.class_sizes[0].new), (Some(2536), Some(2544)));
+        assert_eq!(diff.offsets[0].name, "OFFSET_GNAMES");
+        assert!(diff.offsets[0].new.is_none());
+
+        assert_eq!(diff.to_markdown(), "## Offsets\n\n- `OFFSET_GNAMES`: removed\n\n## Classes\n\n\
+            - **UWorld**: grew 0x8 bytes; `NewMember` added at 0x238; `OwningGameInstance` 0x228 → 0x230\n");
+        assert_eq!(old.diff(&old).to_markdown(), "No changes.\n");
+
+        let mut registry = new.layout_registry();
+        registry.insert("UAdded".to_string(), 0);
+        let changes = super::compare_registries(&old.layout_registry(), &registry);
+        assert_eq!((changes.added, changes.removed, changes.changed), (vec!["UAdded".to_string()], vec![], vec!["UWorld".to_string()]));
+        assert!(super::compare_registries(&registry, &registry).is_empty());
+
+        let report = old.change_report(&new);
+        assert_eq!(report.changed_classes, ["UWorld"]);
+        assert!(report.added_classes.is_empty() && report.removed_classes.is_empty());
+        assert_eq!(report.moved_members.len(), 1);
+        assert_eq!(report.moved_members[0].member, "OwningGameInstance");
+        assert_eq!(report.resized_classes.len(), 1);
+        assert!(old.change_report(&old).is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+//! Export of the parsed dump in plain formats, for tools that don't want to deal with the blob format.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+use serde_derive::Serialize;
+
+use crate::{DSAPI, Game, OffsetInfo};
+
+/// The tables [`DSAPI::export_csv`] can write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Table {
+    /// `class,member,offset,size,is_bit,bit_offset,type`
+    Members,
+    /// `class,size,super`, with the direct parent as the super class.
+    Classes,
+    /// `enum,name,value`
+    Enums,
+    /// `name,offset`
+    Offsets,
+}
+
+#[derive(Serialize)]
+struct ExportedDump<'a> {
+    game: &'a Game,
+    /// Who dumped the offsets, see [`DSAPI::offsets_credit`].
+    credit: BTreeMap<&'a str, &'a str>,
+    classes: Vec<ExportedClass<'a>>,
+    enums: Vec<ExportedEnum<'a>>,
+    offsets: Vec<ExportedOffset<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExportedClass<'a> {
+    name: &'a str,
+    size: Option<i32>,
+    supers: Vec<&'a str>,
+    members: Vec<ExportedMember<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExportedMember<'a> {
+    name: &'a str,
+    offset: i64,
+    size: i64,
+    is_bit: bool,
+    bit_offset: Option<i32>,
+    #[serde(rename = "type")]
+    ty: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ExportedEnum<'a> {
+    name: &'a str,
+    values: Vec<ExportedEnumValue<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExportedEnumValue<'a> {
+    name: &'a str,
+    value: i64,
+}
+
+#[derive(Serialize)]
+struct ExportedOffset<'a> {
+    name: &'a str,
+    offset: u64,
+}
+
+/// Quotes a CSV field if needed, e.g. for types like `TMap<FName, int32>`.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl DSAPI {
+    /// Every class or struct name, sorted.
+    pub(crate) fn class_names(&self) -> BTreeSet<&str> {
+        self.class_size_map.keys()
+            .chain(self.class_super_map.keys())
+            .chain(self.class_member_map.keys().map(|(class, _)| class))
+            .map(|class| self.names.resolve(*class))
+            .collect()
+    }
+    fn member_type_name(&self, class_name: &str, member_name: &str) -> Option<String> {
+        self.get_member_type(class_name, member_name).map(|ty| ty.to_string())
+    }
+    fn exported_member<'a>(&self, class_name: &str, member_name: &'a str, info: &OffsetInfo) -> ExportedMember<'a> {
+        ExportedMember {
+            name: member_name,
+            offset: info.offset,
+            size: info.size,
+            is_bit: info.is_bit,
+            bit_offset: info.is_bit.then_some(info.bit_offset),
+            ty: self.member_type_name(class_name, member_name),
+        }
+    }
+    /// All enums with their values sorted by value.
+    pub(crate) fn sorted_enums(&self) -> BTreeMap<&str, Vec<(i64, &str)>> {
+        let mut enums: BTreeMap<&str, Vec<(i64, &str)>> = BTreeMap::new();
+        for ((enum_name, value), value_name) in &self.enum_name_map {
+            enums.entry(self.names.resolve(*enum_name)).or_default().push((*value, self.names.resolve(*value_name)));
+        }
+        for values in enums.values_mut() {
+            values.sort();
+        }
+        enums
+    }
+    /// Writes the whole parsed dump as one normalized JSON document: the game and the dumper credit, every class
+    /// with its size, super classes and members (sorted by offset), every enum and every global offset.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.export_json(std::fs::File::create("dump.json").unwrap()).unwrap();
+    /// ```
+    pub fn export_json(&self, writer: impl Write) -> Result<(), String> {
+        let classes = self.class_names().into_iter().map(|class| ExportedClass {
+            name: class,
+            size: self.get_class_size(class),
+            supers: self.class_super_map.get(&self.names.get(class).unwrap())
+                .map(|supers| supers.iter().map(|super_class| self.names.resolve(*super_class)).collect())
+                .unwrap_or_default(),
+            members: self.class_members(class).into_iter()
+                .map(|(member, info)| self.exported_member(class, member, info))
+                .collect(),
+        }).collect();
+        let enums = self.sorted_enums().into_iter().map(|(name, values)| ExportedEnum {
+            name,
+            values: values.into_iter().map(|(value, name)| ExportedEnumValue { name, value }).collect(),
+        }).collect();
+        let offsets = self.sorted_offsets().into_iter().map(|(name, offset)| ExportedOffset { name, offset }).collect();
+        let credit = self.offsets_credit.iter().map(|(key, value)| (key.as_str(), value.as_str())).collect();
+        let dump = ExportedDump { game: &self.game, credit, classes, enums, offsets };
+        serde_json::to_writer(writer, &dump).map_err(|e| format!("Failed to export JSON: {}", e))
+    }
+    /// Writes one table of the parsed dump as CSV with a header row, sorted by name (and members by offset).
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{DSAPI, Table};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.export_csv(std::fs::File::create("members.csv").unwrap(), Table::Members).unwrap();
+    /// ```
+    pub fn export_csv(&self, writer: impl Write, table: Table) -> Result<(), String> {
+        let mut writer = std::io::BufWriter::new(writer);
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        match table {
+            Table::Members => {
+                rows.push(["class", "member", "offset", "size", "is_bit", "bit_offset", "type"].map(String::from).to_vec());
+                for class in self.class_names() {
+                    for (member, info) in self.class_members(class) {
+                        let member = self.exported_member(class, member, info);
+                        rows.push(vec![
+                            class.to_string(),
+                            member.name.to_string(),
+                            member.offset.to_string(),
+                            member.size.to_string(),
+                            member.is_bit.to_string(),
+                            member.bit_offset.map(|bit| bit.to_string()).unwrap_or_default(),
+                            member.ty.unwrap_or_default(),
+                        ]);
+                    }
+                }
+            }
+            Table::Classes => {
+                rows.push(["class", "size", "super"].map(String::from).to_vec());
+                for class in self.class_names() {
+                    let size = self.get_class_size(class).map(|size| size.to_string()).unwrap_or_default();
+                    let super_class = self.get_super_classes(class).and_then(|supers| supers.into_iter().next()).unwrap_or_default();
+                    rows.push(vec![class.to_string(), size, super_class]);
+                }
+            }
+            Table::Enums => {
+                rows.push(["enum", "name", "value"].map(String::from).to_vec());
+                for (enum_name, values) in self.sorted_enums() {
+                    for (value, name) in values {
+                        rows.push(vec![enum_name.to_string(), name.to_string(), value.to_string()]);
+                    }
+                }
+            }
+            Table::Offsets => {
+                rows.push(["name", "offset"].map(String::from).to_vec());
+                for (name, offset) in self.sorted_offsets() {
+                    rows.push(vec![name.to_string(), offset.to_string()]);
+                }
+            }
+        }
+        for row in rows {
+            let row: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+            writeln!(writer, "{}", row.join(",")).map_err(|e| format!("Failed to export CSV: {}", e))?;
+        }
+        writer.flush().map_err(|e| format!("Failed to export CSV: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_json() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let mut json = Vec::new();
+        dsapi.export_json(&mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        let world = json["classes"].as_array().unwrap().iter().find(|class| class["name"] == "UWorld").unwrap();
+        assert_eq!(world["size"], 2536);
+        assert_eq!(world["supers"], serde_json::json!(["UObject"]));
+        let member = world["members"].as_array().unwrap().iter().find(|member| member["name"] == "OwningGameInstance").unwrap();
+        assert_eq!(member["offset"], 0x228);
+        assert_eq!(member["type"], "UGameInstance*");
+        assert_eq!(member["bit_offset"], serde_json::Value::Null);
+        assert!(json["offsets"].as_array().unwrap().iter().any(|offset| offset["name"] == "OFFSET_GWORLD"));
+        assert_eq!(json["credit"]["dumper_used"], "Dumper-7");
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let mut csv = Vec::new();
+        dsapi.export_csv(&mut csv, Table::Members).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert!(csv.starts_with("class,member,offset,size,is_bit,bit_offset,type\n"));
+        assert!(csv.contains("\nUWorld,OwningGameInstance,552,8,false,,UGameInstance*\n"));
+        assert!(csv.contains("\nUWorld,bIsWorldInitialized,560,1,true,2,bool\n"));
+
+        let mut csv = Vec::new();
+        dsapi.export_csv(&mut csv, Table::Classes).unwrap();
+        assert!(String::from_utf8(csv).unwrap().contains("\nUWorld,2536,UObject\n"));
+        assert_eq!(csv_field("TMap<FName, int32>"), "\"TMap<FName, int32>\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+    }
+}
@@ -0,0 +1,280 @@
+//! C API for using the crate from C, C++ or anything else that can call C functions.
+//! Enabled with the `ffi` feature, the header is generated into `OUT_DIR` on build. The checked-in
+//! `include/dumpspace_api.h` is updated by building with `DUMPSPACE_API_UPDATE_HEADER=1`.
+//! `ds_new` and `ds_download` need a download feature and are declared if `DUMPSPACE_API_NETWORK` is defined.
+//!
+//! All strings are NUL-terminated UTF-8. Functions that can fail return `false` or null, and
+//! [`ds_last_error`] returns the reason.
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+#[cfg(network)]
+use std::path::PathBuf;
+
+use crate::DSAPI;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(error: impl Into<String>) {
+    let error = CString::new(error.into().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(error));
+}
+
+/// Converts a C string argument, recording an error if it is null or not UTF-8.
+///
+/// # Safety
+/// `value` must be null or point to a NUL-terminated string.
+unsafe fn str_arg<'a>(value: *const c_char, name: &str) -> Option<&'a str> {
+    if value.is_null() {
+        set_last_error(format!("{} is null", name));
+        return None;
+    }
+    // SAFETY: guaranteed by the caller.
+    match unsafe { CStr::from_ptr(value) }.to_str() {
+        Ok(value) => Some(value),
+        Err(e) => {
+            set_last_error(format!("{} is not valid UTF-8: {}", name, e));
+            None
+        }
+    }
+}
+
+/// Converts the `ds` argument, recording an error if it is null.
+///
+/// # Safety
+/// `ds` must be null or come from `ds_new` and not be freed.
+unsafe fn ds_arg<'a>(ds: *const DSAPI) -> Option<&'a DSAPI> {
+    // SAFETY: guaranteed by the caller.
+    let dsapi = unsafe { ds.as_ref() };
+    if dsapi.is_none() {
+        set_last_error("ds is null");
+    }
+    dsapi
+}
+
+/// The offset info of a class member, see `ds_get_member_offset`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DsOffsetInfo {
+    pub offset: i64,
+    pub size: i64,
+    pub is_bit: bool,
+    pub bit_offset: i32,
+}
+
+/// Returns the error of the last call that failed on this thread, or null.
+/// The string is valid until the next failing call on this thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn ds_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| last_error.borrow().as_ref().map_or(std::ptr::null(), |error| error.as_ptr()))
+}
+
+/// Creates a `DSAPI` for a game ID, with an optional cache directory (null for no caching).
+/// Returns null on failure. Free it with `ds_free`.
+///
+/// # Safety
+/// `game_id` must be a NUL-terminated string, `cache_dir` null or a NUL-terminated string.
+#[cfg(network)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ds_new(game_id: *const c_char, cache_dir: *const c_char) -> *mut DSAPI {
+    // SAFETY: guaranteed by the caller.
+    unsafe { build_dsapi(DSAPI::builder(), game_id, cache_dir) }
+}
+
+/// `ds_new` with the settings of `builder`.
+///
+/// # Safety
+/// Like `ds_new`.
+#[cfg(network)]
+unsafe fn build_dsapi(builder: crate::DSAPIBuilder, game_id: *const c_char, cache_dir: *const c_char) -> *mut DSAPI {
+    // SAFETY: guaranteed by the caller.
+    let Some(game_id) = (unsafe { str_arg(game_id, "game_id") }) else {
+        return std::ptr::null_mut();
+    };
+    let mut builder = builder.game_id(game_id);
+    if !cache_dir.is_null() {
+        // SAFETY: guaranteed by the caller.
+        let Some(cache_dir) = (unsafe { str_arg(cache_dir, "cache_dir") }) else {
+            return std::ptr::null_mut();
+        };
+        builder = builder.cache_dir(PathBuf::from(cache_dir));
+    }
+    match builder.build() {
+        Ok(dsapi) => Box::into_raw(Box::new(dsapi)),
+        Err(e) => {
+            set_last_error(e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Downloads (or restores from the cache) the dump, see `DSAPI::download_content`.
+///
+/// # Safety
+/// `ds` must come from `ds_new` and not be freed.
+#[cfg(network)]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ds_download(ds: *mut DSAPI) -> bool {
+    // SAFETY: guaranteed by the caller.
+    let Some(dsapi) = (unsafe { ds.as_mut() }) else {
+        set_last_error("ds is null");
+        return false;
+    };
+    match dsapi.download_content() {
+        Ok(()) => true,
+        Err(e) => {
+            set_last_error(e);
+            false
+        }
+    }
+}
+
+/// Looks up a class member and writes its offset info to `out`. Returns `false` if it is not found.
+///
+/// # Safety
+/// `ds` must come from `ds_new` and not be freed, the names must be NUL-terminated strings
+/// and `out` must point to a writable `DsOffsetInfo`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ds_get_member_offset(ds: *const DSAPI, class_name: *const c_char, member_name: *const c_char, out: *mut DsOffsetInfo) -> bool {
+    // SAFETY: guaranteed by the caller.
+    let Some(dsapi) = (unsafe { ds_arg(ds) }) else {
+        return false;
+    };
+    // SAFETY: guaranteed by the caller.
+    let (Some(class_name), Some(member_name)) = (unsafe { (str_arg(class_name, "class_name"), str_arg(member_name, "member_name")) }) else {
+        return false;
+    };
+    let Some(info) = dsapi.get_member_offset(class_name, member_name) else {
+        set_last_error(format!("Member not found: {}::{}", class_name, member_name));
+        return false;
+    };
+    let info = DsOffsetInfo { offset: info.offset, size: info.size, is_bit: info.is_bit, bit_offset: info.bit_offset };
+    // SAFETY: guaranteed by the caller.
+    match unsafe { out.as_mut() } {
+        Some(out) => *out = info,
+        None => {
+            set_last_error("out is null");
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns the size of a class, or -1 if it is not found.
+///
+/// # Safety
+/// `ds` must come from `ds_new` and not be freed, `class_name` must be a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ds_get_class_size(ds: *const DSAPI, class_name: *const c_char) -> i32 {
+    // SAFETY: guaranteed by the caller.
+    let (Some(dsapi), Some(class_name)) = (unsafe { (ds_arg(ds), str_arg(class_name, "class_name")) }) else {
+        return -1;
+    };
+    dsapi.get_class_size(class_name).unwrap_or_else(|| {
+        set_last_error(format!("Class not found: {}", class_name));
+        -1
+    })
+}
+
+/// Looks up a global offset (e.g. `OFFSET_GWORLD`) and writes it to `out`. Returns `false` if it is not found.
+///
+/// # Safety
+/// `ds` must come from `ds_new` and not be freed, `offset_name` must be a NUL-terminated string
+/// and `out` must point to a writable `uint64_t`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ds_get_offset(ds: *const DSAPI, offset_name: *const c_char, out: *mut u64) -> bool {
+    // SAFETY: guaranteed by the caller.
+    let (Some(dsapi), Some(offset_name)) = (unsafe { (ds_arg(ds), str_arg(offset_name, "offset_name")) }) else {
+        return false;
+    };
+    match (dsapi.get_offset(offset_name), out.is_null()) {
+        (Some(offset), false) => {
+            // SAFETY: guaranteed by the caller.
+            unsafe { *out = offset };
+            true
+        }
+        (None, _) => {
+            set_last_error(format!("Offset not found: {}", offset_name));
+            false
+        }
+        (_, true) => {
+            set_last_error("out is null");
+            false
+        }
+    }
+}
+
+/// Frees a `DSAPI` created with `ds_new`. Does nothing for null.
+///
+/// # Safety
+/// `ds` must be null or come from `ds_new`, and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ds_free(ds: *mut DSAPI) {
+    if !ds.is_null() {
+        // SAFETY: guaranteed by the caller.
+        drop(unsafe { Box::from_raw(ds) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ffi() {
+        let ds = Box::into_raw(Box::new(crate::tests::fixture_dsapi()));
+        unsafe {
+            let mut info = DsOffsetInfo::default();
+            assert!(ds_get_member_offset(ds, c"UWorld".as_ptr(), c"OwningGameInstance".as_ptr(), &mut info));
+            assert_eq!(info, DsOffsetInfo { offset: 0x228, size: 8, is_bit: false, bit_offset: 0 });
+            assert!(!ds_get_member_offset(ds, c"UWorld".as_ptr(), c"Missing".as_ptr(), &mut info));
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("Member not found: UWorld::Missing"));
+
+            assert_eq!(ds_get_class_size(ds, c"UWorld".as_ptr()), 2536);
+            assert_eq!(ds_get_class_size(ds, std::ptr::null()), -1);
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("class_name is null"));
+
+            let mut offset = 0;
+            assert!(ds_get_offset(ds, c"OFFSET_GWORLD".as_ptr(), &mut offset));
+            assert_eq!(offset, 0x14942840);
+            ds_free(ds);
+
+            assert!(!ds_get_member_offset(std::ptr::null(), c"UWorld".as_ptr(), c"OwningGameInstance".as_ptr(), &mut info));
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("ds is null"));
+            set_last_error("stale");
+            assert_eq!(ds_get_class_size(std::ptr::null(), c"UWorld".as_ptr()), -1);
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("ds is null"));
+            set_last_error("stale");
+            assert!(!ds_get_offset(std::ptr::null(), c"OFFSET_GWORLD".as_ptr(), &mut offset));
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("ds is null"));
+        }
+    }
+
+    #[test]
+    #[cfg(network)]
+    fn test_ffi_download() {
+        let cache_dir = std::env::temp_dir().join(format!("dsapi_ffi_{}", std::process::id()));
+        let cache_dir_arg = CString::new(cache_dir.to_str().unwrap()).unwrap();
+        let builder = || DSAPI::builder().base_url(&crate::tests::fixture_server());
+        unsafe {
+            assert!(ds_new(std::ptr::null(), std::ptr::null()).is_null());
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("game_id is null"));
+            assert!(build_dsapi(builder(), c"missing".as_ptr(), std::ptr::null()).is_null());
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("Game not found: missing"));
+            assert!(!ds_download(std::ptr::null_mut()));
+            assert_eq!(CStr::from_ptr(ds_last_error()).to_str(), Ok("ds is null"));
+
+            let ds = build_dsapi(builder(), c"fixture".as_ptr(), cache_dir_arg.as_ptr());
+            assert!(!ds.is_null());
+            assert!(ds_download(ds));
+            let mut offset = 0;
+            assert!(ds_get_offset(ds, c"OFFSET_GWORLD".as_ptr(), &mut offset));
+            assert_eq!(offset, 0x14942840);
+            ds_free(ds);
+        }
+        assert!(cache_dir.join("dsapi_cache.json").exists());
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+//! Formatting bitflag values with the names of a flags enum.
+
+use crate::{DSAPI, short_enum_name};
+
+/// How [`DSAPI::format_flags_with`] writes a combined flag value.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, FlagFormat};
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// let format = FlagFormat::new().separator(", ").short_names(true);
+/// println!("{:?}", dsapi.format_flags_with("EObjectFlags", 0x41, &format));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlagFormat {
+    separator: String,
+    short_names: bool,
+}
+
+impl Default for FlagFormat {
+    fn default() -> Self {
+        FlagFormat { separator: " | ".to_string(), short_names: false }
+    }
+}
+
+impl FlagFormat {
+    /// Names separated by ` | `, written as they are in the dump.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Puts `separator` between the names, ` | ` by default.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+    /// Strips the enum prefix from the names, see [`DSAPI::get_enum_name_short`].
+    pub fn short_names(mut self, short_names: bool) -> Self {
+        self.short_names = short_names;
+        self
+    }
+}
+
+impl DSAPI {
+    /// Writes a combined flag value as the names of its bits, e.g. `RF_Public | RF_Transient` for
+    /// `format_flags("EObjectFlags", 0x41)`. Bits without a name are appended as one hex value (`| 0x8000`),
+    /// and 0 is written as the enum's name for 0 if it has one.
+    /// Returns `None` if the enum is not found.
+    pub fn format_flags(&self, enum_name: &str, value: u64) -> Option<String> {
+        self.format_flags_with(enum_name, value, &FlagFormat::default())
+    }
+    /// Like [`DSAPI::format_flags`], with the separator and names configured by `format`.
+    pub fn format_flags_with(&self, enum_name: &str, value: u64, format: &FlagFormat) -> Option<String> {
+        let enum_sym = self.names.get(enum_name)?;
+        let name = |bits: u64| {
+            let name = self.names.resolve(*self.enum_name_map.get(&(enum_sym, bits as i64))?);
+            Some(if format.short_names { short_enum_name(enum_name, name) } else { name })
+        };
+        if value == 0 {
+            return Some(name(0).unwrap_or("0").to_string());
+        }
+        let mut names = Vec::new();
+        let mut unknown = 0;
+        for bit in (0..64).map(|shift| 1u64 << shift).filter(|bit| value & bit != 0) {
+            match name(bit) {
+                Some(name) => names.push(name.to_string()),
+                None => unknown |= bit,
+            }
+        }
+        if unknown != 0 {
+            names.push(format!("0x{:x}", unknown));
+        }
+        Some(names.join(&format.separator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_flags() {
+        let dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(dsapi.format_flags("EObjectFlags", 0x41).unwrap(), "RF_Public | RF_Transient");
+        assert_eq!(dsapi.format_flags("EObjectFlags", 0x8003).unwrap(), "RF_Public | RF_Standalone | 0x8000");
+        assert_eq!(dsapi.format_flags("EObjectFlags", 0).unwrap(), "RF_NoFlags");
+        assert_eq!(dsapi.format_flags("EFortRarity", 0x6).unwrap(), "EFortRarity__Rare | EFortRarity__Legendary");
+        assert_eq!(dsapi.format_flags("EMissing", 1), None);
+
+        let format = FlagFormat::new().separator(", ").short_names(true);
+        assert_eq!(dsapi.format_flags_with("EFortRarity", 0x103, &format).unwrap(), "Uncommon, Rare, 0x100");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{DSAPI, MemoryReader};
+
+/// Offset of the block pointers in `FNamePool`, after the lock and the current block/cursor.
+const BLOCKS_OFFSET: u64 = 0x10;
+/// `FNameEntry`s are 2-byte aligned, entry offsets within a block are stored divided by this.
+const ENTRY_STRIDE: u64 = 2;
+/// The lower 16 bits of an FName index are the offset within a block, the rest is the block.
+const BLOCK_OFFSET_BITS: u32 = 16;
+/// Longest name an `FNameEntry` header can describe.
+const MAX_NAME_LEN: usize = 1024;
+
+/// Resolves FName indices through the chunked `FNamePool` used by UE4.23+ and UE5 (`OFFSET_GNAMES`).
+/// # Example:
+/// ```no_run
+/// # use dumpspace_api::{DSAPI, FNamePool, MemoryReader};
+/// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, module_base: u64, object: u64) -> Result<(), String> {
+/// let names = FNamePool::from_dsapi(dsapi, module_base)?;
+/// let name_offset = dsapi.get_member_offset("UObject", "NamePrivate").unwrap().offset as u64;
+/// println!("{}", names.resolve_fname(reader, object + name_offset)?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FNamePool {
+    /// Absolute address of the `FNamePool`.
+    pub address: u64,
+}
+
+impl FNamePool {
+    pub fn new(address: u64) -> Self {
+        FNamePool { address }
+    }
+    /// Locates the pool through the dump's `OFFSET_GNAMES` and the game's module base.
+    pub fn from_dsapi(dsapi: &DSAPI, module_base: u64) -> Result<Self, String> {
+        let offset = dsapi.get_offset("OFFSET_GNAMES").ok_or("OFFSET_GNAMES not found")?;
+        Ok(FNamePool::new(module_base.wrapping_add(offset)))
+    }
+    /// Returns the string of an FName's comparison index.
+    pub fn resolve(&self, reader: &impl MemoryReader, index: u32) -> Result<String, String> {
+        let block = (index >> BLOCK_OFFSET_BITS) as u64;
+        let offset = (index & ((1 << BLOCK_OFFSET_BITS) - 1)) as u64 * ENTRY_STRIDE;
+        let block_address = reader.read_ptr(self.address.wrapping_add(BLOCKS_OFFSET + block * 8))?;
+        if block_address == 0 {
+            return Err(format!("FName index {} points to an empty block", index));
+        }
+        // The block pointer comes from the target's memory and may be garbage.
+        let entry = block_address.wrapping_add(offset);
+        let header: u16 = reader.read_value(entry)?;
+        let is_wide = header & 1 != 0;
+        let len = (header >> 6) as usize;
+        if len == 0 || len > MAX_NAME_LEN {
+            return Err(format!("FName index {} has an invalid length: {}", index, len));
+        }
+        if is_wide {
+            let mut bytes = vec![0u8; len * 2];
+            reader.read(entry.wrapping_add(2), &mut bytes)?;
+            let units: Vec<u16> = bytes.chunks_exact(2).map(|unit| u16::from_le_bytes([unit[0], unit[1]])).collect();
+            Ok(String::from_utf16_lossy(&units))
+        } else {
+            let mut bytes = vec![0u8; len];
+            reader.read(entry.wrapping_add(2), &mut bytes)?;
+            // Narrow names are Latin-1.
+            Ok(bytes.into_iter().map(char::from).collect())
+        }
+    }
+    /// Reads the `FName` (comparison index and number) at `address` and returns its string,
+    /// with the `_N` suffix for numbered names.
+    pub fn resolve_fname(&self, reader: &impl MemoryReader, address: u64) -> Result<String, String> {
+        let (index, number): (u32, u32) = (reader.read_value(address)?, reader.read_value(address.wrapping_add(4))?);
+        let name = self.resolve(reader, index)?;
+        if number == 0 {
+            Ok(name)
+        } else {
+            Ok(format!("{}_{}", name, number - 1))
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::memory::tests::Buffer;
+
+    /// Writes an FNamePool at 0x1000 with one block at 0x2000 holding "None" and "Actor", and returns it.
+    pub(crate) fn name_pool(memory: &mut Buffer) -> (FNamePool, u32, u32) {
+        memory.write(0x1000 + BLOCKS_OFFSET, 0x2000_u64);
+        memory.write(0x2000, 4_u16 << 6);
+        memory.bytes[0x1002..0x1006].copy_from_slice(b"None");
+        memory.write(0x2006, 5_u16 << 6);
+        memory.bytes[0x1008..0x100d].copy_from_slice(b"Actor");
+        (FNamePool::new(0x1000), 0, 3)
+    }
+
+    #[test]
+    fn test_resolve_fname() {
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x2000] };
+        let (names, none, actor) = name_pool(&mut memory);
+        assert_eq!(names.resolve(&memory, none), Ok("None".to_string()));
+        assert_eq!(names.resolve(&memory, actor), Ok("Actor".to_string()));
+        assert!(names.resolve(&memory, 1 << BLOCK_OFFSET_BITS).is_err());
+        memory.write(0x1000 + BLOCKS_OFFSET + 8, u64::MAX - 1);
+        assert!(names.resolve(&memory, (1 << BLOCK_OFFSET_BITS) | 2).is_err());
+        assert!(FNamePool::new(u64::MAX - 4).resolve(&memory, none).is_err());
+        assert!(names.resolve_fname(&memory, u64::MAX - 2).is_err());
+
+        memory.write(0x1800, actor);
+        memory.write(0x1804, 3_u32);
+        assert_eq!(names.resolve_fname(&memory, 0x1800), Ok("Actor_2".to_string()));
+
+        let dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(FNamePool::from_dsapi(&dsapi, 0x1_4000_0000).unwrap().address, 0x1_4000_0000 + 343926400);
+        assert_eq!(FNamePool::from_dsapi(&dsapi, u64::MAX).unwrap().address, 343926400 - 1);
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use serde_derive::Serialize;
+
+use crate::intern::Sym;
+use crate::{DSAPI, FunctionInfo, FunctionParam, FunctionSig, MemberType, OffsetInfo};
+
+/// Estimated memory held by one of the lookup maps of a [`DSAPI`], see [`DSAPI::memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MapUsage {
+    pub name: &'static str,
+    pub entries: usize,
+    /// Bytes of the keys in the table, what they point to, and the table's bookkeeping.
+    pub key_bytes: usize,
+    /// Bytes of the values in the table and what they point to.
+    pub value_bytes: usize,
+}
+
+impl MapUsage {
+    pub fn total_bytes(&self) -> usize {
+        self.key_bytes + self.value_bytes
+    }
+}
+
+/// Estimated memory held by a parsed dump, created with [`DSAPI::memory_usage`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MemoryReport {
+    /// One entry per map, largest first.
+    pub maps: Vec<MapUsage>,
+}
+
+impl MemoryReport {
+    pub fn total_bytes(&self) -> usize {
+        self.maps.iter().map(MapUsage::total_bytes).sum()
+    }
+    /// Returns the usage of the map called `name`, e.g. `class_member_map`.
+    pub fn get(&self, name: &str) -> Option<&MapUsage> {
+        self.maps.iter().find(|map| map.name == name)
+    }
+}
+
+/// Bytes a value owns on the heap, on top of its own size.
+pub(crate) trait HeapSize {
+    fn heap_bytes(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for Sym {}
+impl HeapSize for i32 {}
+impl HeapSize for i64 {}
+impl HeapSize for u64 {}
+impl HeapSize for OffsetInfo {}
+impl<A: HeapSize, B: HeapSize> HeapSize for (A, B) {
+    fn heap_bytes(&self) -> usize {
+        self.0.heap_bytes() + self.1.heap_bytes()
+    }
+}
+
+impl HeapSize for String {
+    fn heap_bytes(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for Arc<str> {
+    fn heap_bytes(&self) -> usize {
+        // The strong and weak counts are stored next to the string.
+        self.len() + 2 * size_of::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_bytes(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_bytes).sum::<usize>()
+    }
+}
+
+impl HeapSize for MemberType {
+    fn heap_bytes(&self) -> usize {
+        self.name.heap_bytes() + self.kind.heap_bytes() + self.modifier.heap_bytes() + self.template_args.heap_bytes()
+    }
+}
+
+impl HeapSize for FunctionInfo {
+    fn heap_bytes(&self) -> usize {
+        self.flags.heap_bytes() + self.params.heap_bytes()
+    }
+}
+
+impl HeapSize for FunctionParam {
+    fn heap_bytes(&self) -> usize {
+        self.name.heap_bytes() + self.ty.heap_bytes()
+    }
+}
+
+impl HeapSize for FunctionSig {
+    fn heap_bytes(&self) -> usize {
+        self.return_type.heap_bytes() + self.params.heap_bytes()
+    }
+}
+
+/// Estimates a hash map as a table of `capacity` buckets plus one control byte per bucket.
+pub(crate) fn map_usage<K: HeapSize, V: HeapSize, S>(name: &'static str, map: &std::collections::HashMap<K, V, S>) -> MapUsage {
+    let buckets = map.capacity();
+    MapUsage {
+        name,
+        entries: map.len(),
+        key_bytes: buckets * (size_of::<K>() + 1) + map.keys().map(HeapSize::heap_bytes).sum::<usize>(),
+        value_bytes: buckets * size_of::<V>() + map.values().map(HeapSize::heap_bytes).sum::<usize>(),
+    }
+}
+
+impl DSAPI {
+    /// Estimates the bytes held by each lookup map, to weigh keeping several games loaded against dropping
+    /// what isn't needed. Estimates count the table capacity and the heap memory of keys and values,
+    /// but not allocator overhead, so the real footprint is somewhat larger.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let dsapi = DsApiMock::new().with_member("UWorld", "OwningGameInstance", 0x228, 8).build();
+    /// let report = dsapi.memory_usage();
+    /// assert_eq!(report.get("class_member_map").unwrap().entries, 1);
+    /// for map in &report.maps {
+    ///     println!("{}: {} entries, {} bytes", map.name, map.entries, map.total_bytes());
+    /// }
+    /// ```
+    pub fn memory_usage(&self) -> MemoryReport {
+        let mut maps = vec![
+            self.names.memory_usage(),
+            map_usage("class_member_map", &self.class_member_map),
+            map_usage("class_size_map", &self.class_size_map),
+            map_usage("class_super_map", &self.class_super_map),
+            map_usage("class_package_map", &self.class_package_map),
+            map_usage("ambiguous_class_map", &self.ambiguous_class_map),
+            map_usage("member_type_map", &self.member_type_map),
+            map_usage("function_map", &self.function_map),
+            map_usage("function_sig_map", &self.function_sig_map),
+            map_usage("enum_name_map", &self.enum_name_map),
+            map_usage("offset_map", &self.offset_map),
+        ];
+        if let Some(member_hashes) = self.member_hashes.get() {
+            maps.push(map_usage("member_hashes", member_hashes));
+        }
+        if let Some(folded_names) = &self.folded_names {
+            maps.extend(folded_names.memory_usage());
+        }
+        maps.sort_by_key(|map| std::cmp::Reverse(map.total_bytes()));
+        MemoryReport { maps }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LookupMode;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_memory_usage() {
+        let mut dsapi = fixture_dsapi();
+        let report = dsapi.memory_usage();
+        assert!(report.maps.windows(2).all(|pair| pair[0].total_bytes() >= pair[1].total_bytes()));
+        let members = report.get("class_member_map").unwrap();
+        assert_eq!(members.entries, dsapi.stats().members);
+        assert!(members.value_bytes >= members.entries * size_of::<crate::OffsetInfo>());
+        assert!(report.get("names").unwrap().key_bytes > 0);
+        assert!(report.get("folded_names").is_none());
+
+        dsapi.set_lookup_mode(LookupMode::CaseInsensitive);
+        let folded = dsapi.memory_usage();
+        assert!(folded.get("folded_names").is_some());
+        assert!(folded.total_bytes() > report.total_bytes());
+    }
+}
@@ -0,0 +1,212 @@
+use std::path::Path;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::intern::Sym;
+use crate::{DSAPI, OffsetInfo};
+
+/// The first bytes of every zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// A read-only, compact copy of a parsed dump, created with [`DSAPI::freeze`].
+///
+/// All entries live in sorted `Vec`s and are found with binary search, and every name is stored once
+/// in a sorted string table. Compared to the hash maps of `DSAPI` this uses less memory and has better
+/// cache locality, and it can be written to and loaded from a snapshot file without the game list.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::DSAPI;
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// let frozen = dsapi.freeze();
+/// frozen.save_snapshot("fortnite.snapshot").unwrap();
+/// println!("{:?}", frozen.get_member_offset("UWorld", "OwningGameInstance"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrozenDump {
+    /// Sorted and deduplicated, so a `Sym` compares like the string it points to.
+    pub(crate) names: Vec<Box<str>>,
+    pub(crate) members: Vec<(Sym, Sym, OffsetInfo)>,
+    pub(crate) class_sizes: Vec<(Sym, i32)>,
+    pub(crate) enum_names: Vec<(Sym, i64, Sym)>,
+    pub(crate) offsets: Vec<(Box<str>, u64)>,
+
+    pub engine: String,
+    pub location: String,
+}
+
+impl FrozenDump {
+    pub(crate) fn from_dsapi(dsapi: &DSAPI) -> Self {
+        let mut names: Vec<Box<str>> = dsapi.names.iter().map(Box::from).collect();
+        names.sort_unstable();
+        let remap = |sym: Sym| -> Sym {
+            let index = names.binary_search_by(|name| (**name).cmp(dsapi.names.resolve(sym))).unwrap();
+            Sym(index as u32)
+        };
+
+        let mut members: Vec<_> = dsapi.class_member_map.iter()
+            .map(|((class, member), info)| (remap(*class), remap(*member), info.clone()))
+            .collect();
+        members.sort_unstable_by_key(|(class, member, _)| (*class, *member));
+        let mut class_sizes: Vec<_> = dsapi.class_size_map.iter()
+            .map(|(class, size)| (remap(*class), *size))
+            .collect();
+        class_sizes.sort_unstable_by_key(|(class, _)| *class);
+        let mut enum_names: Vec<_> = dsapi.enum_name_map.iter()
+            .map(|((enum_name, value), value_name)| (remap(*enum_name), *value, remap(*value_name)))
+            .collect();
+        enum_names.sort_unstable_by_key(|(enum_name, value, _)| (*enum_name, *value));
+        let mut offsets: Vec<_> = dsapi.offset_map.iter()
+            .map(|(name, offset)| (Box::from(name.as_str()), *offset))
+            .collect();
+        offsets.sort_unstable();
+
+        FrozenDump {
+            names,
+            members,
+            class_sizes,
+            enum_names,
+            offsets,
+            engine: dsapi.engine.clone(),
+            location: dsapi.location.clone(),
+        }
+    }
+
+    fn sym(&self, name: &str) -> Option<Sym> {
+        self.names.binary_search_by(|probe| (**probe).cmp(name)).ok().map(|index| Sym(index as u32))
+    }
+
+    pub(crate) fn resolve(&self, sym: Sym) -> &str {
+        &self.names[sym.0 as usize]
+    }
+
+    /// Writes the frozen dump to a snapshot file that can be loaded with [`FrozenDump::load_snapshot`].
+    pub fn save_snapshot(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let serialized = serde_json::to_vec(self).map_err(|e| format!("Failed to serialize snapshot: {}", e))?;
+        std::fs::write(path, serialized).map_err(|e| format!("Failed to write snapshot file: {}", e))
+    }
+
+    /// Like [`FrozenDump::save_snapshot`], but compresses the snapshot with zstd at `level` (1 to 22, 0 for
+    /// the default of 3). Snapshots of big games get many times smaller, for a few milliseconds of decompression
+    /// in `load_snapshot`. Requires the `zstd` feature.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{DSAPI, FrozenDump};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.freeze().save_snapshot_zstd("fortnite.snapshot.zst", 19).unwrap();
+    /// let frozen = FrozenDump::load_snapshot("fortnite.snapshot.zst").unwrap();
+    /// ```
+    #[cfg(feature = "zstd")]
+    pub fn save_snapshot_zstd(&self, path: impl AsRef<Path>, level: i32) -> Result<(), String> {
+        let serialized = serde_json::to_vec(self).map_err(|e| format!("Failed to serialize snapshot: {}", e))?;
+        let compressed = zstd::encode_all(serialized.as_slice(), level).map_err(|e| format!("Failed to compress snapshot: {}", e))?;
+        std::fs::write(path, compressed).map_err(|e| format!("Failed to write snapshot file: {}", e))
+    }
+
+    /// Loads a frozen dump from a snapshot file written by [`FrozenDump::save_snapshot`], or by
+    /// [`FrozenDump::save_snapshot_zstd`] if the `zstd` feature is enabled.
+    pub fn load_snapshot(path: impl AsRef<Path>) -> Result<Self, String> {
+        let serialized = std::fs::read(path).map_err(|e| format!("Failed to read snapshot file: {}", e))?;
+        if serialized.starts_with(&ZSTD_MAGIC) {
+            #[cfg(feature = "zstd")]
+            {
+                let decompressed = zstd::decode_all(serialized.as_slice()).map_err(|e| format!("Failed to decompress snapshot: {}", e))?;
+                return serde_json::from_slice(&decompressed).map_err(|e| format!("Failed to deserialize snapshot: {}", e));
+            }
+            #[cfg(not(feature = "zstd"))]
+            return Err("Snapshot is zstd-compressed, loading it requires the `zstd` feature".to_string());
+        }
+        serde_json::from_slice(&serialized).map_err(|e| format!("Failed to deserialize snapshot: {}", e))
+    }
+
+    /// Returns the offset info for a class member as an `Option<OffsetInfo>`.
+    pub fn get_member_offset(&self, class_name: &str, member_name: &str) -> Option<OffsetInfo> {
+        let key = (self.sym(class_name)?, self.sym(member_name)?);
+        self.members.binary_search_by_key(&key, |(class, member, _)| (*class, *member))
+            .ok()
+            .map(|index| self.members[index].2.clone())
+    }
+    /// Returns the size of a class as an `Option<i32>`.
+    /// Returns `None` if the class is not found.
+    pub fn get_class_size(&self, class_name: &str) -> Option<i32> {
+        let class = self.sym(class_name)?;
+        self.class_sizes.binary_search_by_key(&class, |(class, _)| *class)
+            .ok()
+            .map(|index| self.class_sizes[index].1)
+    }
+    /// Returns the name of an enum value as an `Option<String>`.
+    /// Returns `None` if the enum name or value is not found.
+    pub fn get_enum_name(&self, enum_name: &str, enum_value: i64) -> Option<String> {
+        let key = (self.sym(enum_name)?, enum_value);
+        self.enum_names.binary_search_by_key(&key, |(enum_name, value, _)| (*enum_name, *value))
+            .ok()
+            .map(|index| self.resolve(self.enum_names[index].2).to_string())
+    }
+    /// Returns the offset of a specific offset name as an `Option<u64>`.
+    /// Returns `None` if the offset name is not found.
+    pub fn get_offset(&self, offset_name: &str) -> Option<u64> {
+        self.offsets.binary_search_by(|(name, _)| (**name).cmp(offset_name))
+            .ok()
+            .map(|index| self.offsets[index].1)
+    }
+    /// Returns the first of several spellings of a global offset that exists, see [`DSAPI::get_offset_any`].
+    pub fn get_offset_any(&self, offset_names: &[&str]) -> Option<u64> {
+        offset_names.iter().find_map(|offset_name| self.get_offset(offset_name))
+    }
+    /// Returns the offset info for a class member with an .unwrap() and cast to usize.
+    /// This function will panic if the member is not found.
+    pub fn get_member_offset_unchecked(&self, class_name: &str, member_name: &str) -> usize {
+        self.get_member_offset(class_name, member_name)
+            .unwrap_or_else(|| panic!("Member not found: {}::{}", class_name, member_name))
+            .offset as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen_matches_dsapi() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let frozen = dsapi.freeze();
+        assert_eq!(frozen.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert_eq!(frozen.get_member_offset("UWorld", "bIsWorldInitialized").unwrap().bit_offset, 2);
+        assert!(frozen.get_member_offset("UWorld", "X").is_none());
+        assert_eq!(frozen.get_class_size("AActor"), dsapi.get_class_size("AActor"));
+        assert_eq!(frozen.get_enum_name("EFortRarity", 3), Some("EFortRarity__Epic".to_string()));
+        assert_eq!(frozen.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert_eq!(frozen.get_offset("NO_OFFSET"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Member not found: UWorld::NoMember")]
+    fn test_unchecked_panic_message() {
+        crate::tests::fixture_dsapi().freeze().get_member_offset_unchecked("UWorld", "NoMember");
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let frozen = crate::tests::fixture_dsapi().freeze();
+        std::fs::create_dir_all("temp/test_snapshot").unwrap();
+        frozen.save_snapshot("temp/test_snapshot/fixture.snapshot").expect("Failed to save snapshot");
+        let loaded = FrozenDump::load_snapshot("temp/test_snapshot/fixture.snapshot").expect("Failed to load snapshot");
+        assert_eq!(loaded.get_member_offset_unchecked("FVector", "Y"), 8);
+        std::fs::remove_dir_all("temp/test_snapshot").expect("Failed to clean up snapshot directory");
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_zstd_snapshot_roundtrip() {
+        let frozen = crate::tests::fixture_dsapi().freeze();
+        std::fs::create_dir_all("temp/test_zstd_snapshot").unwrap();
+        frozen.save_snapshot_zstd("temp/test_zstd_snapshot/fixture.snapshot.zst", 19).expect("Failed to save snapshot");
+        let compressed = std::fs::read("temp/test_zstd_snapshot/fixture.snapshot.zst").unwrap();
+        assert!(compressed.starts_with(&ZSTD_MAGIC));
+        assert!(compressed.len() < serde_json::to_vec(&frozen).unwrap().len());
+        let loaded = FrozenDump::load_snapshot("temp/test_zstd_snapshot/fixture.snapshot.zst").expect("Failed to load snapshot");
+        assert_eq!(loaded.get_member_offset_unchecked("FVector", "Y"), 8);
+        std::fs::remove_dir_all("temp/test_zstd_snapshot").expect("Failed to clean up snapshot directory");
+    }
+}
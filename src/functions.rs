@@ -0,0 +1,329 @@
+//! Functions from the FunctionsInfo blob.
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{BlobKind, DSAPI, FunctionBlob, MemberType, ParseMode, ParseWarning, ParsedData, ParsedFunction};
+
+/// A function as described in the FunctionsInfo blob. More fields may be added, construct it with [`FunctionInfo::new`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct FunctionInfo {
+    /// Offset of the native implementation from the module base.
+    pub offset: u64,
+    /// The `EFunctionFlags` as named in the dump, e.g. `["Final", "Native", "Public", "BlueprintCallable"]`.
+    pub flags: Vec<String>,
+    /// Names of the parameters in declaration order, without the return value.
+    pub params: Vec<String>,
+    /// Index into the class' virtual function table, for dumps that record one.
+    pub vtable_index: Option<u32>,
+}
+
+impl FunctionInfo {
+    /// A function at `offset` from the module base, without flags or parameters.
+    pub fn new(offset: u64) -> Self {
+        FunctionInfo { offset, ..Default::default() }
+    }
+    /// Sets the function flags, e.g. `&["Final", "Native"]`.
+    pub fn with_flags(mut self, flags: &[&str]) -> Self {
+        self.flags = flags.iter().map(|flag| flag.to_string()).collect();
+        self
+    }
+    /// Sets the parameter names in declaration order.
+    pub fn with_params(mut self, params: &[&str]) -> Self {
+        self.params = params.iter().map(|param| param.to_string()).collect();
+        self
+    }
+    /// Sets the index into the class' virtual function table.
+    pub fn with_vtable_index(mut self, vtable_index: u32) -> Self {
+        self.vtable_index = Some(vtable_index);
+        self
+    }
+    /// Returns `true` if the function has the flag, e.g. `has_flag("Native")`.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|own| own == flag)
+    }
+    /// Converts a function array as stored in the blob:
+    /// `[return type, [[param type, "&" or "", param name], ...], offset, "Flag|Flag", vtable index?]`.
+    pub(crate) fn from_json(value: &serde_json::Value) -> Result<Self, String> {
+        let parts = value.as_array().ok_or("Expected a function array")?;
+        let params = parts.get(1).and_then(|params| params.as_array()).ok_or("Missing parameter list")?
+            .iter()
+            .map(|param| param.get(2).and_then(|name| name.as_str()).map(str::to_string).ok_or("Malformed parameter"))
+            .collect::<Result<_, _>>()?;
+        let offset = parts.get(2).and_then(|offset| offset.as_u64()).ok_or("Missing offset")?;
+        let flags = parts.get(3).and_then(|flags| flags.as_str()).unwrap_or_default()
+            .split('|')
+            .map(str::trim)
+            .filter(|flag| !flag.is_empty())
+            .map(str::to_string)
+            .collect();
+        let vtable_index = parts.get(4).and_then(|index| index.as_u64()).and_then(|index| u32::try_from(index).ok());
+        Ok(FunctionInfo { offset, flags, params, vtable_index })
+    }
+}
+
+/// The parameter and return types of a function, e.g. to generate stubs that call it through `ProcessEvent`.
+/// Displayed like a declaration: `bool (FVector NewLocation, FHitResult& SweepHitResult)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct FunctionSig {
+    /// `void` for functions without a return value.
+    pub return_type: MemberType,
+    pub params: Vec<FunctionParam>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct FunctionParam {
+    pub name: String,
+    pub ty: MemberType,
+    /// Passed by reference, which for Blueprint functions usually makes it an out parameter.
+    pub by_ref: bool,
+}
+
+impl FunctionSig {
+    /// Converts the types of a function array, returning `None` if any of them is malformed.
+    pub(crate) fn from_json(value: &serde_json::Value) -> Option<Self> {
+        let parts = value.as_array()?;
+        let return_type = MemberType::from_json(parts.first()?)?;
+        let params = parts.get(1)?.as_array()?.iter()
+            .map(|param| {
+                Some(FunctionParam {
+                    name: param.get(2)?.as_str()?.to_string(),
+                    ty: MemberType::from_json(param.get(0)?)?,
+                    by_ref: param.get(1)?.as_str()? == "&",
+                })
+            })
+            .collect::<Option<_>>()?;
+        Some(FunctionSig { return_type, params })
+    }
+}
+
+impl std::fmt::Display for FunctionSig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} (", self.return_type)?;
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}{} {}", param.ty, if param.by_ref { "&" } else { "" }, param.name)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl DSAPI {
+    pub(crate) fn parse_function_info(&mut self, functions_info: FunctionBlob) -> Result<(), String> {
+        let _span = trace_span!("parse_blob", blob = BlobKind::Functions.file_name());
+        self.blob_meta.insert(BlobKind::Functions, crate::BlobMeta::of(&functions_info));
+        let mode = self.options.parse_mode;
+        let mut data = ParsedData::new(BlobKind::Functions);
+        for (class_name, functions) in functions_info.data.into_iter().flatten() {
+            for (function_name, entry) in functions.into_iter().flatten() {
+                match FunctionInfo::from_json(&entry) {
+                    Ok(info) => {
+                        let sig = FunctionSig::from_json(&entry);
+                        data.functions.push(ParsedFunction { class: class_name.clone(), name: function_name, info, sig });
+                    }
+                    Err(message) => {
+                        let warning = ParseWarning {
+                            blob: BlobKind::Functions,
+                            class: Some(class_name.clone()),
+                            member: Some(function_name),
+                            message: message.to_string(),
+                            snippet: Some(entry.to_string()),
+                        };
+                        match mode {
+                            ParseMode::Lenient => self.parse_warnings.push(warning),
+                            ParseMode::Strict => return Err(format!("Failed to parse {}", warning)),
+                        }
+                    }
+                }
+            }
+        }
+        self.post_parse_hooks.run(&mut data);
+
+        for ParsedFunction { class, name, info, sig } in data.functions {
+            let key = (self.names.intern(&class), self.names.intern(&name));
+            match sig {
+                Some(sig) => self.function_sig_map.insert(key, sig),
+                None => self.function_sig_map.remove(&key),
+            };
+            self.function_map.insert(key, info);
+        }
+        trace_event!(functions = self.function_map.len(), "functions parsed");
+        Ok(())
+    }
+    /// Returns the offset, flags and parameter names of a function as an `Option<FunctionInfo>`.
+    /// Returns `None` if the function is not found.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let info = dsapi.get_function_info("AActor", "K2_GetActorLocation").unwrap();
+    /// println!("0x{:x} {:?}", info.offset, info.flags);
+    /// ```
+    pub fn get_function_info(&self, function_class: &str, function_name: &str) -> Option<FunctionInfo> {
+        let key = (self.names.get(function_class)?, self.names.get(function_name)?);
+        self.function_map.get(&key).cloned()
+    }
+    /// Returns every function a class declares as `(name, info)`, sorted by name.
+    /// Inherited functions are not included, walk [`DSAPI::get_super_classes`] for those.
+    /// Returns an empty `Vec` if the class is not found or declares no functions.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// for (name, info) in dsapi.get_class_functions("AFortPlayerController") {
+    ///     println!("{} 0x{:x}", name, info.offset);
+    /// }
+    /// ```
+    pub fn get_class_functions(&self, class_name: &str) -> Vec<(String, FunctionInfo)> {
+        let Some(class) = self.names.get(class_name) else {
+            return Vec::new();
+        };
+        let mut functions: Vec<_> = self.function_map.iter()
+            .filter(|((function_class, _), _)| *function_class == class)
+            .map(|((_, function), info)| (self.names.resolve(*function).to_string(), info.clone()))
+            .collect();
+        functions.sort_by(|a, b| a.0.cmp(&b.0));
+        functions
+    }
+    /// Searches the functions of all classes with a wildcard pattern (`*` for any run of characters, `?` for one)
+    /// and returns the matches as `(class, function, info)`, sorted by class and function.
+    /// The pattern is matched against the function name, or against `Class::Function` if it contains `::`.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// for (class, function, info) in dsapi.find_functions("*ServerExecuteInventoryItem*") {
+    ///     println!("{}::{} 0x{:x}", class, function, info.offset);
+    /// }
+    /// let controller = dsapi.find_functions("AFortPlayerController::Server*");
+    /// ```
+    pub fn find_functions(&self, pattern: &str) -> Vec<(String, String, FunctionInfo)> {
+        let qualified = pattern.contains("::");
+        self.matching_functions(|class, function| match qualified {
+            true => crate::glob::matches(pattern, &format!("{}::{}", class, function)),
+            false => crate::glob::matches(pattern, function),
+        })
+    }
+    /// Like [`DSAPI::find_functions`], with a regular expression matched against `Class::Function`.
+    /// The expression is not anchored, so `ServerExecute` finds every function containing it.
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn find_functions_regex(&self, pattern: &str) -> Result<Vec<(String, String, FunctionInfo)>, String> {
+        let regex = regex::Regex::new(pattern).map_err(|e| format!("Invalid pattern: {}", e))?;
+        Ok(self.matching_functions(|class, function| regex.is_match(&format!("{}::{}", class, function))))
+    }
+    fn matching_functions(&self, mut matches: impl FnMut(&str, &str) -> bool) -> Vec<(String, String, FunctionInfo)> {
+        let mut functions: Vec<_> = self.functions()
+            .filter(|(class, function, _)| matches(class, function))
+            .map(|(class, function, info)| (class.to_string(), function.to_string(), info.clone()))
+            .collect();
+        functions.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        functions
+    }
+    /// Returns the parameter names and types and the return type of a function as an `Option<&FunctionSig>`.
+    /// Returns `None` if the function is not found or the blob had no valid types for it.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let sig = dsapi.get_function_signature("AActor", "K2_SetActorLocation").unwrap();
+    /// for param in &sig.params {
+    ///     println!("{}: {}", param.name, param.ty);
+    /// }
+    /// ```
+    pub fn get_function_signature(&self, function_class: &str, function_name: &str) -> Option<&FunctionSig> {
+        self.function_sig_map.get(&(self.names.get(function_class)?, self.names.get(function_name)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_info() {
+        let value = serde_json::json!([["void", "D", "", []], [[["FVector", "S", "", []], "&", "NewLocation"], [["bool", "D", "", []], "", "bSweep"]], 4660, "Final|Native|Public", 12]);
+        let info = FunctionInfo::from_json(&value).unwrap();
+        assert_eq!(info.params, ["NewLocation", "bSweep"]);
+        assert_eq!((info.offset, info.vtable_index), (0x1234, Some(12)));
+        assert!(info.has_flag("Native") && !info.has_flag("Static"));
+        assert_eq!(FunctionInfo::from_json(&serde_json::json!([["void", "D", "", []], [], 16, ""])).unwrap().flags, Vec::<String>::new());
+        assert_eq!(FunctionInfo::from_json(&serde_json::json!([["void", "D", "", []], []])).unwrap_err(), "Missing offset");
+
+        let dsapi = crate::tests::fixture_dsapi();
+        let info = dsapi.get_function_info("AActor", "K2_GetActorLocation").unwrap();
+        assert_eq!(info.offset, 0x2a1b3c0);
+        assert!(info.params.is_empty() && info.has_flag("BlueprintPure"));
+        assert_eq!(dsapi.get_function_info("AActor", "Missing"), None);
+        assert_eq!(dsapi.get_function_offset("UGameplayStatics", "GetPlayerController"), Some(0x3c4d5e0));
+    }
+
+    #[test]
+    fn test_class_functions() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let functions = dsapi.get_class_functions("AActor");
+        let names: Vec<&str> = functions.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["K2_GetActorLocation", "K2_SetActorLocation"]);
+        assert_eq!(functions[1].1.params.len(), 4);
+        assert!(dsapi.get_class_functions("UWorld").is_empty());
+        assert!(dsapi.get_class_functions("UMissing").is_empty());
+    }
+
+    #[test]
+    fn test_find_functions() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let names = |found: Vec<(String, String, FunctionInfo)>| -> Vec<String> {
+            found.into_iter().map(|(class, function, _)| format!("{}::{}", class, function)).collect()
+        };
+        assert_eq!(names(dsapi.find_functions("*Actor*")), ["AActor::K2_GetActorLocation", "AActor::K2_SetActorLocation"]);
+        assert_eq!(names(dsapi.find_functions("Get*")), ["UGameplayStatics::GetPlayerController"]);
+        assert_eq!(names(dsapi.find_functions("*::Get*")), ["UGameplayStatics::GetPlayerController"]);
+        assert_eq!(names(dsapi.find_functions("AActor::*Set*")), ["AActor::K2_SetActorLocation"]);
+        assert!(dsapi.find_functions("*Nope*").is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_find_functions_regex() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let found = dsapi.find_functions_regex("^AActor::K2_[GS]et").unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(dsapi.find_functions_regex("(").unwrap_err().starts_with("Invalid pattern"));
+    }
+
+    #[test]
+    fn test_function_signature() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let sig = dsapi.get_function_signature("AActor", "K2_SetActorLocation").unwrap();
+        assert_eq!(sig.to_string(), "bool (FVector NewLocation, bool bSweep, FHitResult& SweepHitResult, bool bTeleport)");
+        assert!(sig.params[2].by_ref && !sig.params[0].by_ref);
+        assert_eq!(sig.params[0].ty.kind, "S");
+        let sig = dsapi.get_function_signature("UGameplayStatics", "GetPlayerController").unwrap();
+        assert_eq!(sig.return_type.to_string(), "APlayerController*");
+        assert_eq!(dsapi.get_function_signature("AActor", "K2_GetActorLocation").unwrap().params, []);
+        assert_eq!(dsapi.get_function_signature("AActor", "Missing"), None);
+
+        // A function with an offset but unreadable types is still found, just without a signature.
+        let value = serde_json::json!([5, [], 16, "Native"]);
+        assert!(FunctionInfo::from_json(&value).is_ok() && FunctionSig::from_json(&value).is_none());
+    }
+
+    #[test]
+    fn test_parse_malformed_functions() {
+        let blob = r#"{"data":[{"AActor":[{"Broken":[["void","D","",[]],[]]},{"Fine":[["void","D","",[]],[],16,"Native"]}]}],"updated_at":"0","version":10202}"#;
+        let mut dsapi = crate::tests::fixture_dsapi();
+        dsapi.load_blob(BlobKind::Functions, blob).unwrap();
+        assert_eq!(dsapi.get_function_offset("AActor", "Fine"), Some(16));
+        assert_eq!(dsapi.parse_warnings().len(), 1);
+        assert_eq!(dsapi.parse_warnings()[0].to_string(), r#"FunctionsInfo AActor::Broken: Missing offset (in [["void","D","",[]],[]])"#);
+
+        dsapi.options.parse_mode = ParseMode::Strict;
+        assert!(dsapi.load_blob(BlobKind::Functions, blob).unwrap_err().starts_with("Failed to parse FunctionsInfo AActor::Broken"));
+    }
+}
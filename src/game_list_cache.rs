@@ -0,0 +1,75 @@
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::GameList;
+
+/// Game lists fetched by this process, per base URL.
+static MEMORY_CACHE: Mutex<Vec<(String, Instant, GameList)>> = Mutex::new(Vec::new());
+
+const CACHE_FILE: &str = "gamelist_cache.json";
+
+#[derive(Deserialize, Serialize)]
+struct DiskEntry {
+    base_url: String,
+    fetched_at: u64, // Unix timestamp
+    list: GameList,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Returns a game list for `base_url` fetched less than `ttl` ago, from memory or from `cache_dir`.
+pub(crate) fn load(base_url: &str, ttl: Duration, cache_dir: Option<&Path>) -> Option<GameList> {
+    let memory = MEMORY_CACHE.lock().unwrap();
+    if let Some((_, _, list)) = memory.iter().find(|(url, fetched, _)| url == base_url && fetched.elapsed() < ttl) {
+        return Some(list.clone());
+    }
+    drop(memory);
+
+    let serialized = std::fs::read_to_string(cache_dir?.join(CACHE_FILE)).ok()?;
+    let entry: DiskEntry = serde_json::from_str(&serialized).ok()?;
+    let age = Duration::from_secs(now_secs().saturating_sub(entry.fetched_at));
+    (entry.base_url == base_url && age < ttl).then_some(entry.list)
+}
+
+/// Remembers a freshly fetched game list in memory and, if given, in `cache_dir`.
+pub(crate) fn store(base_url: &str, list: &GameList, cache_dir: Option<&Path>) {
+    let mut memory = MEMORY_CACHE.lock().unwrap();
+    memory.retain(|(url, _, _)| url != base_url);
+    memory.push((base_url.to_string(), Instant::now(), list.clone()));
+    drop(memory);
+
+    if let Some(cache_dir) = cache_dir {
+        let entry = DiskEntry { base_url: base_url.to_string(), fetched_at: now_secs(), list: list.clone() };
+        // The disk cache is only an optimization, failing to write it is not an error.
+        if let (Ok(()), Ok(serialized)) = (std::fs::create_dir_all(cache_dir), serde_json::to_string(&entry)) {
+            let _ = std::fs::write(cache_dir.join(CACHE_FILE), serialized);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_list_cache() {
+        let list = GameList { games: Vec::new() };
+        let ttl = Duration::from_secs(60);
+        assert!(load("memory.test", ttl, None).is_none());
+        store("memory.test", &list, None);
+        assert!(load("memory.test", ttl, None).is_some());
+        assert!(load("memory.test", Duration::ZERO, None).is_none());
+
+        let cache_dir = Path::new("temp/test_game_list_cache");
+        store("disk.test", &list, Some(cache_dir));
+        MEMORY_CACHE.lock().unwrap().retain(|(url, _, _)| url != "disk.test");
+        assert!(load("disk.test", ttl, Some(cache_dir)).is_some());
+        assert!(load("other.test", ttl, Some(cache_dir)).is_none());
+        std::fs::remove_dir_all(cache_dir).expect("Failed to clean up cache directory");
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use crate::{Game, GameList};
+
+/// The games that appeared, disappeared or got a new dump between two game lists.
+#[derive(Debug, Clone, Default)]
+pub struct GameListDiff {
+    pub added: Vec<Game>,
+    pub removed: Vec<Game>,
+    /// Games with a newer `uploaded` timestamp, as they appear in the newer list.
+    pub updated: Vec<Game>,
+}
+
+impl GameListDiff {
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+}
+
+impl GameList {
+    /// Compares this (newer) game list against an `older` one.
+    pub fn diff(&self, older: &GameList) -> GameListDiff {
+        let mut diff = GameListDiff::default();
+        for game in &self.games {
+            match older.get_game_by_hash(&game.hash) {
+                None => diff.added.push(game.clone()),
+                Some(old) if game.uploaded > old.uploaded => diff.updated.push(game.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = older.games.iter()
+            .filter(|game| self.get_game_by_hash(&game.hash).is_none())
+            .cloned()
+            .collect();
+        diff
+    }
+    /// Compares this game list against the one saved at `state_path` by the previous call, then saves this one.
+    /// The first call (when there is no saved state yet) returns an empty diff.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::GameList;
+    /// let diff = GameList::init().unwrap().diff_since_last_check("last_games.json").unwrap();
+    /// for game in diff.added {
+    ///     println!("New game on dumpspace: {}", game.name);
+    /// }
+    /// ```
+    pub fn diff_since_last_check(&self, state_path: impl AsRef<Path>) -> Result<GameListDiff, String> {
+        let state_path = state_path.as_ref();
+        let diff = if state_path.exists() {
+            let serialized = std::fs::read_to_string(state_path).map_err(|e| format!("Failed to read game list state: {}", e))?;
+            let older: GameList = serde_json::from_str(&serialized).map_err(|e| format!("Failed to parse game list state: {}", e))?;
+            self.diff(&older)
+        } else {
+            GameListDiff::default()
+        };
+        let serialized = serde_json::to_string(self).map_err(|e| format!("Failed to serialize game list: {}", e))?;
+        std::fs::write(state_path, serialized).map_err(|e| format!("Failed to write game list state: {}", e))?;
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Uploader;
+
+    fn game(hash: &str, uploaded: u64) -> Game {
+        Game {
+            hash: hash.to_string(),
+            name: hash.to_string(),
+            engine: String::new(),
+            location: String::new(),
+            uploaded,
+            uploader: Uploader { name: String::new(), link: String::new() },
+        }
+    }
+
+    #[test]
+    fn test_game_list_diff() {
+        let older = GameList { games: vec![game("a", 1), game("b", 1), game("c", 1)] };
+        let newer = GameList { games: vec![game("a", 1), game("b", 2), game("d", 1)] };
+        let diff = newer.diff(&older);
+        assert_eq!(diff.added[0].hash, "d");
+        assert_eq!(diff.removed[0].hash, "c");
+        assert_eq!(diff.updated[0].hash, "b");
+        assert!(newer.diff(&newer).is_empty());
+
+        std::fs::create_dir_all("temp/test_game_list_diff").unwrap();
+        let state = "temp/test_game_list_diff/state.json";
+        assert!(older.diff_since_last_check(state).unwrap().is_empty());
+        assert_eq!(newer.diff_since_last_check(state).unwrap().added.len(), 1);
+        assert!(newer.diff_since_last_check(state).unwrap().is_empty());
+        std::fs::remove_dir_all("temp/test_game_list_diff").expect("Failed to clean up state directory");
+    }
+}
@@ -0,0 +1,48 @@
+//! Wildcard matching for name searches.
+
+/// Returns `true` if `text` matches `pattern`, where `*` matches any run of characters
+/// (including none) and `?` matches exactly one. Matching is case-sensitive.
+pub(crate) fn matches(pattern: &str, text: &str) -> bool {
+    let (pattern, text): (Vec<char>, Vec<char>) = (pattern.chars().collect(), text.chars().collect());
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text position it currently stands in for, to backtrack to.
+    let mut star = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("*ServerExecute*", "ServerExecuteInventoryItem"));
+        assert!(matches("K2_?etActor*", "K2_GetActorLocation"));
+        assert!(matches("*", ""));
+        assert!(matches("a*b*c", "aXbYbZc"));
+        assert!(!matches("a*b*c", "aXbYbZ"));
+        assert!(!matches("Server*", "ClientServer"));
+        assert!(!matches("server*", "ServerExecute"));
+        assert!(matches("exact", "exact") && !matches("exact", "exactly"));
+    }
+}
@@ -0,0 +1,190 @@
+//! Graphs of the relations between classes, e.g. for visualizing them with GraphViz.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::DSAPI;
+
+/// The types each class or struct depends on, created with [`DSAPI::type_graph`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeGraph {
+    /// Every class or struct to the types it depends on. Classes without dependencies map to an empty set.
+    pub edges: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl TypeGraph {
+    /// Orders the types so that every type comes after its dependencies, e.g. for emitting an SDK header.
+    /// Ties are broken by name. Fails with the types that are part of (or depend on) a cycle.
+    pub fn topological_order(&self) -> Result<Vec<String>, Vec<String>> {
+        let mut pending: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut dependents: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (node, dependencies) in &self.edges {
+            pending.entry(node).or_default();
+            for dependency in dependencies {
+                pending.entry(dependency).or_default();
+                *pending.get_mut(node.as_str()).unwrap() += 1;
+                dependents.entry(dependency).or_default().push(node);
+            }
+        }
+        let mut ready: BTreeSet<&str> = pending.iter().filter(|(_, count)| **count == 0).map(|(node, _)| *node).collect();
+        let mut order = Vec::with_capacity(pending.len());
+        while let Some(node) = ready.pop_first() {
+            order.push(node.to_string());
+            for dependent in dependents.get(node).into_iter().flatten() {
+                let count = pending.get_mut(dependent).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+        if order.len() == pending.len() {
+            Ok(order)
+        } else {
+            Err(pending.into_iter().filter(|(_, count)| *count > 0).map(|(node, _)| node.to_string()).collect())
+        }
+    }
+    /// Renders the graph as GraphViz DOT, with an edge from every type to each of its dependencies.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph types {\n    node [shape=box];\n");
+        for (node, dependencies) in &self.edges {
+            writeln!(dot, "    {};", dot_id(node)).unwrap();
+            for dependency in dependencies {
+                writeln!(dot, "    {} -> {};", dot_id(node), dot_id(dependency)).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Quotes a name for use as a DOT node ID.
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl DSAPI {
+    /// Generates a GraphViz DOT graph of the class hierarchy, with an edge from every class to its direct parent.
+    /// With a `root` only that class and the classes deriving from it are included, e.g. `Some("AActor")`.
+    /// Render it with e.g. `dot -Tsvg classes.dot -o classes.svg`.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("actors.dot", dsapi.export_inheritance_dot(Some("AActor"))).unwrap();
+    /// ```
+    pub fn export_inheritance_dot(&self, root: Option<&str>) -> String {
+        let root = root.map(|root| self.names.get(root));
+        let mut edges = BTreeSet::new();
+        let mut nodes = BTreeSet::new();
+        for (class, supers) in &self.class_super_map {
+            let included = match root {
+                None => true,
+                Some(None) => false,
+                Some(Some(root)) => *class == root || supers.contains(&root),
+            };
+            if !included {
+                continue;
+            }
+            nodes.insert(self.names.resolve(*class));
+            if let Some(parent) = supers.first().filter(|_| root != Some(Some(*class))) {
+                edges.insert((self.names.resolve(*class), self.names.resolve(*parent)));
+            }
+        }
+        let mut dot = String::from("digraph inheritance {\n    rankdir=BT;\n    node [shape=box];\n");
+        for node in nodes {
+            writeln!(dot, "    {};", dot_id(node)).unwrap();
+        }
+        for (class, parent) in edges {
+            writeln!(dot, "    {} -> {};", dot_id(class), dot_id(parent)).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+    /// Builds the graph of "class A contains a member of type B" edges from the member types, plus an edge
+    /// from every class to its direct parent. Only classes, structs and enums of this dump are included.
+    ///
+    /// Without `include_pointers`, pointers and the template arguments of containers (e.g. `TArray<FVector>`)
+    /// are left out, since they don't need the full definition of the type. That is the order an SDK needs.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// for class in dsapi.type_graph(false).topological_order().unwrap() {
+    ///     println!("{}", class);
+    /// }
+    /// ```
+    pub fn type_graph(&self, include_pointers: bool) -> TypeGraph {
+        let enums: BTreeSet<_> = self.enum_name_map.keys().map(|(enum_name, _)| *enum_name).collect();
+        let known = |name: &str| self.names.get(name)
+            .is_some_and(|sym| self.class_size_map.contains_key(&sym) || self.class_super_map.contains_key(&sym) || enums.contains(&sym));
+        let mut edges: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (class, supers) in &self.class_super_map {
+            let dependencies = edges.entry(self.names.resolve(*class).to_string()).or_default();
+            if let Some(parent) = supers.first() {
+                dependencies.insert(self.names.resolve(*parent).to_string());
+            }
+        }
+        for ((class, _), ty) in &self.member_type_map {
+            let class = self.names.resolve(*class);
+            let dependencies = edges.entry(class.to_string()).or_default();
+            let mut add = |name: &str| {
+                if name != class && known(name) {
+                    dependencies.insert(name.to_string());
+                }
+            };
+            if include_pointers {
+                ty.visit(&mut |ty| add(&ty.name));
+            } else if !ty.is_pointer() {
+                add(&ty.name);
+            }
+        }
+        TypeGraph { edges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_inheritance_dot() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let dot = dsapi.export_inheritance_dot(None);
+        assert!(dot.starts_with("digraph inheritance {"));
+        assert!(dot.contains("    \"UWorld\" -> \"UObject\";\n"));
+        assert!(dot.contains("    \"AActor\" -> \"UObject\";\n"));
+
+        let dot = dsapi.export_inheritance_dot(Some("AActor"));
+        assert!(dot.contains("    \"AActor\";\n"));
+        assert!(!dot.contains("UWorld") && !dot.contains("->"));
+        assert!(!dsapi.export_inheritance_dot(Some("Missing")).contains('"'));
+        assert_eq!(dot_id("a\"b"), "\"a\\\"b\"");
+    }
+
+    #[test]
+    fn test_type_graph() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        let blob = r#"{"data":[{"FHitResult":[{"__MDKClassSize":24},{"Location":[["FVector","S","",[]],0,24,1]}]},{"UBox":[{"__InheritInfo":["UObject"]},{"__MDKClassSize":64},{"Hit":[["FHitResult","S","",[]],40,24,1]},{"Hits":[["TArray","C","",[["FHitResult","S","",[]]]],40,16,1]},{"World":[["UWorld","C","*",[]],56,8,1]}]}],"updated_at":"0","version":10202}"#;
+        dsapi.parse_class_info(crate::BlobKind::Structs, crate::parse_blob(blob.as_bytes()).unwrap()).unwrap();
+
+        let graph = dsapi.type_graph(false);
+        assert_eq!(graph.edges["UBox"], BTreeSet::from(["FHitResult".to_string(), "UObject".to_string()]));
+        assert_eq!(graph.edges["FHitResult"], BTreeSet::from(["FVector".to_string()]));
+        assert!(dsapi.type_graph(true).edges["UBox"].contains("UWorld"));
+
+        let order = graph.topological_order().unwrap();
+        let position = |name: &str| order.iter().position(|class| class == name).unwrap();
+        assert!(position("FVector") < position("FHitResult") && position("FHitResult") < position("UBox"));
+        assert!(position("UObject") < position("UBox"));
+        assert!(graph.to_dot().contains("    \"UBox\" -> \"FHitResult\";\n"));
+
+        let mut cyclic = TypeGraph::default();
+        cyclic.edges.insert("A".to_string(), BTreeSet::from(["B".to_string()]));
+        cyclic.edges.insert("B".to_string(), BTreeSet::from(["A".to_string()]));
+        cyclic.edges.insert("C".to_string(), BTreeSet::new());
+        assert_eq!(cyclic.topological_order(), Err(vec!["A".to_string(), "B".to_string()]));
+    }
+}
@@ -0,0 +1,89 @@
+//! Queries on the class hierarchy from the `__InheritInfo` of each class.
+
+use crate::DSAPI;
+use crate::intern::Sym;
+
+impl DSAPI {
+    /// The class followed by all its super classes, nearest first. `None` if the class is not found.
+    fn inheritance_chain(&self, class_name: &str) -> Option<Vec<Sym>> {
+        let class = self.class_sym(class_name)?;
+        let supers = self.class_super_map.get(&class)?;
+        Some(std::iter::once(class).chain(supers.iter().copied()).collect())
+    }
+    /// Returns the nearest class both classes derive from, which is one of them if it derives from the other.
+    /// Returns `None` if either class is not found or they share no ancestor.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let dsapi = DsApiMock::new()
+    ///     .with_super_classes("APawn", &["AActor", "UObject"])
+    ///     .with_super_classes("AFortPlayerPawn", &["APawn", "AActor", "UObject"])
+    ///     .with_super_classes("AFortProjectile", &["AActor", "UObject"])
+    ///     .build();
+    /// assert_eq!(dsapi.common_base("AFortPlayerPawn", "AFortProjectile").as_deref(), Some("AActor"));
+    /// assert_eq!(dsapi.common_base("AFortPlayerPawn", "APawn").as_deref(), Some("APawn"));
+    /// assert_eq!(dsapi.inheritance_depth("AFortPlayerPawn"), Some(3));
+    /// ```
+    pub fn common_base(&self, class_a: &str, class_b: &str) -> Option<String> {
+        let chain_b = self.inheritance_chain(class_b)?;
+        let base = self.inheritance_chain(class_a)?.into_iter().find(|class| chain_b.contains(class))?;
+        Some(self.names.resolve(base).to_string())
+    }
+    /// Returns how many classes a class derives from, e.g. 0 for `UObject` and 2 for `AActor`'s children.
+    /// Returns `None` if the class is not found.
+    pub fn inheritance_depth(&self, class_name: &str) -> Option<usize> {
+        Some(self.class_super_map.get(&self.class_sym(class_name)?)?.len())
+    }
+    /// Returns every class deriving from `base_class`, directly or through other classes, sorted by name.
+    /// Empty if the class is not found or has no subclasses. See [`DSAPI::direct_subclasses_of`] for direct children only.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let dsapi = DsApiMock::new()
+    ///     .with_super_classes("AFortWeapon", &["AActor", "UObject"])
+    ///     .with_super_classes("AFortWeaponRanged", &["AFortWeapon", "AActor", "UObject"])
+    ///     .with_super_classes("AFortWeaponRangedForVehicle", &["AFortWeaponRanged", "AFortWeapon", "AActor", "UObject"])
+    ///     .build();
+    /// assert_eq!(dsapi.subclasses_of("AFortWeapon"), ["AFortWeaponRanged", "AFortWeaponRangedForVehicle"]);
+    /// assert_eq!(dsapi.direct_subclasses_of("AFortWeapon"), ["AFortWeaponRanged"]);
+    /// ```
+    pub fn subclasses_of(&self, base_class: &str) -> Vec<String> {
+        self.subclasses(base_class, |supers, base| supers.contains(&base))
+    }
+    /// Returns the classes whose direct parent is `base_class`, sorted by name.
+    pub fn direct_subclasses_of(&self, base_class: &str) -> Vec<String> {
+        self.subclasses(base_class, |supers, base| supers.first() == Some(&base))
+    }
+    fn subclasses(&self, base_class: &str, derives: impl Fn(&[Sym], Sym) -> bool) -> Vec<String> {
+        let Some(base) = self.class_sym(base_class) else {
+            return Vec::new();
+        };
+        let mut subclasses: Vec<String> = self.class_super_map.iter()
+            .filter(|(_, supers)| derives(supers, base))
+            .map(|(class, _)| self.names.resolve(*class).to_string())
+            .collect();
+        subclasses.sort();
+        subclasses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_hierarchy() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(dsapi.common_base("UWorld", "AActor").as_deref(), Some("UObject"));
+        assert_eq!(dsapi.common_base("UWorld", "UWorld").as_deref(), Some("UWorld"));
+        assert_eq!(dsapi.common_base("UObject", "AActor").as_deref(), Some("UObject"));
+        assert_eq!(dsapi.common_base("UWorld", "AMissing"), None);
+        assert_eq!(dsapi.inheritance_depth("UObject"), Some(0));
+        assert_eq!(dsapi.inheritance_depth("AActor"), Some(1));
+        assert_eq!(dsapi.inheritance_depth("AMissing"), None);
+        assert_eq!(dsapi.subclasses_of("UObject"), ["AActor", "UWorld"]);
+        assert_eq!(dsapi.direct_subclasses_of("UObject"), ["AActor", "UWorld"]);
+        assert!(dsapi.subclasses_of("AActor").is_empty());
+        assert!(dsapi.subclasses_of("AMissing").is_empty());
+    }
+}
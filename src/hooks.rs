@@ -0,0 +1,136 @@
+//! Hooks that transform a parsed blob before it is merged into the lookup maps.
+
+use std::sync::Arc;
+
+use crate::{BlobKind, DSAPI, FunctionInfo, FunctionSig, MemberType, OffsetInfo};
+
+/// The contents of one parsed blob, handed to the hooks added with [`DSAPI::add_post_parse_hook`].
+/// Only the list matching `kind` is filled, Classes and Structs blobs both use `classes`.
+#[derive(Debug, Clone)]
+pub struct ParsedData {
+    pub kind: BlobKind,
+    pub classes: Vec<ParsedClass>,
+    pub enums: Vec<ParsedEnum>,
+    pub functions: Vec<ParsedFunction>,
+    pub offsets: Vec<(String, u64)>,
+}
+
+impl ParsedData {
+    pub(crate) fn new(kind: BlobKind) -> Self {
+        ParsedData { kind, classes: Vec::new(), enums: Vec::new(), functions: Vec::new(), offsets: Vec::new() }
+    }
+}
+
+/// A class or struct of a Classes/Structs blob.
+#[derive(Debug, Clone)]
+pub struct ParsedClass {
+    pub name: String,
+    /// The package the class was declared in, e.g. `Engine`, if the dump records it.
+    pub package: Option<String>,
+    pub size: Option<i32>,
+    /// Super classes, starting with the direct parent.
+    pub supers: Vec<String>,
+    pub members: Vec<ParsedMember>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedMember {
+    pub name: String,
+    pub info: OffsetInfo,
+    pub ty: Option<MemberType>,
+}
+
+/// An enum of the Enums blob with its `(name, value)` pairs.
+#[derive(Debug, Clone)]
+pub struct ParsedEnum {
+    pub name: String,
+    pub values: Vec<(String, i64)>,
+}
+
+/// A function of the Functions blob.
+#[derive(Debug, Clone)]
+pub struct ParsedFunction {
+    pub class: String,
+    pub name: String,
+    pub info: FunctionInfo,
+    pub sig: Option<FunctionSig>,
+}
+
+type PostParseHook = Arc<dyn Fn(&mut ParsedData) + Send + Sync>;
+
+/// The hooks of a `DSAPI`, in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct PostParseHooks(Vec<PostParseHook>);
+
+impl PostParseHooks {
+    pub(crate) fn run(&self, data: &mut ParsedData) {
+        for hook in &self.0 {
+            hook(data);
+        }
+    }
+}
+
+impl std::fmt::Debug for PostParseHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} hooks", self.0.len())
+    }
+}
+
+impl DSAPI {
+    /// Adds a hook that runs after each blob is parsed and before its contents land in the lookup maps,
+    /// e.g. to normalize names, inject synthetic members or strip obfuscation. Hooks run in the order they
+    /// were added. Add hooks before calling `download_content`, dumps restored from the cache are not parsed again.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{BlobKind, DSAPI};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.add_post_parse_hook(Box::new(|data| {
+    ///     // Strip the obfuscation suffix of Blueprint members, e.g. `Health_3_ABCD` to `Health`.
+    ///     for class in &mut data.classes {
+    ///         for member in &mut class.members {
+    ///             if let Some((name, _)) = member.name.split_once('_') {
+    ///                 member.name = name.to_string();
+    ///             }
+    ///         }
+    ///     }
+    ///     if data.kind == BlobKind::Offsets {
+    ///         data.offsets.push(("OFFSET_CUSTOM".to_string(), 0x1234));
+    ///     }
+    /// }));
+    /// dsapi.download_content().unwrap();
+    /// ```
+    pub fn add_post_parse_hook(&mut self, hook: Box<dyn Fn(&mut ParsedData) + Send + Sync>) {
+        self.post_parse_hooks.0.push(Arc::from(hook));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+
+    #[test]
+    fn test_post_parse_hook() {
+        let mut dsapi = DSAPI::from_game(Game::default());
+        dsapi.add_post_parse_hook(Box::new(|data| {
+            for class in &mut data.classes {
+                class.name = class.name.trim_start_matches("Obf_").to_string();
+            }
+            if data.kind == BlobKind::Classes {
+                let info = OffsetInfo { offset: 0x9e0, size: 8, valid: true, ..Default::default() };
+                data.classes[0].members.push(ParsedMember { name: "Synthetic".to_string(), info, ty: None });
+            }
+            data.offsets.retain(|(name, _)| name != "OFFSET_GNAMES");
+        }));
+        dsapi.load_blob(BlobKind::Classes, r#"{"data":[{"Obf_UWorld":[{"__MDKClassSize":2536},{"PersistentLevel":[["ULevel","C","*",[]],48,8,1]}]}],"updated_at":"0","version":10202}"#).unwrap();
+        dsapi.load_blob(BlobKind::Offsets, include_bytes!("../tests/fixtures/OffsetsInfo.json")).unwrap();
+
+        assert_eq!(dsapi.get_class_size("UWorld"), Some(2536));
+        assert_eq!(dsapi.get_member_offset("UWorld", "PersistentLevel").map(|info| info.offset), Some(0x30));
+        assert_eq!(dsapi.get_member_offset("UWorld", "Synthetic").map(|info| info.offset), Some(0x9e0));
+        assert_eq!(dsapi.get_class_size("Obf_UWorld"), None);
+        assert_eq!(dsapi.get_offset("OFFSET_GNAMES"), None);
+        assert!(dsapi.get_offset("OFFSET_GWORLD").is_some());
+        assert!(dsapi.parse_warnings().is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+//! The blocking HTTP client behind downloads: `reqwest` by default, or `ureq` with the `minimal-http` feature.
+
+use std::io::Read;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+// `reqwest` and `ureq` both use the `http` crate's header map.
+#[cfg(all(feature = "blocking", not(feature = "minimal-http")))]
+use reqwest::header::{self, HeaderMap};
+#[cfg(feature = "minimal-http")]
+use ureq::http::{HeaderMap, header};
+
+/// A response body being downloaded.
+pub(crate) type Body = Box<dyn Read + Send>;
+
+/// The response to [`HttpClient::get_range`].
+pub(crate) struct RangeResponse {
+    pub(crate) body: Body,
+    pub(crate) validator: Option<String>,
+    /// `true` if the server sent only the requested range (206), `false` if it sent the whole resource (200).
+    pub(crate) partial: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum HttpClient {
+    #[cfg(feature = "blocking")]
+    Reqwest(reqwest::blocking::Client),
+    #[cfg(feature = "minimal-http")]
+    Ureq(ureq::Agent),
+}
+
+impl HttpClient {
+    /// Creates a client with an optional timeout for every request.
+    /// Uses `ureq` if the `minimal-http` feature is enabled, even if `reqwest` is too.
+    #[cfg(feature = "minimal-http")]
+    pub(crate) fn new(timeout: Option<Duration>) -> Result<Self, String> {
+        let config = ureq::Agent::config_builder().timeout_global(timeout).build();
+        Ok(HttpClient::Ureq(config.into()))
+    }
+    #[cfg(not(feature = "minimal-http"))]
+    pub(crate) fn new(timeout: Option<Duration>) -> Result<Self, String> {
+        let mut builder = reqwest::blocking::Client::builder();
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+        let client = builder.build().map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        Ok(HttpClient::Reqwest(client))
+    }
+    /// Sends a GET request and returns the body, failing on non-success status codes.
+    pub(crate) fn get(&self, url: &str) -> Result<Body, String> {
+        self.get_validated(url).map(|(body, _)| body)
+    }
+    /// Like [`HttpClient::get`], also returning the validator of the response, see [`HttpClient::validator`].
+    pub(crate) fn get_validated(&self, url: &str) -> Result<(Body, Option<String>), String> {
+        match self {
+            #[cfg(feature = "blocking")]
+            HttpClient::Reqwest(client) => match client.get(url).send() {
+                Ok(response) if response.status().is_success() => {
+                    let validator = validator(response.headers());
+                    Ok((Box::new(response), validator))
+                }
+                Ok(response) => Err(format!("Request failed with status: {}", response.status())),
+                Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+            },
+            #[cfg(feature = "minimal-http")]
+            HttpClient::Ureq(agent) => match agent.get(url).call() {
+                Ok(response) => {
+                    let validator = validator(response.headers());
+                    Ok((Box::new(response.into_body().into_reader()), validator))
+                }
+                Err(ureq::Error::StatusCode(status)) => Err(format!("Request failed with status: {}", status)),
+                Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+            },
+        }
+    }
+    /// Sends a GET request and returns the status code and body, whatever the status.
+    /// Fails only if no response arrived.
+    pub(crate) fn get_status(&self, url: &str) -> Result<(u16, Body), String> {
+        match self {
+            #[cfg(feature = "blocking")]
+            HttpClient::Reqwest(client) => match client.get(url).send() {
+                Ok(response) => Ok((response.status().as_u16(), Box::new(response))),
+                Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+            },
+            #[cfg(feature = "minimal-http")]
+            HttpClient::Ureq(agent) => match agent.get(url).config().http_status_as_error(false).build().call() {
+                Ok(response) => Ok((response.status().as_u16(), Box::new(response.into_body().into_reader()))),
+                Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+            },
+        }
+    }
+    /// Sends a GET request for the bytes from `start` on. With `if_range` set to the validator of the bytes
+    /// already downloaded, the server sends the whole resource instead if it changed since.
+    /// Returns `None` if `start` is past the end of the resource (416).
+    pub(crate) fn get_range(&self, url: &str, start: u64, if_range: Option<&str>) -> Result<Option<RangeResponse>, String> {
+        let range = format!("bytes={}-", start);
+        match self {
+            #[cfg(feature = "blocking")]
+            HttpClient::Reqwest(client) => {
+                let mut request = client.get(url).header(header::RANGE, range);
+                if let Some(if_range) = if_range {
+                    request = request.header(header::IF_RANGE, if_range);
+                }
+                match request.send() {
+                    Ok(response) if response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE => Ok(None),
+                    Ok(response) if response.status().is_success() => {
+                        let (validator, partial) = (validator(response.headers()), response.status() == reqwest::StatusCode::PARTIAL_CONTENT);
+                        Ok(Some(RangeResponse { body: Box::new(response), validator, partial }))
+                    }
+                    Ok(response) => Err(format!("Request failed with status: {}", response.status())),
+                    Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+                }
+            }
+            #[cfg(feature = "minimal-http")]
+            HttpClient::Ureq(agent) => {
+                let mut request = agent.get(url).header(header::RANGE, range);
+                if let Some(if_range) = if_range {
+                    request = request.header(header::IF_RANGE, if_range);
+                }
+                match request.call() {
+                    Ok(response) => {
+                        let (validator, partial) = (validator(response.headers()), response.status() == 206);
+                        Ok(Some(RangeResponse { body: Box::new(response.into_body().into_reader()), validator, partial }))
+                    }
+                    Err(ureq::Error::StatusCode(416)) => Ok(None),
+                    Err(ureq::Error::StatusCode(status)) => Err(format!("Request failed with status: {}", status)),
+                    Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+                }
+            }
+        }
+    }
+    /// Sends a POST request with a JSON body, failing on non-success status codes. The response body is ignored.
+    pub(crate) fn post_json(&self, url: &str, body: &str) -> Result<(), String> {
+        match self {
+            #[cfg(feature = "blocking")]
+            HttpClient::Reqwest(client) => match client.post(url).header(header::CONTENT_TYPE, "application/json").body(body.to_string()).send() {
+                Ok(response) if response.status().is_success() => Ok(()),
+                Ok(response) => Err(format!("Request failed with status: {}", response.status())),
+                Err(e) => Err(format!("Failed to post to URL {}: {}", url, e)),
+            },
+            #[cfg(feature = "minimal-http")]
+            HttpClient::Ureq(agent) => match agent.post(url).header(header::CONTENT_TYPE, "application/json").send(body) {
+                Ok(_) => Ok(()),
+                Err(ureq::Error::StatusCode(status)) => Err(format!("Request failed with status: {}", status)),
+                Err(e) => Err(format!("Failed to post to URL {}: {}", url, e)),
+            },
+        }
+    }
+    /// Sends a HEAD request and returns the `ETag` of the resource, or its `Last-Modified` date
+    /// if there is no `ETag`. `None` if the server sends neither.
+    pub(crate) fn validator(&self, url: &str) -> Result<Option<String>, String> {
+        match self {
+            #[cfg(feature = "blocking")]
+            HttpClient::Reqwest(client) => match client.head(url).send() {
+                Ok(response) if response.status().is_success() => Ok(validator(response.headers())),
+                Ok(response) => Err(format!("Request failed with status: {}", response.status())),
+                Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+            },
+            #[cfg(feature = "minimal-http")]
+            HttpClient::Ureq(agent) => match agent.head(url).call() {
+                Ok(response) => Ok(validator(response.headers())),
+                Err(ureq::Error::StatusCode(status)) => Err(format!("Request failed with status: {}", status)),
+                Err(e) => Err(format!("Failed to fetch URL {}: {}", url, e)),
+            },
+        }
+    }
+}
+
+/// The `ETag` header, or `Last-Modified` if there is none.
+fn validator(headers: &HeaderMap) -> Option<String> {
+    headers.get(header::ETAG)
+        .or_else(|| headers.get(header::LAST_MODIFIED))
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// An [`HttpClient`] that is created on the first request, so dumps that are never downloaded
+/// (cache restores, bundles, mocks) don't create one. Clones share the client.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LazyClient {
+    timeout: Option<Duration>,
+    client: Arc<OnceLock<HttpClient>>,
+}
+
+impl LazyClient {
+    /// A client with an optional timeout for every request, created when it is first used.
+    pub(crate) fn new(timeout: Option<Duration>) -> Self {
+        LazyClient { timeout, client: Arc::default() }
+    }
+    /// Wraps a client that was already created, e.g. one configured by the user.
+    pub(crate) fn ready(client: HttpClient) -> Self {
+        LazyClient { timeout: None, client: Arc::new(OnceLock::from(client)) }
+    }
+    /// Returns the client, creating it first if this is the first request.
+    pub(crate) fn get_or_create(&self) -> Result<&HttpClient, String> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = HttpClient::new(self.timeout)?;
+        // Another thread may have created one in the meantime, then that one is used.
+        Ok(self.client.get_or_init(|| client))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreachable_url() {
+        let client = HttpClient::new(Some(Duration::from_secs(5))).unwrap();
+        let error = client.get("http://127.0.0.1:9/GameList.json").err().unwrap();
+        assert!(error.starts_with("Failed to fetch URL http://127.0.0.1:9/GameList.json"));
+
+        // Offline dumps never create a client.
+        let lazy = LazyClient::new(Some(Duration::from_secs(5)));
+        let offline = lazy.clone();
+        assert!(crate::tests::fixture_dsapi().options.client.client.get().is_none());
+        assert!(lazy.client.get().is_none());
+        let error = lazy.get_or_create().unwrap().get("http://127.0.0.1:9/GameList.json").err().unwrap();
+        assert!(error.starts_with("Failed to fetch URL"));
+        assert!(offline.client.get().is_some());
+    }
+}
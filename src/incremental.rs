@@ -0,0 +1,150 @@
+//! Incremental refresh: blobs that did not change on the server are taken over from the previous dump.
+
+use crate::{BlobKind, DSAPI, Diagnostic};
+
+impl DSAPI {
+    /// The selected blobs whose `ETag` or `Last-Modified` header on the server still matches the one recorded
+    /// when they were downloaded. Blobs without a recorded validator, or whose HEAD request fails, count as changed.
+    /// Classes and Structs share the member maps, so they are only unchanged together.
+    pub(crate) fn unchanged_blobs(&self) -> Vec<BlobKind> {
+        let mut unchanged: Vec<BlobKind> = self.options.blobs.iter()
+            .copied()
+            // Overridden entries are written into the maps, taking them over would copy the overlay too.
+            .filter(|blob| !self.overlay.overrides(*blob))
+            .filter(|blob| {
+                let Some(validator) = self.blob_validators.get(blob) else {
+                    return false;
+                };
+                let url = self.options.blob_url(&self.engine, &self.location, *blob);
+                self.options.validator(&url).ok().flatten().as_ref() == Some(validator)
+            })
+            .collect();
+        let members_changed = [BlobKind::Classes, BlobKind::Structs].iter()
+            .any(|blob| self.options.blobs.contains(blob) && !unchanged.contains(blob));
+        if members_changed {
+            unchanged.retain(|blob| !matches!(blob, BlobKind::Classes | BlobKind::Structs));
+        }
+        unchanged
+    }
+    /// Copies the parsed data of `blobs` from `previous`, a dump of the same game.
+    /// Must be called before anything is parsed, the copied maps refer to `previous`'s names.
+    pub(crate) fn reuse_blobs(&mut self, previous: &DSAPI, blobs: &[BlobKind]) {
+        self.names = previous.names.clone();
+        for blob in blobs {
+            match blob {
+                BlobKind::Classes | BlobKind::Structs => {
+                    self.class_member_map.clone_from(&previous.class_member_map);
+                    self.class_size_map.clone_from(&previous.class_size_map);
+                    self.class_super_map.clone_from(&previous.class_super_map);
+                    self.class_package_map.clone_from(&previous.class_package_map);
+                    self.ambiguous_class_map.clone_from(&previous.ambiguous_class_map);
+                    self.member_type_map.clone_from(&previous.member_type_map);
+                }
+                BlobKind::Enums => self.enum_name_map.clone_from(&previous.enum_name_map),
+                BlobKind::Functions => {
+                    self.function_map.clone_from(&previous.function_map);
+                    self.function_sig_map.clone_from(&previous.function_sig_map);
+                }
+                BlobKind::Offsets => {
+                    self.offset_map.clone_from(&previous.offset_map);
+                    self.offsets_credit.clone_from(&previous.offsets_credit);
+                }
+            }
+            if let Some(meta) = previous.blob_meta.get(blob) {
+                self.blob_meta.insert(*blob, meta.clone());
+            }
+            if let Some(validator) = previous.blob_validators.get(blob) {
+                self.blob_validators.insert(*blob, validator.clone());
+            }
+            self.blob_stats.extend(previous.blob_stats.iter().filter(|stats| stats.blob == *blob));
+            self.parse_warnings.extend(previous.parse_warnings.iter().filter(|warning| warning.blob == *blob).cloned());
+        }
+        self.diagnostics.extend(previous.diagnostics.iter()
+            .filter(|diagnostic| diagnostic_blob(diagnostic).is_some_and(|blob| blobs.contains(&blob)))
+            .cloned());
+    }
+}
+
+/// The blob a diagnostic was found in, `None` for diagnostics across blobs.
+fn diagnostic_blob(diagnostic: &Diagnostic) -> Option<BlobKind> {
+    match diagnostic {
+        Diagnostic::DuplicateMember { blob, .. }
+        | Diagnostic::ZeroSizeMember { blob, .. }
+        | Diagnostic::NegativeOffset { blob, .. }
+        | Diagnostic::AmbiguousClass { blob, .. } => Some(*blob),
+        Diagnostic::DuplicateEnumValue { .. } => Some(BlobKind::Enums),
+        Diagnostic::KeyCollision { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use crate::tests::fixture_dsapi;
+    use crate::{BlobKind, DSAPI};
+
+    /// Serves the fixture game with an `ETag` per blob. Once `updated` is set, the game list reports a newer
+    /// upload and only OffsetsInfo changes. Counts the GET requests per blob.
+    fn updating_server(updated: Arc<AtomicBool>, downloads: Arc<[AtomicUsize; 5]>) -> String {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let updated = updated.load(Ordering::SeqCst);
+                if request.url() == "/Games/GameList.json" {
+                    let mut game_list = fixture_dsapi().game_list;
+                    game_list.games[0].uploaded += updated as u64;
+                    let _ = request.respond(tiny_http::Response::from_data(serde_json::to_vec(&game_list).unwrap()));
+                    continue;
+                }
+                let Some(index) = BlobKind::ALL.iter().position(|blob| {
+                    request.url() == format!("/Games/Unreal-Engine-5/Fixture/{}.json.gz", blob.file_name())
+                }) else {
+                    let _ = request.respond(tiny_http::Response::empty(404));
+                    continue;
+                };
+                let blob = match BlobKind::ALL[index] {
+                    BlobKind::Classes => include_str!("../tests/fixtures/ClassesInfo.json").to_string(),
+                    BlobKind::Structs => include_str!("../tests/fixtures/StructsInfo.json").to_string(),
+                    BlobKind::Enums => include_str!("../tests/fixtures/EnumsInfo.json").to_string(),
+                    BlobKind::Functions => include_str!("../tests/fixtures/FunctionsInfo.json").to_string(),
+                    BlobKind::Offsets if updated => include_str!("../tests/fixtures/OffsetsInfo.json").replace("345253952", "345254016"),
+                    BlobKind::Offsets => include_str!("../tests/fixtures/OffsetsInfo.json").to_string(),
+                };
+                let etag = format!("\"{}-{}\"", index, updated && BlobKind::ALL[index] == BlobKind::Offsets);
+                let header = tiny_http::Header::from_bytes("ETag", etag).unwrap();
+                if request.method() == &tiny_http::Method::Head {
+                    let _ = request.respond(tiny_http::Response::empty(200).with_header(header));
+                    continue;
+                }
+                downloads[index].fetch_add(1, Ordering::SeqCst);
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+                encoder.write_all(blob.as_bytes()).unwrap();
+                let _ = request.respond(tiny_http::Response::from_data(encoder.finish().unwrap()).with_header(header));
+            }
+        });
+        format!("http://127.0.0.1:{}", port)
+    }
+
+    #[test]
+    fn test_refresh_downloads_changed_blobs() {
+        let updated = Arc::new(AtomicBool::new(false));
+        let downloads = Arc::new([(); 5].map(|_| AtomicUsize::new(0)));
+        let base_url = updating_server(updated.clone(), downloads.clone());
+        let mut dsapi = DSAPI::builder().base_url(&base_url).game_id("fixture").build().unwrap();
+        dsapi.download_content().unwrap();
+        assert!(!dsapi.refresh().unwrap());
+
+        updated.store(true, Ordering::SeqCst);
+        assert!(dsapi.refresh().unwrap());
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(345254016));
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert_eq!(dsapi.get_enum_name("EFortRarity", 4), Some("EFortRarity__Legendary".to_string()));
+        assert_eq!(dsapi.stats().blobs.len(), 5);
+        let downloads: Vec<_> = downloads.iter().map(|count| count.load(Ordering::SeqCst)).collect();
+        assert_eq!(downloads, [1, 1, 1, 1, 2]);
+    }
+}
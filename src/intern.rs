@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::footprint::{MapUsage, map_usage};
+
+/// Index of a string stored in an [`Interner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub(crate) struct Sym(pub(crate) u32);
+
+/// A string arena that stores every class, member and enum name exactly once.
+/// Lookup maps are keyed by [`Sym`] pairs instead of concatenated strings, so a class name shared
+/// by thousands of members is only kept in memory a single time.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Interner {
+    names: Vec<Arc<str>>,
+    ids: FxHashMap<Arc<str>, Sym>,
+}
+
+impl Interner {
+    /// Returns the symbol for `name`, adding it to the arena if it is not interned yet.
+    pub(crate) fn intern(&mut self, name: &str) -> Sym {
+        if let Some(sym) = self.ids.get(name) {
+            return *sym;
+        }
+        let sym = Sym(self.names.len() as u32);
+        let name: Arc<str> = Arc::from(name);
+        self.names.push(name.clone());
+        self.ids.insert(name, sym);
+        sym
+    }
+
+    /// Returns the symbol for `name` without interning it.
+    pub(crate) fn get(&self, name: &str) -> Option<Sym> {
+        self.ids.get(name).copied()
+    }
+
+    /// Iterates over all interned strings in symbol order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(|name| &**name)
+    }
+
+    /// Returns the string behind a symbol.
+    pub(crate) fn resolve(&self, sym: Sym) -> &str {
+        &self.names[sym.0 as usize]
+    }
+
+    /// Estimated memory of the arena: the strings and the reverse index count as keys, the symbols as values.
+    pub(crate) fn memory_usage(&self) -> MapUsage {
+        let index = map_usage("names", &self.ids);
+        MapUsage { value_bytes: index.value_bytes + self.names.capacity() * size_of::<Arc<str>>(), ..index }
+    }
+}
+
+// Only the names are stored on disk, the reverse index is rebuilt on load.
+impl serde::Serialize for Interner {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.names.iter().map(|name| &**name))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Interner {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+        let mut interner = Interner::default();
+        for name in names {
+            interner.intern(&name);
+        }
+        Ok(interner)
+    }
+}
+
+/// Serializes a map with non-string keys (e.g. `(Sym, Sym)`) as a list of `[key, value]` pairs,
+/// since JSON object keys have to be strings.
+pub(crate) mod map_as_pairs {
+    use std::hash::Hash;
+
+    use rustc_hash::FxHashMap;
+
+    pub(crate) fn serialize<K, V, S>(map: &FxHashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: serde::Serialize,
+        V: serde::Serialize,
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub(crate) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<FxHashMap<K, V>, D::Error>
+    where
+        K: serde::Deserialize<'de> + Eq + Hash,
+        V: serde::Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        let pairs: Vec<(K, V)> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
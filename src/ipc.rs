@@ -0,0 +1,271 @@
+//! Lookups over a Unix socket or a Windows named pipe, enabled with the `ipc` feature.
+//!
+//! Every message in both directions is a little-endian `u32` byte length followed by that many bytes of JSON.
+//! Requests are `{"query": "UWorld::OwningGameInstance"}` ([`DSAPI::query`] syntax), `{"search": "UWorld::*"}`
+//! ([`DSAPI::search`]) or `"game"`. Responses are `{"ok": ...}` with the [`QueryMatch`], list of matches or
+//! game, or `{"error": "..."}`. A connection can send any number of requests, each answered in order.
+
+use std::io::{Read, Write};
+
+use serde_derive::Deserialize;
+use serde_json::json;
+
+use crate::{DSAPI, QueryMatch};
+
+/// Requests larger than this are refused, so a broken client can't make the host allocate gigabytes.
+const MAX_MESSAGE_LEN: u32 = 1 << 20;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Request {
+    Query(String),
+    Search(String),
+    Game,
+}
+
+/// Writes one length-prefixed message.
+fn write_message(stream: &mut impl Write, message: &serde_json::Value) -> std::io::Result<()> {
+    let bytes = message.to_string().into_bytes();
+    let len = u32::try_from(bytes.len()).map_err(|_| std::io::Error::other("Message too long"))?;
+    stream.write_all(&len.to_le_bytes())?;
+    stream.write_all(&bytes)?;
+    stream.flush()
+}
+
+/// Reads one length-prefixed message, `None` if the other side closed the connection.
+fn read_message(stream: &mut impl Read) -> std::io::Result<Option<Vec<u8>>> {
+    let mut len = [0; 4];
+    match stream.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let len = u32::from_le_bytes(len);
+    if len > MAX_MESSAGE_LEN {
+        return Err(std::io::Error::other(format!("Message of {} bytes is too long", len)));
+    }
+    let mut bytes = vec![0; len as usize];
+    stream.read_exact(&mut bytes)?;
+    Ok(Some(bytes))
+}
+
+impl DSAPI {
+    /// Listens on the Unix socket at `name` (a path, replaced if a stale socket is left there) or the Windows named pipe
+    /// `\\.\pipe\<name>` and answers lookups until the process exits, so a payload injected into the game can
+    /// ask this process for offsets instead of bundling an HTTP client and the whole dump.
+    /// See the [`IpcClient`] docs for the protocol. Every connection is answered on its own thread.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.serve_ipc(if cfg!(windows) { "dumpspace" } else { "/tmp/dumpspace.sock" }).unwrap();
+    /// ```
+    pub fn serve_ipc(&self, name: &str) -> Result<(), String> {
+        std::thread::scope(|scope| {
+            for stream in listen(name)? {
+                let stream = stream?;
+                scope.spawn(move || self.answer_ipc(stream));
+            }
+            Ok(())
+        })
+    }
+    /// Answers the requests of one connection until it is closed or sends something unreadable.
+    fn answer_ipc(&self, mut stream: impl Read + Write) {
+        while let Ok(Some(request)) = read_message(&mut stream) {
+            let response = match serde_json::from_slice::<Request>(&request) {
+                Ok(Request::Query(query)) => self.query(&query).map(|found| json!(found)),
+                Ok(Request::Search(pattern)) => Ok(json!(self.search(&pattern))),
+                Ok(Request::Game) => Ok(json!(self.game())),
+                Err(e) => Err(format!("Invalid request: {}", e)),
+            };
+            let response = match response {
+                Ok(value) => json!({ "ok": value }),
+                Err(error) => json!({ "error": error }),
+            };
+            if write_message(&mut stream, &response).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// A connection to [`DSAPI::serve_ipc`], for payloads written in Rust. Other languages only need to
+/// speak the protocol: a little-endian `u32` byte length, then that much JSON, in both directions.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::IpcClient;
+/// let mut client = IpcClient::connect("dumpspace").unwrap();
+/// let gworld = client.query("OFFSET_GWORLD").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct IpcClient {
+    #[cfg(unix)]
+    stream: std::os::unix::net::UnixStream,
+    #[cfg(windows)]
+    stream: std::fs::File,
+}
+
+impl IpcClient {
+    /// Connects to the Unix socket at `name` or the Windows named pipe `\\.\pipe\<name>`.
+    pub fn connect(name: &str) -> Result<Self, String> {
+        #[cfg(unix)]
+        let stream = std::os::unix::net::UnixStream::connect(name);
+        #[cfg(windows)]
+        let stream = std::fs::File::options().read(true).write(true).open(pipe_path(name));
+        let stream = stream.map_err(|e| format!("Failed to connect to {}: {}", name, e))?;
+        Ok(IpcClient { stream })
+    }
+    /// Looks up `UWorld::OwningGameInstance`, `EFortRarity::4`, `UWorld` or `OFFSET_GWORLD` like [`DSAPI::query`].
+    pub fn query(&mut self, query: &str) -> Result<QueryMatch, String> {
+        self.request(json!({ "query": query }))
+    }
+    /// Finds every entry matching a wildcard pattern like [`DSAPI::search`].
+    pub fn search(&mut self, pattern: &str) -> Result<Vec<QueryMatch>, String> {
+        self.request(json!({ "search": pattern }))
+    }
+    fn request<T: serde::de::DeserializeOwned>(&mut self, request: serde_json::Value) -> Result<T, String> {
+        write_message(&mut self.stream, &request).map_err(|e| format!("Failed to send request: {}", e))?;
+        let response = read_message(&mut self.stream)
+            .map_err(|e| format!("Failed to read response: {}", e))?
+            .ok_or("The server closed the connection")?;
+        let response: serde_json::Value = serde_json::from_slice(&response).map_err(|e| format!("Invalid response: {}", e))?;
+        match (response.get("ok"), response.get("error")) {
+            (Some(value), _) => serde_json::from_value(value.clone()).map_err(|e| format!("Invalid response: {}", e)),
+            (None, Some(error)) => Err(error.as_str().unwrap_or_default().to_string()),
+            (None, None) => Err("Invalid response: neither ok nor error".to_string()),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn listen(name: &str) -> Result<impl Iterator<Item = Result<std::os::unix::net::UnixStream, String>>, String> {
+    use std::os::unix::fs::FileTypeExt;
+
+    // A socket left behind by an earlier run would make binding fail. Anything else at the path is left alone.
+    if std::fs::symlink_metadata(name).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        let _ = std::fs::remove_file(name);
+    }
+    let listener = std::os::unix::net::UnixListener::bind(name).map_err(|e| format!("Failed to listen on {}: {}", name, e))?;
+    let mut backoff = Backoff::default();
+    Ok(std::iter::from_fn(move || loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                backoff = Backoff::default();
+                return Some(Ok(stream));
+            }
+            Err(_error) => {
+                trace_event!(error = %_error, "accepting an IPC connection failed");
+                backoff.wait();
+            }
+        }
+    }))
+}
+
+/// Growing pause after a failed accept, so errors like running out of file descriptors don't spin a core.
+struct Backoff(std::time::Duration);
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff(std::time::Duration::from_millis(10))
+    }
+}
+
+impl Backoff {
+    fn wait(&mut self) {
+        std::thread::sleep(self.0);
+        self.0 = (self.0 * 2).min(std::time::Duration::from_secs(1));
+    }
+}
+
+#[cfg(windows)]
+fn pipe_path(name: &str) -> String {
+    format!(r"\\.\pipe\{}", name)
+}
+
+/// Creates a new instance of the pipe for every client, as named pipes only connect one client per instance.
+#[cfg(windows)]
+fn listen(name: &str) -> Result<impl Iterator<Item = Result<std::fs::File, String>>, String> {
+    use std::os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle};
+    use windows_sys::Win32::Foundation::{ERROR_PIPE_CONNECTED, GetLastError, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::Storage::FileSystem::PIPE_ACCESS_DUPLEX;
+    use windows_sys::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, PIPE_READMODE_BYTE, PIPE_REJECT_REMOTE_CLIENTS, PIPE_TYPE_BYTE,
+        PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+    };
+
+    let path: Vec<u16> = pipe_path(name).encode_utf16().chain([0]).collect();
+    let create = move || {
+        let mode = PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT | PIPE_REJECT_REMOTE_CLIENTS;
+        // SAFETY: `path` is a null-terminated UTF-16 string, the handle is checked before use.
+        let handle = unsafe { CreateNamedPipeW(path.as_ptr(), PIPE_ACCESS_DUPLEX, mode, PIPE_UNLIMITED_INSTANCES, 4096, 4096, 0, std::ptr::null()) };
+        if handle == INVALID_HANDLE_VALUE {
+            return Err(format!("Failed to create pipe {}: error {}", name, unsafe { GetLastError() }));
+        }
+        // SAFETY: the handle was just created and is owned by nothing else.
+        Ok(std::fs::File::from(unsafe { OwnedHandle::from_raw_handle(handle) }))
+    };
+    // Fail right away if the pipe can't be created at all, e.g. because of an invalid name.
+    let mut next = Some(create()?);
+    let mut backoff = Backoff::default();
+    Ok(std::iter::from_fn(move || loop {
+        let pipe = match next.take() {
+            Some(pipe) => pipe,
+            None => match create() {
+                Ok(pipe) => pipe,
+                Err(e) => return Some(Err(e)),
+            },
+        };
+        // SAFETY: blocks until a client connects to this instance of the pipe.
+        let connected = unsafe { ConnectNamedPipe(pipe.as_raw_handle(), std::ptr::null_mut()) } != 0
+            || unsafe { GetLastError() } == ERROR_PIPE_CONNECTED;
+        if connected {
+            backoff = Backoff::default();
+            return Some(Ok(pipe));
+        }
+        trace_event!(error = unsafe { GetLastError() }, "connecting an IPC client failed");
+        backoff.wait();
+    }))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_ipc() {
+        let socket = std::env::temp_dir().join(format!("dumpspace_test_ipc_{}.sock", std::process::id()));
+        let name = socket.to_str().unwrap().to_string();
+        let server_name = name.clone();
+        std::thread::spawn(move || fixture_dsapi().serve_ipc(&server_name));
+        let mut client = (0..100)
+            .find_map(|_| IpcClient::connect(&name).map_err(|_| std::thread::sleep(std::time::Duration::from_millis(10))).ok())
+            .unwrap();
+
+        assert!(matches!(client.query("UWorld::OwningGameInstance"), Ok(QueryMatch::Member { info, .. }) if info.offset == 0x228));
+        assert_eq!(client.query("OFFSET_GWORLD").unwrap().to_string(), "OFFSET_GWORLD = 0x14942840");
+        assert_eq!(client.query("UWorld::Nope").unwrap_err(), "Nothing named UWorld::Nope in the dump");
+        assert_eq!(client.search("UWorld::Owning*").unwrap().len(), 1);
+
+        // Raw protocol, as a client in another language would speak it.
+        let mut raw = std::os::unix::net::UnixStream::connect(&name).unwrap();
+        write_message(&mut raw, &json!("game")).unwrap();
+        let response: serde_json::Value = serde_json::from_slice(&read_message(&mut raw).unwrap().unwrap()).unwrap();
+        assert_eq!(response["ok"]["name"], fixture_dsapi().game().name);
+        write_message(&mut raw, &json!({ "nope": 1 })).unwrap();
+        let response: serde_json::Value = serde_json::from_slice(&read_message(&mut raw).unwrap().unwrap()).unwrap();
+        assert!(response["error"].as_str().unwrap().starts_with("Invalid request"));
+        let _ = std::fs::remove_file(&socket);
+    }
+
+    #[test]
+    fn test_ipc_keeps_other_files() {
+        let path = std::env::temp_dir().join(format!("dumpspace_test_ipc_file_{}", std::process::id()));
+        std::fs::write(&path, "not a socket").unwrap();
+        let error = fixture_dsapi().serve_ipc(path.to_str().unwrap()).unwrap_err();
+        assert!(error.starts_with("Failed to listen on"), "{}", error);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,222 @@
+//! Typed member keys, generated with [`DSAPI::export_member_keys`], and keys hashed at compile time with [`ds_key!`](crate::ds_key).
+
+use std::marker::PhantomData;
+
+use rustc_hash::FxHashMap;
+
+use crate::intern::Sym;
+use crate::{DSAPI, OffsetInfo};
+
+/// A class member whose lookup hash is computed at compile time, made with [`ds_key!`](crate::ds_key).
+/// See [`DSAPI::get_member_offset_by_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DsKey {
+    class: &'static str,
+    member: &'static str,
+    hash: u64,
+}
+
+impl DsKey {
+    /// Hashes the class and member name. Prefer [`ds_key!`](crate::ds_key), which guarantees that this happens
+    /// at compile time.
+    pub const fn new(class: &'static str, member: &'static str) -> Self {
+        DsKey { class, member, hash: key_hash(class, member) }
+    }
+    /// The class the member belongs to.
+    pub fn class(&self) -> &'static str {
+        self.class
+    }
+    /// The member name as stored in the dump.
+    pub fn member(&self) -> &'static str {
+        self.member
+    }
+}
+
+/// FNV-1a of the class name, a separator that can't occur in UTF-8 and the member name.
+const fn key_hash(class: &str, member: &str) -> u64 {
+    const PRIME: u64 = 0x100000001b3;
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < class.len() {
+        hash = (hash ^ class.as_bytes()[i] as u64).wrapping_mul(PRIME);
+        i += 1;
+    }
+    hash = (hash ^ 0xff).wrapping_mul(PRIME);
+    let mut i = 0;
+    while i < member.len() {
+        hash = (hash ^ member.as_bytes()[i] as u64).wrapping_mul(PRIME);
+        i += 1;
+    }
+    hash
+}
+
+/// Makes a [`DsKey`] for a class member, hashed at compile time, for code that looks up the same members
+/// every frame with [`DSAPI::get_member_offset_by_key`].
+/// # Example:
+/// ```
+/// use dumpspace_api::{DsApiMock, DsKey, ds_key};
+/// const OWNING_GAME_INSTANCE: DsKey = ds_key!("UWorld", "OwningGameInstance");
+/// let dsapi = DsApiMock::new().with_member("UWorld", "OwningGameInstance", 0x228, 8).build();
+/// assert_eq!(dsapi.get_member_offset_by_key(OWNING_GAME_INSTANCE).unwrap().offset, 0x228);
+/// ```
+#[macro_export]
+macro_rules! ds_key {
+    ($class:expr, $member:expr $(,)?) => {
+        const { $crate::DsKey::new($class, $member) }
+    };
+}
+
+/// The members of one class as an enum, e.g. `UWorldMember::OwningGameInstance`.
+/// Implemented by the enums [`DSAPI::export_member_keys`] generates, so typos in member names are compile errors.
+pub trait MemberKey: Copy + 'static {
+    /// The class the members belong to.
+    const CLASS: &'static str;
+    /// Every member, in the order of [`MemberKey::index`].
+    const ALL: &'static [Self];
+    /// The member name as stored in the dump.
+    fn name(self) -> &'static str;
+    /// The position of the member in [`MemberKey::ALL`].
+    fn index(self) -> usize;
+}
+
+/// The offsets of every member of a [`MemberKey`] enum, resolved once with [`DSAPI::member_table`]
+/// so later lookups are an array index instead of hashing strings.
+#[derive(Debug, Clone)]
+pub struct MemberTable<K> {
+    offsets: Vec<Option<OffsetInfo>>,
+    key: PhantomData<K>,
+}
+
+impl<K: MemberKey> MemberTable<K> {
+    /// The offset info of `key`, or `None` if the member is not in the dump the table was resolved from.
+    pub fn get(&self, key: K) -> Option<&OffsetInfo> {
+        self.offsets.get(key.index())?.as_ref()
+    }
+    /// The members that are not in the dump the table was resolved from.
+    pub fn missing(&self) -> Vec<K> {
+        K::ALL.iter().copied().filter(|key| self.get(*key).is_none()).collect()
+    }
+}
+
+impl DSAPI {
+    /// Returns the offset info for a member given as a generated key, e.g. `UWorldMember::OwningGameInstance`,
+    /// as an `Option<OffsetInfo>`. See [`DSAPI::export_member_keys`].
+    /// # Example:
+    /// ```no_run
+    /// # mod keys {
+    /// #     #[derive(Clone, Copy)]
+    /// #     pub enum UWorldMember { OwningGameInstance }
+    /// #     impl dumpspace_api::MemberKey for UWorldMember {
+    /// #         const CLASS: &'static str = "UWorld";
+    /// #         const ALL: &'static [Self] = &[Self::OwningGameInstance];
+    /// #         fn name(self) -> &'static str { "OwningGameInstance" }
+    /// #         fn index(self) -> usize { self as usize }
+    /// #     }
+    /// # }
+    /// use dumpspace_api::DSAPI;
+    /// // `keys` is generated with `dsapi.export_member_keys(&["UWorld"])`.
+    /// use keys::UWorldMember;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let info = dsapi.get_member_offset_keyed(UWorldMember::OwningGameInstance).unwrap();
+    /// ```
+    pub fn get_member_offset_keyed<K: MemberKey>(&self, key: K) -> Option<OffsetInfo> {
+        self.get_member_offset(K::CLASS, key.name())
+    }
+    /// Resolves every member of `K` at once, for hot paths that look members up by key.
+    /// # Example:
+    /// ```no_run
+    /// # mod keys {
+    /// #     #[derive(Clone, Copy)]
+    /// #     pub enum UWorldMember { OwningGameInstance }
+    /// #     impl dumpspace_api::MemberKey for UWorldMember {
+    /// #         const CLASS: &'static str = "UWorld";
+    /// #         const ALL: &'static [Self] = &[Self::OwningGameInstance];
+    /// #         fn name(self) -> &'static str { "OwningGameInstance" }
+    /// #         fn index(self) -> usize { self as usize }
+    /// #     }
+    /// # }
+    /// use dumpspace_api::DSAPI;
+    /// use keys::UWorldMember;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let world = dsapi.member_table::<UWorldMember>();
+    /// let offset = world.get(UWorldMember::OwningGameInstance).unwrap().offset;
+    /// ```
+    pub fn member_table<K: MemberKey>(&self) -> MemberTable<K> {
+        MemberTable { offsets: K::ALL.iter().map(|key| self.get_member_offset_keyed(*key)).collect(), key: PhantomData }
+    }
+    /// Returns the offset info for a member given as a key made with [`ds_key!`](crate::ds_key),
+    /// as an `Option<OffsetInfo>`. Unlike [`DSAPI::get_member_offset`] no names are hashed: the first call
+    /// indexes every member by its key hash, later calls only look up the precomputed hash.
+    /// Falls back to `get_member_offset` for aliases and case-insensitive matches.
+    pub fn get_member_offset_by_key(&self, key: DsKey) -> Option<OffsetInfo> {
+        let index = self.member_hashes.get_or_init(|| self.index_member_hashes());
+        // Comparing the names guards against hash collisions, it's far cheaper than hashing them.
+        let found = index.get(&key.hash)
+            .filter(|(class, member)| self.names.resolve(*class) == key.class && self.names.resolve(*member) == key.member)
+            .and_then(|sym_key| self.class_member_map.get(sym_key));
+        match found {
+            Some(info) => Some(info.clone()),
+            None => self.get_member_offset(key.class, key.member),
+        }
+    }
+    fn index_member_hashes(&self) -> FxHashMap<u64, (Sym, Sym)> {
+        self.class_member_map.keys()
+            .map(|&(class, member)| (key_hash(self.names.resolve(class), self.names.resolve(member)), (class, member)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// What `export_member_keys` generates for `UWorld`, with a member the dump doesn't have.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum UWorldMember {
+        OwningGameInstance,
+        Removed,
+    }
+
+    impl MemberKey for UWorldMember {
+        const CLASS: &'static str = "UWorld";
+        const ALL: &'static [Self] = &[Self::OwningGameInstance, Self::Removed];
+        fn name(self) -> &'static str {
+            match self {
+                Self::OwningGameInstance => "OwningGameInstance",
+                Self::Removed => "Removed",
+            }
+        }
+        fn index(self) -> usize {
+            self as usize
+        }
+    }
+
+    #[test]
+    fn test_member_keys() {
+        let dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(dsapi.get_member_offset_keyed(UWorldMember::OwningGameInstance).map(|info| info.offset), Some(0x228));
+        assert_eq!(dsapi.get_member_offset_keyed(UWorldMember::Removed), None);
+
+        let table = dsapi.member_table::<UWorldMember>();
+        assert_eq!(table.get(UWorldMember::OwningGameInstance).map(|info| info.offset), Some(0x228));
+        assert_eq!(table.missing(), [UWorldMember::Removed]);
+    }
+
+    #[test]
+    fn test_member_offset_by_key() {
+        const OWNING_GAME_INSTANCE: DsKey = crate::ds_key!("UWorld", "OwningGameInstance");
+        let mut dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(OWNING_GAME_INSTANCE.class(), "UWorld");
+        assert_eq!(dsapi.get_member_offset_by_key(OWNING_GAME_INSTANCE).map(|info| info.offset), Some(0x228));
+        assert_eq!(dsapi.get_member_offset_by_key(crate::ds_key!("UWorld", "Removed")), None);
+        assert_ne!(crate::ds_key!("UWorld", "OwningGameInstance"), crate::ds_key!("UWorldOwning", "GameInstance"));
+
+        // The index is rebuilt when the dump changes.
+        dsapi.overlay(crate::DumpOverlay::new().member("UWorld", "OwningGameInstance", OffsetInfo::member(0x230, 8)));
+        assert_eq!(dsapi.get_member_offset_by_key(OWNING_GAME_INSTANCE).map(|info| info.offset), Some(0x230));
+        dsapi.add_member_alias("UWorld", "OwningGameInstance", &["GameInstance"]);
+        assert_eq!(dsapi.get_member_offset_by_key(crate::ds_key!("UWorld", "GameInstance")).map(|info| info.offset), Some(0x230));
+    }
+}
@@ -1,10 +1,177 @@
-use std::{collections::HashMap, io::Read};
+use std::collections::HashMap;
+use std::io::Read;
+#[cfg(network)]
+use std::sync::Arc;
+#[cfg(network)]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(any(network, feature = "tracing"))]
+use std::time::Instant;
+
+use rustc_hash::FxHashMap;
+
+#[macro_use]
+mod trace;
+
+mod alias;
+mod batch;
+mod builder;
+mod bundle;
+mod cache_store;
+mod codegen;
+mod config;
+#[cfg(network)]
+mod counting;
+mod cross_check;
+mod csharp;
+mod custom;
+#[cfg(feature = "gzip")]
+mod decompress;
+mod detect;
+mod diagnostics;
+mod diff;
+mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod flags;
+mod fname;
+mod footprint;
+mod functions;
+mod frozen;
+#[cfg(network)]
+mod game_list_cache;
+mod game_list_diff;
+mod glob;
+mod graph;
+mod hierarchy;
+mod hooks;
+#[cfg(network)]
+mod incremental;
+#[cfg(network)]
+mod http;
+mod intern;
+#[cfg(all(feature = "ipc", any(unix, windows)))]
+mod ipc;
+mod keys;
+#[cfg(all(target_os = "linux", feature = "linux-reader"))]
+mod linux_reader;
+mod lookup;
+#[cfg(network)]
+mod manager;
+mod manifest;
+#[cfg(feature = "memflow")]
+mod memflow_reader;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod math;
+mod memory;
+mod mock;
+mod naming;
+mod objects;
+mod overlay;
+mod packages;
+mod parser;
+mod path;
+#[cfg(network)]
+mod ping;
+pub mod prelude;
+#[cfg(all(feature = "python", network))]
+mod python;
+mod query;
+mod rebase;
+mod rename;
+#[cfg(all(feature = "serve", not(target_arch = "wasm32")))]
+mod rest;
+#[cfg(network)]
+mod resume;
+mod retain;
+mod scripts;
+#[cfg(all(feature = "serve", network))]
+mod serve;
+mod validate;
+mod shared;
+mod signatures;
+mod stats;
+#[cfg(feature = "tui")]
+mod tui;
+mod types;
+mod ue;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(network)]
+mod watch;
+#[cfg(network)]
+mod webhook;
+#[cfg(all(windows, feature = "windows-reader"))]
+mod windows_reader;
+pub use builder::DSAPIBuilder;
+pub use cache_store::{CacheStore, CachedGame};
+pub use codegen::CodegenSpec;
+pub use config::{Config, config, configure};
+pub use cross_check::{CrossCheckReport, Disagreement};
+pub use csharp::CSharpStyle;
+pub use custom::{UserClass, UserDump, UserMember};
+#[cfg(feature = "process-detect")]
+pub use detect::running_executables;
+use builder::DownloadOptions;
+#[cfg(network)]
+use counting::{BlobTransfer, CountingReader};
+pub use diagnostics::Diagnostic;
+pub use diff::{ChangeReport, ClassSizeChange, DumpDiff, MemberChange, OffsetChange, RegistryChanges, compare_registries};
+pub use export::Table;
+pub use flags::FlagFormat;
+pub use fname::FNamePool;
+pub use footprint::{MapUsage, MemoryReport};
+pub use functions::{FunctionInfo, FunctionParam, FunctionSig};
+pub use frozen::FrozenDump;
+pub use game_list_diff::GameListDiff;
+pub use graph::TypeGraph;
+pub use hooks::{ParsedClass, ParsedData, ParsedEnum, ParsedFunction, ParsedMember};
+#[cfg(all(feature = "ipc", any(unix, windows)))]
+pub use ipc::IpcClient;
+pub use keys::{DsKey, MemberKey, MemberTable};
+#[cfg(all(target_os = "linux", feature = "linux-reader"))]
+pub use linux_reader::LinuxProcessReader;
+pub use lookup::LookupMode;
+#[cfg(network)]
+pub use manager::{DsManager, PrefetchProgress};
+pub use manifest::Drift;
+#[cfg(feature = "mmap")]
+pub use mapped::MappedDump;
+pub use math::{FQuat, FRotator, FTransform, FVector};
+#[cfg(feature = "memflow")]
+pub use memflow_reader::MemflowReader;
+pub use memory::{MemoryReader, Pod};
+pub use mock::DsApiMock;
+pub use naming::{UeKind, classify, is_blueprint_generated};
+pub use objects::{ObjectArrayLayout, ObjectInfo, ObjectIter};
+pub use overlay::DumpOverlay;
+pub use parser::{BlobParser, FallbackParser, ParseMode, ParseWarning, RawMember, V10201Parser, V10202Parser};
+#[cfg(network)]
+pub use ping::{ServerStatus, ping};
+pub use query::QueryMatch;
+pub use rename::MemberRename;
+pub use retain::RetainSpec;
+#[cfg(all(feature = "serve", network))]
+pub use serve::CacheServer;
+pub use validate::{ValidationIssue, ValidationReport};
+pub use shared::SharedDsApi;
+pub use signatures::rip_relative;
+pub use stats::{BlobStats, DumpStats};
+pub use types::MemberType;
+pub use ue::{FTEXT_STRING_OFFSET, MAX_FSTRING_LEN, MAX_TSET_SLOTS, SetElementLayout, TArrayView, TSetView, read_fstring, read_ftext, read_tarray, read_tmap, read_tset};
+#[cfg(network)]
+pub use watch::{DumpNotifier, Watcher};
+#[cfg(network)]
+pub use webhook::Webhook;
+#[cfg(all(windows, feature = "windows-reader"))]
+pub use windows_reader::WinProcessReader;
+use intern::{Interner, Sym};
 
-use reqwest;
 use serde_derive::Serialize;
 use serde_derive::Deserialize;
 
-
+/// The dumpspace server used unless another base URL is configured.
+pub const DEFAULT_BASE_URL: &str = "https://dumpspace.spuckwaffel.com";
 
 #[derive(Debug)]
 /// The main struct for the Dumpspace API, which provides methods to interact with the Dumpspace data.
@@ -20,23 +187,81 @@ use serde_derive::Deserialize;
 /// println!("0x{:x?}", dsapi.get_class_size("AActor").unwrap());
 /// println!("0x{:x?}", dsapi.get_offset("OFFSET_GWORLD").unwrap());
 /// ```
+/// `DSAPI` is `Send + Sync`, so a parsed dump can be shared between threads behind an `Arc`.
+/// To swap in newer dumps while other threads keep reading, use [`SharedDsApi`].
 #[derive(Deserialize, Serialize)]
 pub struct DSAPI {
     game_list: GameList,
-    class_member_map: HashMap<String, OffsetInfo>,
-    class_size_map: HashMap<String, i32>,
-    function_offset_map: HashMap<String, u64>,
-    enum_name_map: HashMap<String, String>,
-    offset_map: HashMap<String, u64>,
-    game_id: String,
+    names: Interner,
+    #[serde(with = "intern::map_as_pairs")]
+    class_member_map: FxHashMap<(Sym, Sym), OffsetInfo>,
+    #[serde(with = "intern::map_as_pairs")]
+    class_size_map: FxHashMap<Sym, i32>,
+    #[serde(default, with = "intern::map_as_pairs")]
+    class_super_map: FxHashMap<Sym, Vec<Sym>>,
+    /// Package of every class that came with one (`__Package`), by the key the class is stored under.
+    #[serde(default, with = "intern::map_as_pairs")]
+    class_package_map: FxHashMap<Sym, Sym>,
+    /// Short class names declared in several packages, to the `Package.Class` keys their classes are stored under.
+    #[serde(default, with = "intern::map_as_pairs")]
+    ambiguous_class_map: FxHashMap<Sym, Vec<Sym>>,
+    #[serde(default, with = "intern::map_as_pairs")]
+    member_type_map: FxHashMap<(Sym, Sym), MemberType>,
+    #[serde(default, with = "intern::map_as_pairs")]
+    function_map: FxHashMap<(Sym, Sym), FunctionInfo>,
+    #[serde(default, with = "intern::map_as_pairs")]
+    function_sig_map: FxHashMap<(Sym, Sym), FunctionSig>,
+    #[serde(with = "intern::map_as_pairs")]
+    enum_name_map: FxHashMap<(Sym, i64), Sym>,
+    offset_map: FxHashMap<String, u64>,
+    /// The `credit` object of the Offsets blob, naming the dumper that produced the dump.
+    #[serde(default)]
+    offsets_credit: HashMap<String, String>,
+    blob_meta: HashMap<BlobKind, BlobMeta>,
+    /// `ETag` or `Last-Modified` of each downloaded blob, to skip unchanged blobs on `refresh`.
+    #[serde(default)]
+    blob_validators: HashMap<BlobKind, String>,
+    game: Game,
     downloaded_at: u64,
     cache_path: Option<std::path::PathBuf>,
+    #[serde(default)]
+    overlay: DumpOverlay,
+    /// Class name to groups of names that refer to the same member, see `add_member_alias`.
+    #[serde(default)]
+    member_aliases: HashMap<String, Vec<Vec<String>>>,
+    #[serde(default)]
+    parse_warnings: Vec<ParseWarning>,
+    #[serde(default)]
+    diagnostics: Vec<Diagnostic>,
+    #[serde(default)]
+    blob_stats: Vec<BlobStats>,
+    #[serde(skip)]
+    parsers: parser::ParserRegistry,
+    #[serde(skip)]
+    post_parse_hooks: hooks::PostParseHooks,
+    #[serde(skip)]
+    signatures: signatures::Signatures,
+    #[serde(skip)]
+    options: DownloadOptions,
+    #[serde(skip)]
+    module_base: Option<u64>,
+    #[serde(skip)]
+    folded_names: Option<lookup::FoldedNames>,
+    /// Members by the hash of their [`DsKey`], built on the first `get_member_offset_by_key`.
+    #[serde(skip)]
+    member_hashes: std::sync::OnceLock<FxHashMap<u64, (Sym, Sym)>>,
 
     pub engine: String,
     pub location: String,
 
 }
 
+// `DSAPI` is documented to be shareable between threads, keep it that way.
+const _: () = {
+    fn assert_send_sync<T: Send + Sync>() {}
+    let _ = assert_send_sync::<DSAPI>;
+};
+
 impl DSAPI {
     /// Creates a new instance of `DSAPI` for a specific game identified by its hash.
     /// This function initializes the game list and sets the engine and location based on the provided game ID.
@@ -44,29 +269,136 @@ impl DSAPI {
     /// cache_path is an optional path to a directory where the API can cache downloaded content.
     /// If caching is enabled, the API will check if the content is already cached before downloading
     /// and parsing the content. If you want to disable caching, pass `None` as the `cache_path`.
+    /// With `None`, the cache directory set with [`configure`] is still used.
+    #[cfg(network)]
     pub fn new(game_id: &str, cache_path:Option<std::path::PathBuf>) -> Self {
-        let mut ret = DSAPI {
-            game_list: GameList::init().expect("Failed to initialize game list"),
-            class_member_map: HashMap::new(),
-            class_size_map: HashMap::new(),
-            function_offset_map: HashMap::new(),
-            enum_name_map: HashMap::new(),
-            offset_map: HashMap::new(),
+        let mut builder = DSAPI::builder().game_id(game_id);
+        if let Some(cache_path) = cache_path {
+            builder = builder.cache_dir(cache_path);
+        }
+        builder.build().expect("Failed to initialize DSAPI")
+    }
+
+    /// Creates a new instance of `DSAPI` for a game identified by its name instead of its hash,
+    /// e.g. `DSAPI::from_game_name("Fortnite", NameMatch::CaseInsensitive, None)`.
+    #[cfg(network)]
+    pub fn from_game_name(game_name: &str, matching: NameMatch, cache_path: Option<std::path::PathBuf>) -> Result<Self, String> {
+        let mut builder = DSAPI::builder().game_name(game_name, matching);
+        if let Some(cache_path) = cache_path {
+            builder = builder.cache_dir(cache_path);
+        }
+        builder.build()
+    }
+
+    /// Returns a [`DSAPIBuilder`] to configure the cache, server, timeouts, retries and downloaded blobs.
+    /// It starts from the defaults set with [`configure`].
+    pub fn builder() -> DSAPIBuilder {
+        DSAPIBuilder::default().with_config(config())
+    }
+
+    #[cfg(network)]
+    pub(crate) fn with_game_list(game_id: &str, cache_path: Option<std::path::PathBuf>, game_list: GameList, options: DownloadOptions) -> Result<Self, String> {
+        let game = game_list.get_game_by_hash(game_id)
+            .ok_or_else(|| format!("Game not found: {}", game_id))?
+            .clone();
+        Ok(DSAPI::empty(game, cache_path, game_list, options))
+    }
+
+    /// Creates a `DSAPI` for `game` without downloading anything, to be filled with [`DSAPI::load_blob`].
+    /// This is all the parsing core needs, so it also works without the `reqwest` and `minimal-http` features,
+    /// e.g. for blobs fetched through your own HTTP stack or embedded in the binary.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{BlobKind, DSAPI, Game};
+    /// let game = Game::new("6b77eceb", "Fortnite", "Unreal-Engine-5", "Fortnite");
+    /// let mut dsapi = DSAPI::from_game(game);
+    /// dsapi.load_blob(BlobKind::Classes, std::fs::read("ClassesInfo.json").unwrap()).unwrap();
+    /// let offsets = std::fs::read_to_string("OffsetsInfo.json").unwrap();
+    /// dsapi.load_blob(BlobKind::Offsets, &offsets).unwrap();
+    /// println!("{:?}", dsapi.get_member_offset("UWorld", "OwningGameInstance"));
+    /// ```
+    pub fn from_game(game: Game) -> Self {
+        let game_list = GameList { games: vec![game.clone()] };
+        let mut dsapi = DSAPI::empty(game, None, game_list, DownloadOptions::default());
+        dsapi.downloaded_at = dsapi.game.uploaded;
+        dsapi
+    }
+
+    /// Parses a decompressed blob (the contents of e.g. `ClassesInfo.json`) into the lookup maps,
+    /// on top of anything loaded before. The overlay is applied again afterwards, so it still takes precedence.
+    pub fn load_blob(&mut self, kind: BlobKind, blob: impl AsRef<[u8]>) -> Result<(), String> {
+        self.ingest_blob(kind, blob.as_ref())?;
+        self.diagnose_key_collisions();
+        self.apply_overlay();
+        Ok(())
+    }
+
+    /// Like [`DSAPI::load_blob`], for a gzip-compressed blob as served by dumpspace (e.g. `ClassesInfo.json.gz`).
+    /// Blobs that turn out not to start with the gzip magic bytes are parsed as plain JSON.
+    #[cfg(feature = "gzip")]
+    pub fn load_blob_gz(&mut self, kind: BlobKind, blob: impl AsRef<[u8]>) -> Result<(), String> {
+        let decoder = decompress::BlobDecoder::new(blob.as_ref()).map_err(|e| format!("Failed to read blob: {}", e))?;
+        self.ingest_blob(kind, decoder)?;
+        self.diagnose_key_collisions();
+        self.apply_overlay();
+        Ok(())
+    }
+
+    /// Creates a `DSAPI` from the decompressed JSON of the four main blobs, e.g. small fixtures checked in
+    /// next to a test suite. The game is left empty; load functions with [`DSAPI::load_blob`] if needed.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DSAPI;
+    /// let read = |blob: &str| std::fs::read_to_string(format!("tests/fixtures/{}.json", blob)).unwrap();
+    /// let dsapi = DSAPI::from_json_blobs(&read("ClassesInfo"), &read("StructsInfo"), &read("EnumsInfo"), &read("OffsetsInfo")).unwrap();
+    /// assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+    /// ```
+    pub fn from_json_blobs(classes: &str, structs: &str, enums: &str, offsets: &str) -> Result<Self, String> {
+        let mut dsapi = DSAPI::from_game(Game::default());
+        for (kind, blob) in [(BlobKind::Classes, classes), (BlobKind::Structs, structs), (BlobKind::Enums, enums), (BlobKind::Offsets, offsets)] {
+            dsapi.ingest_blob(kind, blob.as_bytes())?;
+        }
+        dsapi.diagnose_key_collisions();
+        dsapi.apply_overlay();
+        Ok(dsapi)
+    }
+
+    /// A `DSAPI` for `game` without any parsed content.
+    pub(crate) fn empty(game: Game, cache_path: Option<std::path::PathBuf>, game_list: GameList, options: DownloadOptions) -> Self {
+        DSAPI {
+            game_list,
+            names: Interner::default(),
+            class_member_map: FxHashMap::default(),
+            class_size_map: FxHashMap::default(),
+            class_super_map: FxHashMap::default(),
+            class_package_map: FxHashMap::default(),
+            ambiguous_class_map: FxHashMap::default(),
+            member_type_map: FxHashMap::default(),
+            function_map: FxHashMap::default(),
+            function_sig_map: FxHashMap::default(),
+            enum_name_map: FxHashMap::default(),
+            offset_map: FxHashMap::default(),
+            offsets_credit: HashMap::new(),
+            blob_meta: HashMap::new(),
+            blob_validators: HashMap::new(),
             cache_path,
-            game_id: game_id.to_string(),
+            overlay: DumpOverlay::default(),
+            member_aliases: HashMap::new(),
+            parse_warnings: Vec::new(),
+            diagnostics: Vec::new(),
+            blob_stats: Vec::new(),
+            parsers: parser::ParserRegistry::default(),
+            post_parse_hooks: hooks::PostParseHooks::default(),
+            signatures: signatures::Signatures::default(),
             downloaded_at: 0, // This will be set when the content is downloaded
-            engine: String::new(),
-            location: String::new(),
-        };
-        ret.engine = ret.game_list.get_game_by_hash(game_id)
-            .expect("Game not found")
-            .engine
-            .clone();
-        ret.location = ret.game_list.get_game_by_hash(game_id)
-            .expect("Game not found")
-            .location
-            .clone();
-        ret
+            options,
+            module_base: None,
+            folded_names: None,
+            member_hashes: std::sync::OnceLock::new(),
+            engine: game.engine.clone(),
+            location: game.location.clone(),
+            game,
+        }
     }
 
     pub fn cache_self(&self) -> Result<(), String> {
@@ -81,8 +413,8 @@ impl DSAPI {
         Ok(())
     }
     pub fn restore_from_cache(&self) -> Result<Self, String> {
-        if self.cache_path.is_some() && self.cache_path.as_ref().unwrap().exists() {
-            let cache_file = self.cache_path.as_ref().unwrap().join("dsapi_cache.json");
+        if let Some(cache_path) = self.cache_path.as_ref().filter(|path| path.exists()) {
+            let cache_file = cache_path.join("dsapi_cache.json");
             if cache_file.exists() {
                 let serialized = std::fs::read_to_string(cache_file).map_err(|e| format!("Failed to read cache file: {}", e))?;
                 serde_json::from_str(&serialized).map_err(|e| format!("Failed to deserialize DSAPI from cache: {}", e))
@@ -96,206 +428,538 @@ impl DSAPI {
     /// Downloads and parses the content from the dumpspace API.
     /// This function fetches various JSON blobs containing class, struct, enum, and function information,
     /// and populates the internal maps with this data.
+    #[cfg(network)]
     pub fn download_content(&mut self) -> Result<(), String> {
-        if self.cache_path.is_some() {
-            if self.cache_path.as_ref().unwrap().exists() {
-                let restored_cache = self.restore_from_cache()
-                    .map_err(|e| format!("Failed to restore from cache: {}", e))?;
-                if self.game_list.get_game_by_hash(&self.game_id).unwrap().uploaded <= restored_cache.downloaded_at {
-                    // If the cached content is still valid, we can use it
-                    *self = restored_cache;
-                    return Ok(());
-                }
+        // An interrupted download leaves only partial blobs in the cache directory, which are resumed below.
+        if self.cache_path.as_ref().is_some_and(|path| path.join("dsapi_cache.json").exists()) {
+            let mut restored_cache = self.restore_from_cache()
+                .map_err(|e| format!("Failed to restore from cache: {}", e))?;
+            if self.game_list.get_game_by_hash(&self.game.hash).unwrap().uploaded <= restored_cache.downloaded_at {
+                // If the cached content is still valid, we can use it
+                std::mem::swap(&mut restored_cache.options, &mut self.options);
+                std::mem::swap(&mut restored_cache.overlay, &mut self.overlay);
+                std::mem::swap(&mut restored_cache.member_aliases, &mut self.member_aliases);
+                std::mem::swap(&mut restored_cache.parsers, &mut self.parsers);
+                std::mem::swap(&mut restored_cache.post_parse_hooks, &mut self.post_parse_hooks);
+                std::mem::swap(&mut restored_cache.signatures, &mut self.signatures);
+                restored_cache.module_base = self.module_base;
+                *self = restored_cache;
+                self.apply_overlay();
+                return Ok(());
             }
         }
 
-        fn parse_class_info(classes_info: &BlobInfo, dsapi: &mut DSAPI) {
-            for class in &classes_info.data {
-
-                for (key, value) in class {
-                    let class_name = key;
-                    let value: Vec<HashMap<String, serde_json::Value>> = serde_json::from_str(&value.to_string()).unwrap();
-                    for value in value {
-                        let key = value.keys().next().unwrap().as_str();
-                        assert!(value.keys().len() == 1);
-                        if key == "__MDKClassSize" {
-                            dsapi.class_size_map.insert(class_name.clone(), value.get("__MDKClassSize").unwrap().as_i64().unwrap() as i32);
-                            continue;
-                        }
-                        if key == "__InheritInfo" {
-                            continue;
-                        }
-
-                        let mut info = OffsetInfo::new();
-                        let value_data = value.get(key).unwrap().as_array().unwrap();
-                        info.offset = value_data[1].as_i64().unwrap();
-                        info.size = value_data[2].as_i64().unwrap();
-
-                        if classes_info.version == 10201 {
-                            info.is_bit = value_data.len() == 4;
-                        } else if classes_info.version == 10202 {
-                            info.is_bit = value_data.len() == 5;
-                        } else {
-                            panic!("Unknown version: {}", classes_info.version);
-                        }
-                        info.valid = true;
-
-                        if info.is_bit {
-                            
-                            if classes_info.version == 10201 {
-                                info.bit_offset = value_data[3].as_i64().unwrap() as i32;
-                                dsapi.class_member_map.insert(class_name.clone() + &key[..key.len()-4], info);
-                            } else if classes_info.version == 10202 {
-                                info.bit_offset = value_data[4].as_i64().unwrap() as i32;
-                                dsapi.class_member_map.insert(class_name.clone() + key, info);
-                                //class_member_map insertion
-                            } else {
-                                panic!("Unknown version: {}", classes_info.version);
-                            }
-                        } else {
-                            dsapi.class_member_map.insert(class_name.clone() + key, info);
-                        }
-                        
-                    }
-                }
+        self.parse_warnings.clear();
+        self.diagnostics.clear();
+        self.blob_stats.clear();
+        self.download_blobs(&[])
+    }
+    /// Downloads and parses the selected blobs except `skip`, then caches the result and applies the overlay.
+    #[cfg(network)]
+    pub(crate) fn download_blobs(&mut self, skip: &[BlobKind]) -> Result<(), String> {
+        for blob in BlobKind::ALL {
+            if self.options.blobs.contains(&blob) && !skip.contains(&blob) {
+                self.download_blob_content(blob)?;
             }
         }
-        fn download_url(url: &str) -> Result<String, String> {
-            let response = reqwest::blocking::get(url)
-                .map_err(|e| format!("Failed to fetch URL {}: {}", url, e))?;
-            if response.status().is_success() {
-                let mut d = flate2::read::GzDecoder::new(response);
-                let mut s = String::new();
-                d.read_to_string(&mut s).map_err(|e| format!("Failed to read decompressed data: {}", e))?;
-                Ok(s)
-            } else {
-                Err(format!("Request failed with status: {}", response.status()))
+
+        self.diagnose_key_collisions();
+        self.downloaded_at = self.game_list.get_game_by_hash(&self.game.hash).unwrap().uploaded;
+        if self.cache_path.is_some() {
+            self.cache_self().map_err(|e| format!("Failed to cache DSAPI: {}", e))?;
+        }
+        // Applied after caching so the cache only holds dumpspace data.
+        self.apply_overlay();
+        Ok(())
+    }
+    /// Downloads one blob and parses it into the lookup maps.
+    #[cfg(network)]
+    fn download_blob_content(&mut self, blob: BlobKind) -> Result<(), String> {
+        let (entries, transfer, parse_time) = match blob {
+            BlobKind::Classes | BlobKind::Structs => {
+                let (classes_info, transfer) = self.fetch_blob::<ClassBlob>(blob)?;
+                let (entries, start) = (classes_info.entries(), Instant::now());
+                self.parse_class_info(blob, classes_info)?;
+                (entries, transfer, start.elapsed())
+            }
+            BlobKind::Enums => {
+                let (enums_info, transfer) = self.fetch_blob::<EnumBlob>(blob)?;
+                let (entries, start) = (enums_info.entries(), Instant::now());
+                self.parse_enum_info(enums_info);
+                (entries, transfer, start.elapsed())
             }
+            BlobKind::Functions => {
+                let (functions_info, transfer) = self.fetch_blob::<FunctionBlob>(blob)?;
+                let (entries, start) = (functions_info.entries(), Instant::now());
+                self.parse_function_info(functions_info)?;
+                (entries, transfer, start.elapsed())
+            }
+            BlobKind::Offsets => {
+                let (offsets_info, transfer) = self.fetch_blob::<OffsetBlob>(blob)?;
+                let (entries, start) = (offsets_info.entries(), Instant::now());
+                self.parse_offset_info(offsets_info);
+                (entries, transfer, start.elapsed())
+            }
+        };
+        self.record_blob_stats(blob, entries, &transfer, parse_time);
+        match transfer.validator {
+            Some(validator) => self.blob_validators.insert(blob, validator),
+            None => self.blob_validators.remove(&blob),
+        };
+        Ok(())
+    }
+    /// Checks dumpspace for a newer dump of the current game and applies it.
+    /// The new dump is downloaded and parsed completely before it replaces the current data,
+    /// so a failed refresh leaves the existing offsets untouched.
+    /// Blobs whose `ETag` or `Last-Modified` header did not change since they were downloaded are taken over
+    /// from the current dump instead of being downloaded again, often only OffsetsInfo changes.
+    /// Returns `true` if a newer dump was applied and `false` if the current one is still up to date.
+    #[cfg(network)]
+    pub fn refresh(&mut self) -> Result<bool, String> {
+        match self.fetch_update()? {
+            Some(refreshed) => {
+                *self = refreshed;
+                Ok(true)
+            }
+            None => Ok(false),
         }
-        let engine = self.engine.clone();
-        let location = self.location.clone();
-        let format_url = |json_type: &str| -> String {
-            format!("https://dumpspace.spuckwaffel.com/Games/{}/{}/{}.json.gz", engine, location, json_type)
+    }
+    /// Downloads and parses the current game again if dumpspace has a newer dump, without modifying `self`.
+    #[cfg(network)]
+    pub(crate) fn fetch_update(&self) -> Result<Option<DSAPI>, String> {
+        let game_list = GameList::fetch(&self.options)?;
+        let uploaded = game_list.get_game_by_hash(&self.game.hash)
+            .ok_or_else(|| format!("Game not found: {}", self.game.hash))?
+            .uploaded;
+        if uploaded <= self.downloaded_at {
+            return Ok(None);
+        }
+        let mut refreshed = DSAPI::with_game_list(&self.game.hash, self.cache_path.clone(), game_list, self.options.clone())?;
+        refreshed.overlay = self.overlay.clone();
+        refreshed.member_aliases = self.member_aliases.clone();
+        refreshed.parsers = self.parsers.clone();
+        refreshed.post_parse_hooks = self.post_parse_hooks.clone();
+        refreshed.signatures = self.signatures.clone();
+        refreshed.module_base = self.module_base;
+        let unchanged = self.unchanged_blobs();
+        if unchanged.is_empty() {
+            refreshed.download_content()?;
+        } else {
+            refreshed.reuse_blobs(self, &unchanged);
+            refreshed.download_blobs(&unchanged)?;
+        }
+        Ok(Some(refreshed))
+    }
+    /// Downloads a blob of the current game again and returns it as plain JSON,
+    /// for fields this crate does not model yet. Nothing is cached, every call downloads the blob.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{BlobKind, DSAPI};
+    /// let dsapi = DSAPI::new("6b77eceb", None);
+    /// let offsets = dsapi.raw_blob(BlobKind::Offsets).unwrap();
+    /// println!("{}", offsets["credit"]);
+    /// ```
+    #[cfg(network)]
+    pub fn raw_blob(&self, blob: BlobKind) -> Result<serde_json::Value, String> {
+        self.fetch_blob(blob).map(|(value, _)| value)
+    }
+    /// Uses `parser` for Classes/Structs blobs of the given schema version, replacing the built-in parser
+    /// if there is one. Register parsers before calling `download_content`, dumps restored from the cache
+    /// are not parsed again.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{DSAPI, V10202Parser};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// // A private dumper that writes 10202 member arrays under its own version number.
+    /// dsapi.register_parser(20000, Box::new(V10202Parser));
+    /// dsapi.download_content().unwrap();
+    /// ```
+    pub fn register_parser(&mut self, version: u64, parser: Box<dyn BlobParser>) {
+        self.parsers.register(version, parser);
+    }
+    /// Parses a decompressed blob into the lookup maps.
+    pub(crate) fn ingest_blob(&mut self, kind: BlobKind, reader: impl Read) -> Result<(), String> {
+        let error = |e| format!("Failed to parse {}: {}", kind.file_name(), e);
+        match kind {
+            BlobKind::Classes | BlobKind::Structs => self.parse_class_info(kind, parse_blob(reader).map_err(error)?),
+            BlobKind::Enums => {
+                self.parse_enum_info(parse_blob(reader).map_err(error)?);
+                Ok(())
+            }
+            BlobKind::Functions => self.parse_function_info(parse_blob(reader).map_err(error)?),
+            BlobKind::Offsets => {
+                self.parse_offset_info(parse_blob(reader).map_err(error)?);
+                Ok(())
+            }
+        }
+    }
+    /// Iterates over all class and struct members as `(class, member, info)`.
+    pub(crate) fn members(&self) -> impl Iterator<Item = (&str, &str, &OffsetInfo)> {
+        self.class_member_map.iter()
+            .map(|((class, member), info)| (self.names.resolve(*class), self.names.resolve(*member), info))
+    }
+    /// Iterates over all class and struct sizes as `(class, size)`.
+    pub(crate) fn class_sizes(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.class_size_map.iter().map(|(class, size)| (self.names.resolve(*class), *size))
+    }
+    /// The members of one class or struct as `(member, info)`, sorted by offset.
+    pub(crate) fn class_members(&self, class_name: &str) -> Vec<(&str, &OffsetInfo)> {
+        let Some(class) = self.class_sym(class_name) else {
+            return Vec::new();
         };
-
-
-
-
-
-        let url = format_url("ClassesInfo");
-        let resp = download_url(&url)
-            .expect("Failed to download classes info");
-        let classes_info = serde_json::from_str::<BlobInfo>(&resp)
-            .expect("Failed to parse classes info");
-        parse_class_info(&classes_info, self);
-
-
-        let url = format_url("StructsInfo");
-        let resp = download_url(&url)
-            .expect("Failed to download structs info"); 
-        let structs_info = serde_json::from_str::<BlobInfo>(&resp)
-            .expect("Failed to parse structs info");
-        parse_class_info(&structs_info, self);
-
-
-        let url = format_url("EnumsInfo");
-        let resp = download_url(&url)
-            .expect("Failed to download enums info");
-        let enums_info = serde_json::from_str::<BlobInfo>(&resp)
-            .expect("Failed to parse enums info");
-
-        for enum_info in &enums_info.data {
-            for (key, value) in enum_info {
-                let enum_name = key;
-                let value = &value.as_array().unwrap()[0];
-                for entry in value.as_array().unwrap() {
-                    let entry: serde_json::Map<String, serde_json::Value> = entry.as_object().unwrap().clone();
-                    let enum_value_name = entry.keys().next().unwrap();
-                    assert!(entry.keys().len() == 1);
-                    let enum_value = entry.get(enum_value_name).unwrap().as_i64().unwrap();
-                    self.enum_name_map.insert(enum_name.to_owned() + &enum_value.to_string().clone(), enum_value_name.clone());
-                }
+        let mut members: Vec<_> = self.class_member_map.iter()
+            .filter(|((member_class, _), _)| *member_class == class)
+            .map(|((_, member), info)| (self.names.resolve(*member), info))
+            .collect();
+        members.sort_by_key(|(member, info)| (info.offset, info.bit_offset, *member));
+        members
+    }
+    /// Iterates over all functions as `(class, function, info)`.
+    pub(crate) fn functions(&self) -> impl Iterator<Item = (&str, &str, &FunctionInfo)> {
+        self.function_map.iter()
+            .map(|((class, function), info)| (self.names.resolve(*class), self.names.resolve(*function), info))
+    }
+    /// All global offsets as `(name, offset)`, sorted by name.
+    pub(crate) fn sorted_offsets(&self) -> Vec<(&str, u64)> {
+        let mut offsets: Vec<_> = self.offset_map.iter().map(|(name, offset)| (name.as_str(), *offset)).collect();
+        offsets.sort();
+        offsets
+    }
+    /// Opens a blob of the current game for streaming decompression and parsing,
+    /// instead of buffering the whole blob into a String first. Responses that aren't gzipped are read as plain JSON.
+    /// With a cache directory, the compressed blob is also written into it while it is parsed, so an interrupted
+    /// download continues where it stopped on the next attempt.
+    /// Also returns the counters of compressed and decompressed bytes read so far and the response's validator.
+    #[cfg(network)]
+    fn download_blob(&self, blob: BlobKind) -> Result<BlobDownload, String> {
+        let url = self.options.blob_url(&self.engine, &self.location, blob);
+        let (response, validator) = match &self.cache_path {
+            Some(cache_path) => {
+                let file = cache_path.join(self.options.blob_file(blob));
+                let (download, validator) = self.options.download_resumable(&url, &file)?;
+                (Box::new(download) as http::Body, validator)
             }
+            None => self.options.get_validated(&url)?,
+        };
+        let (response, compressed) = CountingReader::new(response);
+        let decoder = decompress::BlobDecoder::new(std::io::BufReader::new(response)).map_err(|e| format!("Failed to read blob: {}", e))?;
+        let (decoder, decompressed) = CountingReader::new(decoder);
+        Ok((std::io::BufReader::new(decoder), [compressed, decompressed], validator))
+    }
+    /// Downloads and deserializes a blob of the current game.
+    #[cfg(network)]
+    fn fetch_blob<T: serde::de::DeserializeOwned>(&self, blob: BlobKind) -> Result<(T, BlobTransfer), String> {
+        let _span = trace_span!("fetch_blob", blob = blob.file_name());
+        let start = Instant::now();
+        let (reader, [compressed, decompressed], validator) = self.download_blob(blob)
+            .map_err(|e| format!("Failed to download {}: {}", blob.file_name(), e))?;
+        let request_time = start.elapsed();
+        let parsed = parse_blob(reader)
+            .map_err(|e| format!("Failed to parse {}: {}", blob.file_name(), e))?;
+        let transfer = BlobTransfer {
+            compressed_bytes: compressed.load(Ordering::Relaxed),
+            decompressed_bytes: decompressed.load(Ordering::Relaxed),
+            request_time,
+            decode_time: start.elapsed() - request_time,
+            validator,
+        };
+        trace_event!(
+            compressed_bytes = transfer.compressed_bytes,
+            decompressed_bytes = transfer.decompressed_bytes,
+            request_ms = transfer.request_time.as_millis() as u64,
+            decode_ms = transfer.decode_time.as_millis() as u64,
+            "blob decompressed and deserialized"
+        );
+        Ok((parsed, transfer))
+    }
+    fn parse_class_info(&mut self, kind: BlobKind, classes_info: ClassBlob) -> Result<(), String> {
+        let _span = trace_span!("parse_blob", blob = kind.file_name());
+        #[cfg(feature = "tracing")]
+        let start = Instant::now();
+        self.blob_meta.insert(kind, BlobMeta::of(&classes_info));
+        let version = classes_info.version;
+        let parser = self.parsers.get(version);
+        if !parser::is_known_version(version) && !self.parsers.is_registered(version) {
+            let warning = ParseWarning {
+                blob: kind,
+                class: None,
+                member: None,
+                message: format!("Unknown blob version {}, parsed on a best-effort basis", version),
+                snippet: None,
+            };
+            self.parse_warnings.push(warning);
         }
+        let mode = self.options.parse_mode;
+        let classes = classes_info.data.into_iter().flatten();
 
+        #[cfg(feature = "rayon")]
+        let parsed: Vec<(ParsedClass, Vec<ParseWarning>)> = {
+            use rayon::prelude::*;
+            classes.collect::<Vec<_>>()
+                .into_par_iter()
+                .map(|(class_name, fields)| ParsedClass::parse(kind, class_name, fields, parser, mode))
+                .collect::<Result<_, _>>()?
+        };
+        #[cfg(not(feature = "rayon"))]
+        let parsed = classes
+            .map(|(class_name, fields)| ParsedClass::parse(kind, class_name, fields, parser, mode))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        // let url = format_url("FunctionsInfo");
-        // let resp = download_url(&url)
-        //     .expect("Failed to download functions info"); 
-        // let functions_info = serde_json::from_str::<BlobInfo>(&resp)
-        //     .expect("Failed to parse functions info");
-        // for function in &functions_info.data {
-            
-        //     for (key, value) in function {
-        //         dbg!(key, value);
-        //         let function_name = key;
-        //         let value = value.as_array().unwrap()[2].as_u64().unwrap();
-        //         self.function_offset_map.insert(function_name.clone() + &function_name, value);
-        //     }
-        // }
-
-
-        let url = format_url("OffsetsInfo");
-        let resp = download_url(&url)
-            .expect("Failed to download offsets info"); 
-        let offsets_info = serde_json::from_str::<OffsetBlob>(&resp)
-            .expect("Failed to parse offsets info");
-        
-        for offset in &offsets_info.data {
-            self.offset_map.insert(offset[0].as_str().unwrap().to_string(), offset[1].as_u64().unwrap());
+        let mut data = ParsedData::new(kind);
+        for (class, warnings) in parsed {
+            data.classes.push(class);
+            self.parse_warnings.extend(warnings);
         }
+        self.post_parse_hooks.run(&mut data);
 
-
-
-        if self.cache_path.is_some() {
-            self.downloaded_at = self.game_list.get_game_by_hash(&self.game_id).unwrap().uploaded;
-            self.cache_self().map_err(|e| format!("Failed to cache DSAPI: {}", e))?;
+        for class in data.classes {
+            let class_name = self.class_key(kind, &class.name, class.package.as_deref());
+            if let Some(size) = class.size {
+                self.class_size_map.insert(class_name, size);
+            }
+            let supers = class.supers.iter().map(|super_class| self.names.intern(super_class)).collect();
+            self.class_super_map.insert(class_name, supers);
+            for ParsedMember { name: member_name, info, ty } in class.members {
+                let member = self.names.intern(&member_name);
+                match ty {
+                    Some(ty) => self.member_type_map.insert((class_name, member), ty),
+                    None => self.member_type_map.remove(&(class_name, member)),
+                };
+                let duplicate = self.class_member_map.insert((class_name, member), info.clone()).is_some();
+                self.diagnose_member(kind, &class.name, &member_name, &info, duplicate);
+            }
         }
+        trace_event!(
+            members = self.class_member_map.len(),
+            warnings = self.parse_warnings.len(),
+            elapsed_ms = start.elapsed().as_millis() as u64,
+            "classes parsed"
+        );
         Ok(())
     }
+    fn parse_enum_info(&mut self, enums_info: EnumBlob) {
+        let _span = trace_span!("parse_blob", blob = BlobKind::Enums.file_name());
+        self.blob_meta.insert(BlobKind::Enums, BlobMeta::of(&enums_info));
+        let mut data = ParsedData::new(BlobKind::Enums);
+        for (name, values) in enums_info.data.into_iter().flatten() {
+            data.enums.push(ParsedEnum { name, values: values.0.into_iter().map(|EnumValue(name, value)| (name, value)).collect() });
+        }
+        self.post_parse_hooks.run(&mut data);
+
+        for ParsedEnum { name: enum_name, values } in data.enums {
+            let enum_name = self.names.intern(&enum_name);
+            for (enum_value_name, enum_value) in values {
+                let value_name = self.names.intern(&enum_value_name);
+                if let Some(other) = self.enum_name_map.insert((enum_name, enum_value), value_name) {
+                    self.diagnostics.push(Diagnostic::DuplicateEnumValue {
+                        enum_name: self.names.resolve(enum_name).to_string(),
+                        value: enum_value,
+                        names: (self.names.resolve(other).to_string(), enum_value_name),
+                    });
+                }
+            }
+        }
+        trace_event!(enum_values = self.enum_name_map.len(), "enums parsed");
+    }
+    fn parse_offset_info(&mut self, offsets_info: OffsetBlob) {
+        self.blob_meta.insert(BlobKind::Offsets, BlobMeta { updated_at: offsets_info.updated_at.clone(), version: offsets_info.version });
+        self.offsets_credit = offsets_info.credit;
+        let mut data = ParsedData::new(BlobKind::Offsets);
+        data.offsets = offsets_info.data;
+        self.post_parse_hooks.run(&mut data);
+        for (offset_name, offset) in data.offsets {
+            self.offset_map.insert(offset_name, offset);
+        }
+        trace_event!(offsets = self.offset_map.len(), "offsets parsed");
+    }
     /// Returns the offset info for a class member as an `Option<OffsetInfo>`.
+    /// Falls back to the member's aliases, see [`DSAPI::add_member_alias`].
     pub fn get_member_offset(&self, class_name: &str, member_name: &str) -> Option<OffsetInfo> {
-        self.class_member_map.get(&(class_name.to_string() + member_name)).cloned()
+        self.lookup_member(class_name, member_name)
+            .or_else(|| self.member_aliases(class_name, member_name).find_map(|alias| self.lookup_member(class_name, alias)))
+    }
+    fn lookup_member(&self, class_name: &str, member_name: &str) -> Option<OffsetInfo> {
+        let key = (self.class_sym(class_name)?, self.lookup_sym(member_name)?);
+        self.class_member_map.get(&key).cloned()
     }
     /// Returns the size of a class as an `Option<i32>`.
     /// Returns `None` if the class is not found.
     pub fn get_class_size(&self, class_name: &str) -> Option<i32> {
-        self.class_size_map.get(class_name).cloned()
+        self.class_size_map.get(&self.class_sym(class_name)?).cloned()
+    }
+    /// Returns the super classes of a class or struct, starting with the direct parent, as an `Option<Vec<String>>`.
+    /// Returns `None` if the class is not found.
+    pub fn get_super_classes(&self, class_name: &str) -> Option<Vec<String>> {
+        self.class_super_map.get(&self.class_sym(class_name)?)
+            .map(|supers| supers.iter().map(|super_class| self.names.resolve(*super_class).to_string()).collect())
+    }
+    /// Returns a hash of a class or struct layout (its size and the name, offset and size of every member)
+    /// as an `Option<u64>`, so cached per-class data can be invalidated only when that class changed.
+    /// The hash is stable across runs and crate versions. Returns `None` if the class is not found.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let cached: u64 = 0x1234;
+    /// if dsapi.class_fingerprint("UWorld") != Some(cached) {
+    ///     println!("UWorld changed, regenerating");
+    /// }
+    /// ```
+    pub fn class_fingerprint(&self, class_name: &str) -> Option<u64> {
+        let size = self.get_class_size(class_name)?;
+        // FNV-1a, unlike std's hashers its output is specified and never changes.
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash = (hash ^ *byte as u64).wrapping_mul(0x100000001b3);
+            }
+        };
+        write(&size.to_le_bytes());
+        for (member, info) in self.class_members(class_name) {
+            write(member.as_bytes());
+            write(&[0]);
+            write(&info.offset.to_le_bytes());
+            write(&info.size.to_le_bytes());
+            write(&[info.is_bit as u8]);
+            write(&info.bit_offset.to_le_bytes());
+        }
+        Some(hash)
     }
     /// Returns the offset of a function as an `Option<u64>`.
-    /// Returns `None` if the function is not found.
-    /// Note: Functions are not currently implemented.
-    #[allow(dead_code)] //removeme
-    fn get_function_offset(&self, function_class: &str, function_name: &str) -> Option<u64> {
-        self.function_offset_map.get(&(function_class.to_string() + function_name)).cloned()
+    /// Returns `None` if the function is not found. See [`DSAPI::get_function_info`] for its flags and parameters.
+    pub fn get_function_offset(&self, function_class: &str, function_name: &str) -> Option<u64> {
+        let key = (self.names.get(function_class)?, self.names.get(function_name)?);
+        self.function_map.get(&key).map(|info| info.offset)
     }
     /// Returns the name of an enum value as an `Option<String>`.
     /// Returns `None` if the enum name or value is not found.
     pub fn get_enum_name(&self, enum_name: &str, enum_value: i64) -> Option<String> {
-        self.enum_name_map.get(&(enum_name.to_string() + &enum_value.to_string())).cloned()
+        self.enum_name_map.get(&(self.names.get(enum_name)?, enum_value))
+            .map(|name| self.names.resolve(*name).to_string())
+    }
+    /// Returns the name of an enum value without the enum prefix, e.g. `Uncommon` for `EFortRarity__Uncommon`,
+    /// as an `Option<String>`. Names without the prefix (like `RF_Public` in `EObjectFlags`) are returned as they are.
+    /// Returns `None` if the enum name or value is not found.
+    pub fn get_enum_name_short(&self, enum_name: &str, enum_value: i64) -> Option<String> {
+        let name = self.enum_name_map.get(&(self.names.get(enum_name)?, enum_value))?;
+        Some(short_enum_name(enum_name, self.names.resolve(*name)).to_string())
     }
     /// Returns the offset of a specific offset name as an `Option<u64>`.
     /// Returns `None` if the offset name is not found.
     pub fn get_offset(&self, offset_name: &str) -> Option<u64> {
-        self.offset_map.get(offset_name).cloned()
+        self.offset_map.get(offset_name)
+            .or_else(|| self.offset_map.get(self.lookup_offset_name(offset_name)?))
+            .cloned()
+    }
+    /// Returns the first of several spellings of a global offset that exists in the dump,
+    /// e.g. `get_offset_any(&["OFFSET_GWORLD", "GWorld", "OFFSET_UWORLD"])`.
+    /// Returns `None` if none of them is found.
+    pub fn get_offset_any(&self, offset_names: &[&str]) -> Option<u64> {
+        offset_names.iter().find_map(|offset_name| self.get_offset(offset_name))
+    }
+    /// Returns the entries that were skipped or parsed on a best-effort basis by the last `download_content`.
+    /// Always empty with [`ParseMode::Strict`], which fails instead.
+    pub fn parse_warnings(&self) -> &[ParseWarning] {
+        &self.parse_warnings
+    }
+    /// Returns the dumpspace game list entry of the loaded game (name, hash, upload time and uploader).
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+    /// Returns the `updated_at` and `version` fields of a parsed blob.
+    /// Returns `None` if the blob was not downloaded.
+    pub fn blob_meta(&self, blob: BlobKind) -> Option<&BlobMeta> {
+        self.blob_meta.get(&blob)
+    }
+    /// Returns the `credit` of the Offsets blob, e.g. `dumper_used` and `dumper_link`, to attribute the dumper
+    /// in tools and generated code. Empty if the Offsets blob was not downloaded.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// if let Some(dumper) = dsapi.offsets_credit().get("dumper_used") {
+    ///     println!("Offsets dumped with {}", dumper);
+    /// }
+    /// ```
+    pub fn offsets_credit(&self) -> &HashMap<String, String> {
+        &self.offsets_credit
+    }
+    /// Returns the most recent `updated_at` timestamp of all parsed blobs.
+    pub fn dump_updated_at(&self) -> Option<u64> {
+        self.blob_meta.values().filter_map(BlobMeta::updated_at_secs).max()
+    }
+    /// Returns the schema version of the parsed class blob (e.g. 10202), falling back to any other parsed blob.
+    pub fn dump_version(&self) -> Option<u64> {
+        self.blob_meta(BlobKind::Classes)
+            .or_else(|| self.blob_meta.values().next())
+            .map(|meta| meta.version)
+    }
+    /// Returns the Unix timestamp (in seconds) of the newest data in the dump,
+    /// taking both the game's upload time and the blobs' `updated_at` into account.
+    pub fn dump_timestamp(&self) -> u64 {
+        self.dump_updated_at().unwrap_or(0).max(unix_secs(self.game.uploaded))
+    }
+    /// Returns how long ago the dump was uploaded or last updated.
+    pub fn dump_age(&self) -> std::time::Duration {
+        let dumped_at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(self.dump_timestamp());
+        std::time::SystemTime::now().duration_since(dumped_at).unwrap_or_default()
+    }
+    /// Returns `true` if the dump is older than `threshold`, e.g. to warn before using offsets
+    /// that likely predate the latest game patch.
+    pub fn is_stale(&self, threshold: std::time::Duration) -> bool {
+        self.dump_age() > threshold
+    }
+    /// Returns `true` if the dump was last updated before the given Unix timestamp (in seconds),
+    /// e.g. the release time of the latest game patch.
+    pub fn predates(&self, timestamp: u64) -> bool {
+        self.dump_timestamp() < timestamp
+    }
+    /// Creates a compact, read-only [`FrozenDump`] of the parsed content.
+    /// Use this once the content is downloaded and no more updates are needed.
+    pub fn freeze(&self) -> FrozenDump {
+        FrozenDump::from_dsapi(self)
     }
     /// Returns the offset info for a class member with an .unwrap() and cast to usize.
     /// This function will panic if the member is not found.
     /// # Safety: This function assumes that the member exists and will panic if it does not.
     /// This should be fine to use in practice, as the code should only panic if the member is misspelled or does not exist.
     pub fn get_member_offset_unchecked(&self, class_name: &str, member_name: &str) -> usize {
-        self.class_member_map.get(&(class_name.to_string() + member_name)).cloned().unwrap().offset as usize
+        self.get_member_offset(class_name, member_name)
+            .unwrap_or_else(|| panic!("Member not found: {}::{}", class_name, member_name))
+            .offset as usize
+    }
+    /// Returns the size of a class with an .unwrap() and cast to usize.
+    /// This function will panic if the class is not found, naming the missing class.
+    pub fn get_class_size_unchecked(&self, class_name: &str) -> usize {
+        self.get_class_size(class_name)
+            .unwrap_or_else(|| panic!("Class not found: {}", class_name)) as usize
+    }
+    /// Returns the name of an enum value with an .unwrap().
+    /// This function will panic if the enum or value is not found, naming the missing value.
+    pub fn get_enum_name_unchecked(&self, enum_name: &str, enum_value: i64) -> String {
+        self.get_enum_name(enum_name, enum_value)
+            .unwrap_or_else(|| panic!("Enum value not found: {}::{}", enum_name, enum_value))
+    }
+    /// Returns a global offset with an .unwrap().
+    /// This function will panic if the offset is not found, naming the missing offset.
+    pub fn get_offset_unchecked(&self, offset_name: &str) -> u64 {
+        self.get_offset(offset_name)
+            .unwrap_or_else(|| panic!("Offset not found: {}", offset_name))
     }
 }
 
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct GameList {
     pub games: Vec<Game>
 }
 
 
-#[derive(Deserialize, Serialize, Debug)]
+/// A game listed on dumpspace. More fields may be added, construct it with [`Game::new`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
 pub struct Game {
     pub hash: String,
     pub name: String,
@@ -305,12 +969,47 @@ pub struct Game {
     pub uploader: Uploader
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+impl Game {
+    /// A game with the given ID (hash), name and the engine and location its blobs are stored under,
+    /// e.g. `Game::new("6b77eceb", "Fortnite", "Unreal-Engine-5", "Fortnite")`.
+    pub fn new(hash: &str, name: &str, engine: &str, location: &str) -> Self {
+        Game {
+            hash: hash.to_string(),
+            name: name.to_string(),
+            engine: engine.to_string(),
+            location: location.to_string(),
+            ..Default::default()
+        }
+    }
+    /// Sets when the dump was uploaded, as a Unix timestamp.
+    pub fn with_uploaded(mut self, uploaded: u64) -> Self {
+        self.uploaded = uploaded;
+        self
+    }
+    /// Sets who uploaded the dump.
+    pub fn with_uploader(mut self, uploader: Uploader) -> Self {
+        self.uploader = uploader;
+        self
+    }
+    /// When the dump was uploaded as a Unix timestamp in seconds, `uploaded` is in milliseconds for some games.
+    pub fn uploaded_secs(&self) -> u64 {
+        unix_secs(self.uploaded)
+    }
+    /// How long ago the dump was uploaded.
+    pub fn upload_age(&self) -> std::time::Duration {
+        let uploaded_at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(self.uploaded_secs());
+        std::time::SystemTime::now().duration_since(uploaded_at).unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
 pub struct Uploader {
     pub name: String,
     pub link: String,
 }
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Where a class member is stored. More fields may be added, construct it with [`OffsetInfo::member`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct OffsetInfo {
     pub offset: i64,
     pub size: i64,
@@ -329,69 +1028,705 @@ impl OffsetInfo {
             valid: false,
         }
     }
+    /// A valid member of `size` bytes at `offset`.
+    pub fn member(offset: i64, size: i64) -> Self {
+        OffsetInfo { offset, size, valid: true, ..Self::new() }
+    }
+    /// Makes the member a bit field, stored in bit `bit_offset` of the bytes at its offset.
+    pub fn with_bit(mut self, bit_offset: i32) -> Self {
+        self.is_bit = true;
+        self.bit_offset = bit_offset;
+        self
+    }
+}
+
+impl Default for OffsetInfo {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // converting bool() operation from c++
-impl Into<bool> for OffsetInfo {
-    fn into(self) -> bool {
-        self.valid
+impl From<OffsetInfo> for bool {
+    fn from(info: OffsetInfo) -> bool {
+        info.valid
+    }
+}
+
+
+/// The JSON blobs a game dump is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum BlobKind {
+    Classes,
+    Structs,
+    Enums,
+    Functions,
+    Offsets,
+}
+
+impl BlobKind {
+    pub const ALL: [BlobKind; 5] = [BlobKind::Classes, BlobKind::Structs, BlobKind::Enums, BlobKind::Functions, BlobKind::Offsets];
+
+    /// Name of the blob on the dumpspace server, without the `.json.gz` extension.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            BlobKind::Classes => "ClassesInfo",
+            BlobKind::Structs => "StructsInfo",
+            BlobKind::Enums => "EnumsInfo",
+            BlobKind::Functions => "FunctionsInfo",
+            BlobKind::Offsets => "OffsetsInfo",
+        }
     }
 }
 
+/// Metadata stored in the header of every blob.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BlobMeta {
+    /// When the blob was last updated, as a Unix timestamp string.
+    pub updated_at: String,
+    /// Schema version of the blob, e.g. 10202.
+    pub version: u64,
+}
+
+impl BlobMeta {
+    pub(crate) fn of<T>(blob: &BlobInfo<T>) -> Self {
+        BlobMeta { updated_at: blob.updated_at.clone(), version: blob.version }
+    }
+    /// Parses `updated_at` as a Unix timestamp in seconds. Millisecond timestamps are converted.
+    pub fn updated_at_secs(&self) -> Option<u64> {
+        self.updated_at.trim().parse().ok().map(unix_secs)
+    }
+}
+
+/// Strips the `Enum__` (or `Enum::`) prefix from the name of an enum value.
+pub(crate) fn short_enum_name<'a>(enum_name: &str, value_name: &'a str) -> &'a str {
+    value_name.strip_prefix(enum_name)
+        .and_then(|rest| rest.strip_prefix("__").or_else(|| rest.strip_prefix("::")))
+        .filter(|short| !short.is_empty())
+        .unwrap_or(value_name)
+}
+
+/// Converts a dumpspace timestamp to seconds. Some timestamps are stored in milliseconds;
+/// anything past the year 5000 in seconds is treated as one.
+fn unix_secs(timestamp: u64) -> u64 {
+    if timestamp > 100_000_000_000 { timestamp / 1000 } else { timestamp }
+}
+
+#[cfg(network)]
+type BlobReader = std::io::BufReader<CountingReader<decompress::BlobDecoder<std::io::BufReader<CountingReader<http::Body>>>>>;
+/// A blob being downloaded, the counters of compressed and decompressed bytes and the response's validator.
+#[cfg(network)]
+type BlobDownload = (BlobReader, [Arc<AtomicU64>; 2], Option<String>);
+
+/// Deserializes a decompressed blob.
+/// With the `simd-json` feature the blob is read into memory once and parsed with SIMD acceleration,
+/// otherwise it is streamed through `serde_json`.
+#[cfg(not(feature = "simd-json"))]
+fn parse_blob<T: serde::de::DeserializeOwned>(reader: impl Read) -> Result<T, String> {
+    serde_json::from_reader(reader).map_err(|e| format!("Failed to parse blob: {}", e))
+}
+
+#[cfg(feature = "simd-json")]
+fn parse_blob<T: serde::de::DeserializeOwned>(mut reader: impl Read) -> Result<T, String> {
+    let mut buffer = Vec::new();
+    reader.read_to_end(&mut buffer).map_err(|e| format!("Failed to read blob: {}", e))?;
+    simd_json::serde::from_slice(&mut buffer).map_err(|e| format!("Failed to parse blob: {}", e))
+}
 
 #[derive(Deserialize, Debug)]
-#[allow(dead_code)]
-struct BlobInfo {
-    data: Vec<HashMap<String, serde_json::Value>>,
+struct BlobInfo<T> {
+    data: Vec<T>,
     updated_at: String, // Unix timestamp
     version: u64, // Version number
 }
 
+type ClassBlob = BlobInfo<Named<Vec<ClassField>>>;
+type EnumBlob = BlobInfo<Named<EnumDef>>;
+/// Classes with their functions, each function kept as plain JSON until [`FunctionInfo::from_json`] converts it.
+type FunctionBlob = BlobInfo<Named<Vec<Named<serde_json::Value>>>>;
+
+impl<T> BlobInfo<Named<T>> {
+    /// Number of classes, enums or classes with functions in the blob.
+    #[cfg(network)]
+    fn entries(&self) -> usize {
+        self.data.iter().map(|named| named.0.len()).sum()
+    }
+}
+
+impl OffsetBlob {
+    #[cfg(network)]
+    fn entries(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// A `{ "name": ..., ... }` object of classes or enums. Errors while parsing an entry are prefixed
+/// with its name, so they point at the class or enum that broke.
+#[derive(Debug)]
+struct Named<T>(Vec<(String, T)>);
+
+impl<T> IntoIterator for Named<T> {
+    type Item = (String, T);
+    type IntoIter = std::vec::IntoIter<(String, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 #[derive(Deserialize, Debug)]
-#[allow(dead_code)]
 struct OffsetBlob {
     credit: HashMap<String, String>,
-    data: Vec<Vec<serde_json::Value>>, //fucking hate this
+    data: Vec<(String, u64)>,
     updated_at: String, // Unix timestamp
     version: u64, // Version number
 }
+
+impl ParsedClass {
+    /// Converts a single class or struct from its blob fields, ready to be merged into the lookup maps.
+    /// Classes are independent of each other, so this step can run in parallel.
+    fn parse(kind: BlobKind, name: String, fields: Vec<ClassField>, parser: &dyn BlobParser, mode: ParseMode) -> Result<(Self, Vec<ParseWarning>), String> {
+        let mut class = ParsedClass { name, package: None, size: None, supers: Vec::new(), members: Vec::with_capacity(fields.len()) };
+        let mut warnings = Vec::new();
+        for field in fields {
+            let (key, member) = match field {
+                ClassField::Size(size) => {
+                    class.size = Some(size);
+                    continue;
+                }
+                ClassField::Inherit(supers) => {
+                    class.supers = supers;
+                    continue;
+                }
+                ClassField::Package(package) => {
+                    class.package = Some(package);
+                    continue;
+                }
+                ClassField::Member(key, member) => (key, member),
+            };
+            let parser::MemberEntry(member, ty) = member;
+            let member = match member {
+                Ok(member) => parser.parse_member(&key, &member).map_err(|message| (message, member.snippet())),
+                Err(error) => Err(error),
+            };
+            match member {
+                Ok((name, info)) => class.members.push(ParsedMember { name, info, ty }),
+                Err((message, snippet)) => {
+                    let warning = ParseWarning { blob: kind, class: Some(class.name.clone()), member: Some(key), message, snippet: Some(snippet) };
+                    match mode {
+                        ParseMode::Lenient => warnings.push(warning),
+                        ParseMode::Strict => return Err(format!("Failed to parse {}", warning)),
+                    }
+                }
+            }
+        }
+        Ok((class, warnings))
+    }
+}
+
+/// One single-key `{ "name": ... }` entry of a class or struct in the Classes/Structs blobs.
+#[derive(Debug)]
+enum ClassField {
+    Size(i32),
+    /// The super classes, starting with the direct parent.
+    Inherit(Vec<String>),
+    /// The package the class was declared in, if the dumper records it.
+    Package(String),
+    Member(String, parser::MemberEntry),
+}
+
+/// An enum entry: `[[{"Name": value}, ...], "underlying type"]`. Only the values are kept.
+#[derive(Debug)]
+struct EnumDef(Vec<EnumValue>);
+
+#[derive(Debug)]
+struct EnumValue(String, i64);
+
+impl<'de> serde::Deserialize<'de> for ClassField {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+        impl<'de> serde::de::Visitor<'de> for FieldVisitor {
+            type Value = ClassField;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a single-key class field object")
+            }
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<ClassField, A::Error> {
+                let key: String = map.next_key()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                let field = match key.as_str() {
+                    "__MDKClassSize" => ClassField::Size(map.next_value()
+                        .map_err(|e| serde::de::Error::custom(format!("in __MDKClassSize: {}", e)))?),
+                    "__InheritInfo" => ClassField::Inherit(map.next_value()
+                        .map_err(|e| serde::de::Error::custom(format!("in __InheritInfo: {}", e)))?),
+                    "__Package" => ClassField::Package(map.next_value()
+                        .map_err(|e| serde::de::Error::custom(format!("in __Package: {}", e)))?),
+                    _ => ClassField::Member(key, map.next_value()?),
+                };
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &self));
+                }
+                Ok(field)
+            }
+        }
+        deserializer.deserialize_map(FieldVisitor)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Named<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NamedVisitor<T>(std::marker::PhantomData<T>);
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for NamedVisitor<T> {
+            type Value = Named<T>;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an object of named entries")
+            }
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Named<T>, A::Error> {
+                let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(name) = map.next_key::<String>()? {
+                    let value = map.next_value()
+                        .map_err(|e| serde::de::Error::custom(format!("in {}: {}", name, e)))?;
+                    entries.push((name, value));
+                }
+                Ok(Named(entries))
+            }
+        }
+        deserializer.deserialize_map(NamedVisitor(std::marker::PhantomData))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EnumDef {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EnumVisitor;
+        impl<'de> serde::de::Visitor<'de> for EnumVisitor {
+            type Value = EnumDef;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an enum array [[{name: value}, ...], type]")
+            }
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<EnumDef, A::Error> {
+                let values = seq.next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(EnumDef(values))
+            }
+        }
+        deserializer.deserialize_seq(EnumVisitor)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EnumValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ValueVisitor;
+        impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+            type Value = EnumValue;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a single-key enum value object")
+            }
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<EnumValue, A::Error> {
+                let (name, value) = map.next_entry()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &self));
+                }
+                Ok(EnumValue(name, value))
+            }
+        }
+        deserializer.deserialize_map(ValueVisitor)
+    }
+}
+
 impl GameList {
+    /// Fetches the game list from the server set with [`configure`], or the dumpspace server.
+    #[cfg(network)]
     pub fn init() -> Result<Self, String> {
-        let url = "https://dumpspace.spuckwaffel.com/Games/GameList.json";
+        Self::fetch(&DSAPIBuilder::configured_options()?)
+    }
+    /// Like `init`, but reuses a game list this process, or the configured cache directory, fetched less than `ttl` ago.
+    #[cfg(network)]
+    pub fn init_cached(ttl: std::time::Duration) -> Result<Self, String> {
+        Self::fetch_cached(&DSAPIBuilder::configured_options()?, ttl, config().cache_dir.as_deref())
+    }
+    /// Fetches the game list even if a cached one is still valid, and updates the cache with it.
+    #[cfg(network)]
+    pub fn force_refresh() -> Result<Self, String> {
+        Self::refresh(&DSAPIBuilder::configured_options()?, config().cache_dir.as_deref())
+    }
+    #[cfg(network)]
+    pub(crate) fn fetch(options: &DownloadOptions) -> Result<Self, String> {
+        let url = format!("{}/Games/GameList.json", options.base_url);
 
-        let response = reqwest::blocking::get(url)
+        let mut response = options.get(&url)
             .map_err(|e| format!("Failed to fetch game list: {}", e))?;
-
-        if response.status().is_success() {
-            let text = response.text().map_err(|e| format!("Failed to read response text: {}", e))?;
-            serde_json::from_str(&text).map_err(|e| format!("Failed to parse JSON: {}", e))
-        } else {
-            Err(format!("Request failed with status: {}", response.status()))
+        let mut text = String::new();
+        response.read_to_string(&mut text).map_err(|e| format!("Failed to read response text: {}", e))?;
+        let list: GameList = serde_json::from_str(&text).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        game_list_cache::store(&options.base_url, &list, None);
+        Ok(list)
+    }
+    /// Returns a game list fetched less than `ttl` ago from memory or `cache_dir`, or fetches a new one.
+    #[cfg(network)]
+    pub(crate) fn fetch_cached(options: &DownloadOptions, ttl: std::time::Duration, cache_dir: Option<&std::path::Path>) -> Result<Self, String> {
+        match game_list_cache::load(&options.base_url, ttl, cache_dir) {
+            Some(list) => Ok(list),
+            None => Self::refresh(options, cache_dir),
         }
     }
+    /// Fetches a new game list and stores it in the caches [`GameList::fetch_cached`] reads.
+    #[cfg(network)]
+    pub(crate) fn refresh(options: &DownloadOptions, cache_dir: Option<&std::path::Path>) -> Result<Self, String> {
+        let list = Self::fetch(options)?;
+        game_list_cache::store(&options.base_url, &list, cache_dir);
+        Ok(list)
+    }
     pub fn get_game_by_hash(&self, hash: &str) -> Option<&Game> {
         self.games.iter().find(|game| game.hash == hash)
     }
     pub fn get_game_by_name(&self, name: &str) -> Option<&Game> {
         self.games.iter().find(|game| game.name == name)
     }
+    /// Finds a game by name using the given matching mode.
+    /// With [`NameMatch::Fuzzy`] the closest match is returned, preferring shorter names.
+    pub fn find_game_by_name(&self, name: &str, matching: NameMatch) -> Option<&Game> {
+        match matching {
+            NameMatch::Exact => self.get_game_by_name(name),
+            NameMatch::CaseInsensitive => self.games.iter().find(|game| game.name.eq_ignore_ascii_case(name)),
+            NameMatch::Fuzzy => self.search(name).into_iter().next(),
+        }
+    }
+    /// Iterates over all games in the list.
+    pub fn iter(&self) -> std::slice::Iter<'_, Game> {
+        self.games.iter()
+    }
+    /// Iterates over all games using the given engine, e.g. `"Unreal-Engine-5"`. The comparison ignores case.
+    pub fn games_by_engine<'a>(&'a self, engine: &'a str) -> impl Iterator<Item = &'a Game> + 'a {
+        self.games.iter().filter(move |game| game.engine.eq_ignore_ascii_case(engine))
+    }
+    /// Searches games by name, ignoring case, spaces and punctuation.
+    /// Exact matches come first, then names containing the query, then names containing its letters in order.
+    /// Within each group shorter names are ranked higher.
+    pub fn search(&self, query: &str) -> Vec<&Game> {
+        let query = normalize_game_name(query);
+        let score = |game: &Game| {
+            let candidate = normalize_game_name(&game.name);
+            if candidate == query {
+                Some(0)
+            } else if candidate.contains(&query) {
+                Some(1)
+            } else if is_subsequence(&query, &candidate) {
+                Some(2)
+            } else {
+                None
+            }
+        };
+        let mut results: Vec<_> = self.games.iter()
+            .filter_map(|game| score(game).map(|score| (score, game.name.len(), game)))
+            .collect();
+        results.sort_by_key(|(score, len, _)| (*score, *len));
+        results.into_iter().map(|(_, _, game)| game).collect()
+    }
+    /// Returns all games sorted by their upload time, newest first.
+    pub fn sorted_by_upload(&self) -> Vec<&Game> {
+        let mut games: Vec<_> = self.games.iter().collect();
+        games.sort_by_key(|game| std::cmp::Reverse(unix_secs(game.uploaded)));
+        games
+    }
+    /// Returns the games that got a new dump after the given Unix timestamp (in seconds), newest first.
+    pub fn recently_updated(&self, since: u64) -> Vec<&Game> {
+        let mut games = self.sorted_by_upload();
+        games.retain(|game| unix_secs(game.uploaded) > unix_secs(since));
+        games
+    }
+}
+
+impl<'a> IntoIterator for &'a GameList {
+    type Item = &'a Game;
+    type IntoIter = std::slice::Iter<'a, Game>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.games.iter()
+    }
+}
+
+/// How [`GameList::find_game_by_name`] compares game names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameMatch {
+    /// The name has to match exactly.
+    Exact,
+    /// The name has to match, ignoring ASCII case.
+    CaseInsensitive,
+    /// Ignores case, spaces and punctuation, and also accepts partial names like `"fort"`.
+    Fuzzy,
+}
+
+pub(crate) fn normalize_game_name(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    static mut LOCAL_DSAPI: std::sync::LazyLock<DSAPI> = std::sync::LazyLock::new(||{let mut res = DSAPI::new("6b77eceb", None);res.download_content().unwrap();return res;}); //fortnite
+
+    /// Builds a `DSAPI` from the checked-in fixture blobs, without touching the network.
+    pub(crate) fn fixture_dsapi() -> DSAPI {
+        let game = Game {
+            hash: "fixture".to_string(),
+            name: "Fixture".to_string(),
+            engine: "Unreal-Engine-5".to_string(),
+            location: "Fixture".to_string(),
+            uploaded: 1718035200,
+            uploader: Uploader { name: "tester".to_string(), link: String::new() },
+        };
+        let mut dsapi = DSAPI::empty(game.clone(), None, GameList { games: vec![game] }, DownloadOptions::default());
+        dsapi.parse_class_info(BlobKind::Classes, parse_blob(include_bytes!("../tests/fixtures/ClassesInfo.json").as_slice()).unwrap()).unwrap();
+        dsapi.parse_class_info(BlobKind::Structs, parse_blob(include_bytes!("../tests/fixtures/StructsInfo.json").as_slice()).unwrap()).unwrap();
+        dsapi.parse_enum_info(parse_blob(include_bytes!("../tests/fixtures/EnumsInfo.json").as_slice()).unwrap());
+        dsapi.parse_function_info(parse_blob(include_bytes!("../tests/fixtures/FunctionsInfo.json").as_slice()).unwrap()).unwrap();
+        dsapi.parse_offset_info(parse_blob(include_bytes!("../tests/fixtures/OffsetsInfo.json").as_slice()).unwrap());
+        dsapi
+    }
+
+    /// Serves the game list and the gzipped fixture blobs of the fixture game on localhost, returning the base URL.
+    #[cfg(network)]
+    pub(crate) fn fixture_server() -> String {
+        use std::io::Write;
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let game_list = serde_json::to_string(&fixture_dsapi().game_list).unwrap();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                // Blobs are served gzipped as `.json.gz` and plain as `.json`.
+                let file = request.url().strip_prefix("/Games/Unreal-Engine-5/Fixture/").map(|file| file.trim_end_matches(".gz"));
+                let blob = match file {
+                    Some("ClassesInfo.json") => include_bytes!("../tests/fixtures/ClassesInfo.json").as_slice(),
+                    Some("StructsInfo.json") => include_bytes!("../tests/fixtures/StructsInfo.json").as_slice(),
+                    Some("EnumsInfo.json") => include_bytes!("../tests/fixtures/EnumsInfo.json").as_slice(),
+                    Some("FunctionsInfo.json") => include_bytes!("../tests/fixtures/FunctionsInfo.json").as_slice(),
+                    Some("OffsetsInfo.json") => include_bytes!("../tests/fixtures/OffsetsInfo.json").as_slice(),
+                    _ if request.url() == "/Games/GameList.json" => game_list.as_bytes(),
+                    _ => {
+                        let _ = request.respond(tiny_http::Response::empty(404));
+                        continue;
+                    }
+                };
+                let body = match request.url().ends_with(".gz") {
+                    true => {
+                        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+                        encoder.write_all(blob).unwrap();
+                        encoder.finish().unwrap()
+                    }
+                    false => blob.to_vec(),
+                };
+                let _ = request.respond(tiny_http::Response::from_data(body));
+            }
+        });
+        format!("http://127.0.0.1:{}", port)
+    }
+
+    #[test]
+    fn test_parse_fixture() {
+        let dsapi = fixture_dsapi();
+        let info = dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap();
+        assert_eq!((info.offset, info.size, info.is_bit), (0x228, 8, false));
+        assert_eq!(dsapi.get_member_offset("FVector", "Z").unwrap().offset, 16);
+        assert_eq!(dsapi.get_class_size("UWorld"), Some(2536));
+        assert_eq!(dsapi.get_super_classes("UWorld"), Some(vec!["UObject".to_string()]));
+        assert_eq!(dsapi.get_super_classes("UObject"), Some(vec![]));
+        assert_eq!(dsapi.get_enum_name("EFortRarity", 4), Some("EFortRarity__Legendary".to_string()));
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert_eq!(dsapi.get_offset_any(&["GWorld", "OFFSET_GWORLD", "OFFSET_GNAMES"]), Some(0x14942840));
+        assert_eq!(dsapi.get_offset_any(&["GWorld", "OFFSET_UWORLD"]), None);
+    }
+
+    #[test]
+    fn test_parse_bitfields() {
+        let dsapi = fixture_dsapi();
+        let info = dsapi.get_member_offset("UWorld", "bIsWorldInitialized").unwrap();
+        assert!(info.is_bit);
+        assert_eq!(info.bit_offset, 2);
+
+        // 10201 blobs carry the bit offset in the 4th slot and suffix the member name with " : 1".
+        let mut dsapi = fixture_dsapi();
+        let blob = r#"{"data":[{"AOld":[{"bFlag : 1":[["bool","D","",[]],8,1,5]}]}],"updated_at":"0","version":10201}"#;
+        dsapi.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+        let info = dsapi.get_member_offset("AOld", "bFlag").unwrap();
+        assert!(info.is_bit);
+        assert_eq!(info.bit_offset, 5);
+    }
+
+    #[test]
+    fn test_register_parser() {
+        struct ShiftedParser;
+        impl BlobParser for ShiftedParser {
+            fn parse_member(&self, name: &str, member: &RawMember) -> Result<(String, OffsetInfo), String> {
+                Ok((name.to_string(), OffsetInfo { offset: member.offset + 0x10, size: member.size, valid: true, ..Default::default() }))
+            }
+        }
+        let mut dsapi = fixture_dsapi();
+        dsapi.register_parser(20000, Box::new(ShiftedParser));
+        let blob = r#"{"data":[{"ANew":[{"Value":[["int32","D","",[]],8,4,1,0,0]}]}],"updated_at":"0","version":20000}"#;
+        dsapi.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+        assert_eq!(dsapi.get_member_offset("ANew", "Value").unwrap().offset, 0x18);
+    }
+
+    #[test]
+    fn test_parse_modes() {
+        let blob = r#"{"data":[{"ABad":[{"Broken":[["int32","D","",[]],8]},{"Good":[["int32","D","",[]],12,4,1]}]}],"updated_at":"0","version":10202}"#;
+        let mut dsapi = fixture_dsapi();
+        dsapi.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+        assert_eq!(dsapi.get_member_offset("ABad", "Good").unwrap().offset, 12);
+        assert!(dsapi.get_member_offset("ABad", "Broken").is_none());
+        assert_eq!(dsapi.parse_warnings().len(), 1);
+        assert_eq!(dsapi.parse_warnings()[0].to_string(), "ClassesInfo ABad::Broken: member array has 2 elements, expected at least 3 (in [..., 8])");
+
+        let mut dsapi = fixture_dsapi();
+        dsapi.options.parse_mode = ParseMode::Strict;
+        let error = dsapi.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap_err();
+        assert!(error.contains("ABad::Broken"));
+    }
+
+    #[test]
+    fn test_blob_meta() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(dsapi.dump_version(), Some(10202));
+        assert_eq!(dsapi.dump_updated_at(), Some(1718035200));
+        assert_eq!(dsapi.blob_meta(BlobKind::Enums).unwrap().updated_at, "1718035200");
+        assert_eq!(dsapi.offsets_credit().get("dumper_used").map(String::as_str), Some("Dumper-7"));
+    }
+
+    #[test]
+    fn test_dump_age() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(dsapi.dump_timestamp(), 1718035200);
+        assert!(dsapi.is_stale(std::time::Duration::from_secs(24 * 60 * 60)));
+        assert!(!dsapi.is_stale(std::time::Duration::from_secs(100 * 365 * 24 * 60 * 60)));
+        assert!(dsapi.predates(1718035201));
+        assert!(!dsapi.predates(1718035200));
+        assert_eq!(dsapi.game().clone().with_uploaded(1718035200123).uploaded_secs(), 1718035200);
+        assert!(dsapi.game().upload_age() > std::time::Duration::from_secs(24 * 60 * 60));
+    }
+
+    #[test]
+    fn test_get_enum_name_short() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(dsapi.get_enum_name_short("EFortRarity", 1), Some("Uncommon".to_string()));
+        assert_eq!(dsapi.get_enum_name_short("EFortRarity", 9), None);
+        assert_eq!(short_enum_name("EObjectFlags", "RF_Public"), "RF_Public");
+        assert_eq!(short_enum_name("EMode", "EMode::Fast"), "Fast");
+        assert_eq!(short_enum_name("EMode", "EModeExtra__Fast"), "EModeExtra__Fast");
+    }
+
+    #[test]
+    fn test_class_fingerprint() {
+        let mut dsapi = fixture_dsapi();
+        let fingerprint = dsapi.class_fingerprint("UWorld").unwrap();
+        assert_eq!(fixture_dsapi().class_fingerprint("UWorld"), Some(fingerprint));
+        assert_ne!(dsapi.class_fingerprint("UGameInstance"), Some(fingerprint));
+        assert_eq!(dsapi.class_fingerprint("UMissing"), None);
+
+        let mut moved = dsapi.get_member_offset("UWorld", "PersistentLevel").unwrap();
+        moved.offset += 8;
+        dsapi.overlay(DumpOverlay::new().member("UWorld", "PersistentLevel", moved));
+        assert_ne!(dsapi.class_fingerprint("UWorld"), Some(fingerprint));
+    }
+
+    #[test]
+    fn test_serialized_roundtrip() {
+        let dsapi = fixture_dsapi();
+        let restored: DSAPI = serde_json::from_str(&serde_json::to_string(&dsapi).unwrap()).unwrap();
+        assert_eq!(restored.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert_eq!(restored.get_class_size("FVector"), Some(24));
+        assert_eq!(restored.get_enum_name("EFortRarity", 1), Some("EFortRarity__Uncommon".to_string()));
+        assert!(restored.get_member_offset("UWorld", "X").is_none());
+    }
+
+    #[test]
+    fn test_parse_rejects_multi_key_entries() {
+        let blob = r#"{"data":[{"ABad":[{"A":[["int","D","",[]],0,4,1],"B":[["int","D","",[]],4,4,1]}]}],"updated_at":"0","version":10202}"#;
+        assert!(parse_blob::<ClassBlob>(blob.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_parse_error_context() {
+        let blob = r#"{"data":[{"AGood":[{"__MDKClassSize":8}]},{"ABad":[{"__MDKClassSize":"big"}]}],"updated_at":"0","version":10202}"#;
+        let error = parse_blob::<ClassBlob>(blob.as_bytes()).unwrap_err();
+        assert!(error.contains("in ABad") && error.contains("in __MDKClassSize"), "{}", error);
+
+        let blob = r#"{"data":[{"ABad":[{"Value":[["int32","D","",[]],"8",4]}]}],"updated_at":"0","version":10202}"#;
+        let mut dsapi = fixture_dsapi();
+        dsapi.options.parse_mode = ParseMode::Strict;
+        let error = dsapi.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap_err();
+        assert_eq!(error, "Failed to parse ClassesInfo ABad::Value: offset or size is not an integer (in [..., \"8\", 4])");
+    }
+
+    #[test]
+    #[cfg(network)]
+    fn test_builder_requires_game_id() {
+        assert_eq!(DSAPI::builder().cache_dir("unused").build().unwrap_err(), "No game ID set");
+    }
 
     #[test]
+    fn test_find_game_by_name() {
+        let game = |name: &str, hash: &str| Game {
+            hash: hash.to_string(),
+            name: name.to_string(),
+            engine: "Unreal-Engine-5".to_string(),
+            location: name.replace(' ', ""),
+            uploaded: u64::from_str_radix(hash, 16).unwrap(),
+            uploader: Uploader { name: String::new(), link: String::new() },
+        };
+        let list = GameList { games: vec![game("Fortnite", "6b77eceb"), game("Fortnite Festival", "1234abcd"), game("Rocket League", "deadbeef")] };
+        assert!(list.find_game_by_name("fortnite", NameMatch::Exact).is_none());
+        assert_eq!(list.find_game_by_name("fortnite", NameMatch::CaseInsensitive).unwrap().hash, "6b77eceb");
+        assert_eq!(list.find_game_by_name("fort", NameMatch::Fuzzy).unwrap().hash, "6b77eceb");
+        assert_eq!(list.find_game_by_name("fortnite-festival", NameMatch::Fuzzy).unwrap().hash, "1234abcd");
+        assert_eq!(list.find_game_by_name("rktlg", NameMatch::Fuzzy).unwrap().hash, "deadbeef");
+        assert!(list.find_game_by_name("Minecraft", NameMatch::Fuzzy).is_none());
+
+        let names = |games: Vec<&Game>| games.iter().map(|game| game.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(list.search("fortnite")), ["Fortnite", "Fortnite Festival"]);
+        assert_eq!(names(list.search("festival")), ["Fortnite Festival"]);
+        assert_eq!(list.games_by_engine("unreal-engine-5").count(), 3);
+        assert_eq!(list.games_by_engine("Unity").count(), 0);
+        assert_eq!((&list).into_iter().count(), 3);
+
+        assert_eq!(names(list.sorted_by_upload()), ["Rocket League", "Fortnite", "Fortnite Festival"]);
+        assert_eq!(names(list.recently_updated(0x1234abcd)), ["Rocket League", "Fortnite"]);
+    }
+
+    #[test]
+    #[cfg(network)]
+    fn test_force_refresh_updates_cache() {
+        let options = DownloadOptions { base_url: fixture_server(), ..Default::default() };
+        let cache_dir = std::env::temp_dir().join(format!("dsapi_force_refresh_{}", std::process::id()));
+        let ttl = std::time::Duration::from_secs(3600);
+        game_list_cache::store(&options.base_url, &GameList { games: Vec::new() }, Some(&cache_dir));
+        assert!(GameList::fetch_cached(&options, ttl, Some(&cache_dir)).unwrap().games.is_empty());
+
+        let refreshed = GameList::refresh(&options, Some(&cache_dir)).unwrap();
+        assert_eq!(refreshed.games.len(), fixture_dsapi().game_list.games.len());
+        assert_eq!(GameList::fetch_cached(&options, ttl, Some(&cache_dir)).unwrap().games.len(), refreshed.games.len());
+        // The next process reads the list from disk.
+        let on_disk: serde_json::Value = serde_json::from_slice(&std::fs::read(cache_dir.join("gamelist_cache.json")).unwrap()).unwrap();
+        assert_eq!(on_disk["list"]["games"].as_array().unwrap().len(), refreshed.games.len());
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(network)]
     fn test_new_dsapi() {
         let dsapi = DSAPI::new("6b77eceb", None);
         assert_eq!(dsapi.engine, "Unreal-Engine-5");
         assert_eq!(dsapi.location, "Fortnite");
+        assert_eq!(dsapi.game().name, "Fortnite");
     }
 
     #[test]
     fn test_get_member_offset_some() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         let info = dsapi.get_member_offset("UWorld", "OwningGameInstance");
         assert!(info.is_some());
         let info = info.unwrap();
@@ -402,63 +1737,61 @@ mod tests {
 
     #[test]
     fn test_get_member_offset_none() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert!(dsapi.get_member_offset("NoClass", "NoMember").is_none());
     }
 
     #[test]
     fn test_get_class_size_some() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_class_size("UWorld"), Some(2536));
     }
 
     #[test]
     fn test_get_class_size_none() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_class_size("NoClass"), None);
     }
 
     #[test]
-    #[allow(unreachable_code)] //removeme
     fn test_get_function_offset_some() {
-        return; //functions are not implemented yet.
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
-        assert_eq!(dsapi.get_function_offset("TestClass", "TestFunc"), Some(0x1234));
+        let dsapi = fixture_dsapi();
+        assert!(dsapi.get_function_offset("AActor", "K2_GetActorLocation").is_some());
     }
 
     #[test]
     fn test_get_function_offset_none() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_function_offset("NoClass", "NoFunc"), None);
     }
 
     #[test]
     fn test_get_enum_name_some() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_enum_name("EFortRarity", 1), Some("EFortRarity__Uncommon".to_string()));
     }
 
     #[test]
     fn test_get_enum_name_none() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_enum_name("NoEnum", 2), None);
     }
 
     #[test]
     fn test_get_offset_some() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
     }
 
     #[test]
     fn test_get_offset_none() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         assert_eq!(dsapi.get_offset("NO_OFFSET"), None);
     }
 
     #[test]
     fn test_get_member_offset_unchecked() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         let offset = dsapi.get_member_offset_unchecked("UWorld", "OwningGameInstance");
         assert_eq!(offset, 0x228);
     }
@@ -466,11 +1799,85 @@ mod tests {
     #[test]
     #[should_panic]
     fn test_get_member_offset_unchecked_panic() {
-        let dsapi = unsafe{ (&raw const LOCAL_DSAPI).as_ref().unwrap() };
+        let dsapi = fixture_dsapi();
         dsapi.get_member_offset_unchecked("NoClass", "NoMember");
     }
 
     #[test]
+    fn test_public_types_roundtrip() {
+        let dsapi = fixture_dsapi();
+        let info = dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap();
+        let restored: OffsetInfo = serde_json::from_str(&serde_json::to_string(&info).unwrap()).unwrap();
+        assert_eq!(restored, info);
+        let restored: GameList = serde_json::from_str(&serde_json::to_string(&dsapi.game_list).unwrap()).unwrap();
+        assert_eq!(restored, dsapi.game_list);
+        assert_eq!(Game::default().uploader, Uploader::default());
+    }
+
+    #[test]
+    fn test_ingest_blob() {
+        let mut dsapi = fixture_dsapi();
+        dsapi.offset_map.clear();
+        dsapi.ingest_blob(BlobKind::Offsets, include_bytes!("../tests/fixtures/OffsetsInfo.json").as_slice()).unwrap();
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert!(dsapi.ingest_blob(BlobKind::Enums, b"{}".as_slice()).unwrap_err().starts_with("Failed to parse EnumsInfo: "));
+    }
+
+    #[test]
+    fn test_load_blob() {
+        let game = Game { hash: "fixture".to_string(), uploaded: 1718035200, ..Default::default() };
+        let mut dsapi = DSAPI::from_game(game);
+        dsapi.overlay(DumpOverlay::new().offset("OFFSET_GWORLD", 0x10));
+        dsapi.load_blob(BlobKind::Classes, include_str!("../tests/fixtures/ClassesInfo.json")).unwrap();
+        dsapi.load_blob(BlobKind::Offsets, include_bytes!("../tests/fixtures/OffsetsInfo.json")).unwrap();
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x10));
+        assert_eq!(dsapi.get_offset("OFFSET_GNAMES"), fixture_dsapi().get_offset("OFFSET_GNAMES"));
+        assert_eq!(dsapi.game_list.get_game_by_hash("fixture").map(|game| game.uploaded), Some(dsapi.downloaded_at));
+        assert!(dsapi.load_blob(BlobKind::Enums, "{}").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn test_load_blob_gz() {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(include_bytes!("../tests/fixtures/OffsetsInfo.json")).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut dsapi = fixture_dsapi();
+        dsapi.offset_map.clear();
+        dsapi.load_blob_gz(BlobKind::Offsets, &compressed).unwrap();
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        // Blobs that were decompressed on the way are parsed as they are.
+        dsapi.offset_map.clear();
+        dsapi.load_blob_gz(BlobKind::Offsets, include_bytes!("../tests/fixtures/OffsetsInfo.json")).unwrap();
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert!(dsapi.load_blob_gz(BlobKind::Offsets, &compressed[..compressed.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_unchecked_family() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(dsapi.get_class_size_unchecked("UWorld"), 2536);
+        assert_eq!(dsapi.get_enum_name_unchecked("EFortRarity", 4), "EFortRarity__Legendary");
+        assert_eq!(dsapi.get_offset_unchecked("OFFSET_GWORLD"), 0x14942840);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset not found: OFFSET_NOPE")]
+    fn test_get_offset_unchecked_panic() {
+        fixture_dsapi().get_offset_unchecked("OFFSET_NOPE");
+    }
+
+    #[test]
+    #[should_panic(expected = "Member not found: UWorld::NoMember")]
+    fn test_unchecked_panic_message() {
+        fixture_dsapi().get_member_offset_unchecked("UWorld", "NoMember");
+    }
+
+    #[test]
+    #[cfg(network)]
     fn test_cache_self() {
         let dsapi = DSAPI::new("6b77eceb", Some(std::path::PathBuf::from("temp/test_cache")));
         dsapi.cache_self().expect("Failed to cache DSAPI");
@@ -481,6 +1888,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(network)]
     fn test_update_cache() {
         let mut dsapi = DSAPI::new("6b77eceb", Some(std::path::PathBuf::from("temp/test_update_cache")));
         dsapi.download_content().expect("Failed to download content");
@@ -489,7 +1897,7 @@ mod tests {
         dsapi.cache_self().expect("Failed to update cache");
 
         let mut new_dsapi = DSAPI::new("6b77eceb", Some(std::path::PathBuf::from("temp/test_update_cache")));
-        new_dsapi.game_list.games.iter_mut().find(|game| game.hash == new_dsapi.game_id)
+        new_dsapi.game_list.games.iter_mut().find(|game| game.hash == new_dsapi.game.hash)
             .expect("Game not found").uploaded += 1; // Increment the uploaded timestamp
         
         // Download the content again to trigger the cache update
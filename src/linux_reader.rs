@@ -0,0 +1,146 @@
+//! [`MemoryReader`] for another process on Linux, through `/proc/<pid>/mem`.
+
+use std::fs::File;
+use std::os::unix::fs::FileExt;
+
+use crate::{DSAPI, MemoryReader, detect};
+
+/// Reads the memory of a running process through `/proc/<pid>/mem`, including Windows games running under
+/// Wine or Proton. Requires the `linux-reader` feature, and ptrace access to the process
+/// (the same user with `kernel.yama.ptrace_scope = 0`, or root).
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, LinuxProcessReader, MemoryReader};
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// // Finds the game's process and sets the module base of `dsapi` to its executable.
+/// let reader = LinuxProcessReader::attach(&mut dsapi).unwrap();
+/// let world = reader.read_ptr(dsapi.get_offset_rebased("OFFSET_GWORLD").unwrap()).unwrap();
+/// let game_instance: u64 = dsapi.read_member(&reader, world, "UWorld", "OwningGameInstance").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct LinuxProcessReader {
+    mem: File,
+    pid: u32,
+}
+
+/// The file name of a path, with `/` or (for Wine processes) `\` separators.
+fn file_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+/// The executable name of a process: the first command line argument, which is the `.exe` for Wine processes,
+/// or `comm` for processes without a command line.
+fn executable(pid: u32) -> Option<String> {
+    let cmdline = std::fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    let argv0 = cmdline.split(|byte| *byte == 0).next().unwrap_or_default();
+    if !argv0.is_empty() {
+        return Some(file_name(&String::from_utf8_lossy(argv0)).to_string());
+    }
+    let comm = std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok()?;
+    Some(comm.trim_end().to_string())
+}
+
+/// Returns the IDs and executable names of all running processes.
+fn processes() -> Result<Vec<(u32, String)>, String> {
+    let entries = std::fs::read_dir("/proc").map_err(|e| format!("Failed to enumerate processes: {}", e))?;
+    let mut processes: Vec<_> = entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .filter_map(|pid| Some((pid, executable(pid)?)))
+        .collect();
+    processes.sort();
+    Ok(processes)
+}
+
+impl LinuxProcessReader {
+    /// Opens the process with the given ID for reading.
+    pub fn open(pid: u32) -> Result<Self, String> {
+        let mem = File::open(format!("/proc/{}/mem", pid))
+            .map_err(|e| format!("Failed to open process {}: {}", pid, e))?;
+        Ok(LinuxProcessReader { mem, pid })
+    }
+    /// Opens the first running process with the given executable name, e.g. `FortniteClient-Win64-Shipping.exe`.
+    /// The name is compared case-insensitively.
+    pub fn open_by_name(executable: &str) -> Result<Self, String> {
+        let (pid, _) = processes()?.into_iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(executable))
+            .ok_or_else(|| format!("Process not found: {}", executable))?;
+        LinuxProcessReader::open(pid)
+    }
+    /// Opens the running process of the game `dsapi` was created for, matching executable names like
+    /// [`GameList::find_game_by_executable`](crate::GameList::find_game_by_executable), and sets the
+    /// module base of `dsapi` to the process's executable so the `*_rebased` getters work right away.
+    pub fn attach(dsapi: &mut DSAPI) -> Result<Self, String> {
+        let (pid, _) = processes()?.into_iter()
+            .find(|(_, name)| detect::executable_matches(name, dsapi.game()))
+            .ok_or_else(|| format!("No running process found for {}", dsapi.game().name))?;
+        let reader = LinuxProcessReader::open(pid)?;
+        dsapi.set_module_base(reader.main_module_base()?);
+        Ok(reader)
+    }
+    /// Returns the ID of the process.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+    /// Returns the lowest address a file is mapped at in the process, e.g. `GameAssembly.dll` or `libUE4.so`,
+    /// parsed from `/proc/<pid>/maps`. The name is compared case-insensitively.
+    pub fn module_base(&self, module_name: &str) -> Result<u64, String> {
+        self.mapped_base(module_name, |path| file_name(path).eq_ignore_ascii_case(module_name))
+    }
+    /// Returns the base address of the process's executable, the `.exe` for Wine processes.
+    pub fn main_module_base(&self) -> Result<u64, String> {
+        let exe = std::fs::read_link(format!("/proc/{}/exe", self.pid))
+            .map_err(|e| format!("Failed to read executable of process {}: {}", self.pid, e))?;
+        let exe = exe.to_string_lossy();
+        // Under Wine the executable is the loader, the game's `.exe` is only named on the command line.
+        if file_name(&exe).starts_with("wine") {
+            let executable = executable(self.pid).ok_or_else(|| format!("Process {} has exited", self.pid))?;
+            return self.module_base(&executable);
+        }
+        self.mapped_base(&exe, |path| path == exe)
+    }
+    /// Returns the lowest address of the mappings in `/proc/<pid>/maps` whose path matches.
+    fn mapped_base(&self, module_name: &str, matches: impl Fn(&str) -> bool) -> Result<u64, String> {
+        let maps = std::fs::read_to_string(format!("/proc/{}/maps", self.pid))
+            .map_err(|e| format!("Failed to read memory map of process {}: {}", self.pid, e))?;
+        maps.lines()
+            .filter_map(|line| {
+                // `start-end perms offset dev inode path`, the path may contain spaces.
+                let mut fields = line.splitn(6, ' ');
+                let start = fields.next()?.split('-').next()?;
+                let path = fields.nth(4)?.trim_start();
+                if !matches(path) {
+                    return None;
+                }
+                u64::from_str_radix(start, 16).ok()
+            })
+            .min()
+            .ok_or_else(|| format!("Module {} not found", module_name))
+    }
+}
+
+impl MemoryReader for LinuxProcessReader {
+    fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String> {
+        self.mem.read_exact_at(buf, address).map_err(|e| format!("Failed to read 0x{:x}: {}", address, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_own_process() {
+        let value = 0x1234_5678_u32;
+        let reader = LinuxProcessReader::open(std::process::id()).unwrap();
+        assert_eq!(reader.read_value::<u32>(&value as *const u32 as u64), Ok(value));
+        assert!(reader.read_value::<u32>(0).is_err());
+
+        let main_module_base = reader.main_module_base().unwrap();
+        assert_ne!(main_module_base, 0);
+        let test_binary = std::env::current_exe().unwrap();
+        assert_eq!(reader.module_base(test_binary.file_name().unwrap().to_str().unwrap()), Ok(main_module_base));
+        assert_eq!(reader.mapped_base("test binary", |path| path == test_binary.to_str().unwrap()), Ok(main_module_base));
+        assert!(reader.module_base("NotLoaded.dll").is_err());
+    }
+}
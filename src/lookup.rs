@@ -0,0 +1,94 @@
+//! Case-insensitive lookups for hand-typed names.
+
+use rustc_hash::FxHashMap;
+
+use crate::DSAPI;
+use crate::footprint::{MapUsage, map_usage};
+use crate::intern::Sym;
+
+/// How `get_member_offset`, `get_class_size` and `get_offset` match names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LookupMode {
+    /// Names must match exactly.
+    #[default]
+    Exact,
+    /// Names that don't match exactly are compared ignoring case and whitespace, so `uworld` or
+    /// `Owning GameInstance` still resolve. Exact matches always win.
+    CaseInsensitive,
+}
+
+/// Every name of the dump under its folded spelling, built when [`LookupMode::CaseInsensitive`] is used.
+/// If several names fold to the same spelling, the first one parsed wins.
+#[derive(Debug, Default)]
+pub(crate) struct FoldedNames {
+    names: FxHashMap<String, Sym>,
+    offsets: FxHashMap<String, String>,
+}
+
+impl FoldedNames {
+    /// Estimated memory of both indexes, see [`DSAPI::memory_usage`].
+    pub(crate) fn memory_usage(&self) -> [MapUsage; 2] {
+        [map_usage("folded_names", &self.names), map_usage("folded_offsets", &self.offsets)]
+    }
+}
+
+/// Lowercases `name` and removes all whitespace.
+fn fold(name: &str) -> String {
+    name.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect()
+}
+
+impl DSAPI {
+    /// Sets how names are matched by lookups, see [`DSAPIBuilder::lookup_mode`](crate::DSAPIBuilder::lookup_mode).
+    pub fn set_lookup_mode(&mut self, lookup_mode: LookupMode) {
+        self.options.lookup_mode = lookup_mode;
+        self.index_folded_names();
+    }
+    /// Rebuilds the folded names after the dump changed. Does nothing for exact lookups.
+    pub(crate) fn index_folded_names(&mut self) {
+        if self.options.lookup_mode == LookupMode::Exact {
+            self.folded_names = None;
+            return;
+        }
+        let mut folded = FoldedNames::default();
+        for (index, name) in self.names.iter().enumerate() {
+            folded.names.entry(fold(name)).or_insert(Sym(index as u32));
+        }
+        let mut offset_names: Vec<&String> = self.offset_map.keys().collect();
+        offset_names.sort();
+        for name in offset_names {
+            folded.offsets.entry(fold(name)).or_insert_with(|| name.clone());
+        }
+        self.folded_names = Some(folded);
+    }
+    /// The symbol of a class or member name, falling back to its folded spelling if enabled.
+    pub(crate) fn lookup_sym(&self, name: &str) -> Option<Sym> {
+        self.names.get(name).or_else(|| self.folded_names.as_ref()?.names.get(&fold(name)).copied())
+    }
+    /// The spelling of a global offset name in the dump, if it only matches when folded.
+    pub(crate) fn lookup_offset_name(&self, name: &str) -> Option<&str> {
+        self.folded_names.as_ref()?.offsets.get(&fold(name)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_insensitive_lookups() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(dsapi.get_class_size("uworld"), None);
+        dsapi.set_lookup_mode(LookupMode::CaseInsensitive);
+        assert_eq!(dsapi.get_class_size("uworld"), Some(2536));
+        assert_eq!(dsapi.get_member_offset("UWORLD", " owning GameInstance ").unwrap().offset, 0x228);
+        assert_eq!(dsapi.get_offset("offset_gworld"), Some(0x14942840));
+        assert_eq!(dsapi.get_offset("OFFSET_NOPE"), None);
+
+        // Names added later are found too.
+        dsapi.overlay(crate::DumpOverlay::new().offset("OFFSET_Extra", 0x10));
+        assert_eq!(dsapi.get_offset("offset_extra"), Some(0x10));
+
+        dsapi.set_lookup_mode(LookupMode::Exact);
+        assert_eq!(dsapi.get_class_size("uworld"), None);
+    }
+}
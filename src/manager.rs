@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::builder::DownloadOptions;
+use crate::{CacheStore, DSAPI, GameList, NameMatch, SharedDsApi, game_list_cache};
+
+/// A game finished by [`DsManager::prefetch_all_with_progress`].
+#[derive(Debug, Clone)]
+pub struct PrefetchProgress {
+    pub game_id: String,
+    /// Number of games finished so far, including this one.
+    pub completed: usize,
+    /// Number of games being prefetched.
+    pub total: usize,
+    /// Why the game failed, `None` if it was downloaded or already cached.
+    pub error: Option<String>,
+}
+
+/// Works with several games at once while sharing one game list, one HTTP client and one cache directory.
+/// Every game gets its own subdirectory in the cache directory.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DsManager, NameMatch};
+/// let mut manager = DsManager::new(Some("dumpspace_cache".into())).unwrap();
+/// let fortnite = manager.get("6b77eceb").unwrap();
+/// let other = manager.get_by_name("Palworld", NameMatch::CaseInsensitive).unwrap();
+/// println!("{:?}", fortnite.get_offset("OFFSET_GWORLD"));
+/// println!("{:?}", other.get_offset("OFFSET_GWORLD"));
+/// ```
+#[derive(Debug)]
+pub struct DsManager {
+    game_list: GameList,
+    options: DownloadOptions,
+    cache_dir: Option<PathBuf>,
+    loaded: HashMap<String, SharedDsApi>,
+}
+
+impl DsManager {
+    /// Creates a manager with the default settings. Use [`crate::DSAPIBuilder::build_manager`] to configure it.
+    pub fn new(cache_dir: Option<PathBuf>) -> Result<Self, String> {
+        let mut builder = DSAPI::builder();
+        if let Some(cache_dir) = cache_dir {
+            builder = builder.cache_dir(cache_dir);
+        }
+        builder.build_manager()
+    }
+
+    pub(crate) fn with_game_list(game_list: GameList, options: DownloadOptions, cache_dir: Option<PathBuf>) -> Self {
+        DsManager { game_list, options, cache_dir, loaded: HashMap::new() }
+    }
+
+    /// Returns the shared game list.
+    pub fn game_list(&self) -> &GameList {
+        &self.game_list
+    }
+
+    /// Fetches the game list again. Already loaded games are kept, use [`SharedDsApi::refresh`] to update them.
+    pub fn refresh_game_list(&mut self) -> Result<(), String> {
+        self.game_list = GameList::fetch(&self.options)?;
+        Ok(())
+    }
+
+    /// Returns the dump of a game, downloading it on first use.
+    /// Later calls return a handle to the same dump.
+    pub fn get(&mut self, game_id: &str) -> Result<SharedDsApi, String> {
+        if let Some(dsapi) = self.loaded.get(game_id) {
+            return Ok(dsapi.clone());
+        }
+        let cache_path = self.cache_dir.as_ref().map(|dir| dir.join(game_id));
+        let mut dsapi = DSAPI::with_game_list(game_id, cache_path, self.game_list.clone(), self.options.clone())?;
+        dsapi.download_content()?;
+        let shared = SharedDsApi::new(dsapi);
+        self.loaded.insert(game_id.to_string(), shared.clone());
+        Ok(shared)
+    }
+
+    /// Like [`DsManager::get`], but selects the game by name, see [`GameList::find_game_by_name`].
+    pub fn get_by_name(&mut self, game_name: &str, matching: NameMatch) -> Result<SharedDsApi, String> {
+        let game_id = self.game_list.find_game_by_name(game_name, matching)
+            .ok_or_else(|| format!("Game not found: {}", game_name))?
+            .hash
+            .clone();
+        self.get(&game_id)
+    }
+
+    /// The manager's cache directory, to list, evict and purge cached games. `None` without a cache directory.
+    pub fn cache_store(&self) -> Option<CacheStore> {
+        self.cache_dir.clone().map(CacheStore::new)
+    }
+
+    /// Iterates over the IDs of the games that were loaded so far.
+    pub fn loaded_games(&self) -> impl Iterator<Item = &str> {
+        self.loaded.keys().map(|game_id| game_id.as_str())
+    }
+
+    /// Drops the manager's handle to a game. Returns `false` if it was not loaded.
+    pub fn unload(&mut self, game_id: &str) -> bool {
+        self.loaded.remove(game_id).is_some()
+    }
+
+    /// Downloads the dumps of `game_ids` into the cache directory without keeping them loaded, and caches
+    /// the game list next to them. Games that are cached and up to date are not downloaded again.
+    /// Every game is tried, the error names all games that failed.
+    pub fn prefetch(&self, game_ids: &[&str]) -> Result<(), String> {
+        self.prefetch_all(game_ids, 1)
+    }
+
+    /// Like [`DsManager::prefetch`], downloading up to `concurrency` games at the same time.
+    pub fn prefetch_all(&self, game_ids: &[&str], concurrency: usize) -> Result<(), String> {
+        self.prefetch_all_with_progress(game_ids, concurrency, |_| {})
+    }
+
+    /// Like [`DsManager::prefetch_all`], calling `on_progress` from the worker threads whenever a game finished.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DsManager;
+    /// let manager = DsManager::new(Some("dumpspace_cache".into())).unwrap();
+    /// let game_ids: Vec<&str> = manager.game_list().games.iter().map(|game| game.hash.as_str()).collect();
+    /// manager.prefetch_all_with_progress(&game_ids, 8, |progress| {
+    ///     println!("[{}/{}] {} {}", progress.completed, progress.total, progress.game_id, progress.error.as_deref().unwrap_or("ok"));
+    /// }).unwrap();
+    /// ```
+    pub fn prefetch_all_with_progress(&self, game_ids: &[&str], concurrency: usize, on_progress: impl Fn(&PrefetchProgress) + Sync) -> Result<(), String> {
+        let cache_dir = self.cache_dir.as_ref().ok_or("No cache directory set")?;
+        game_list_cache::store(&self.options.base_url, &self.game_list, Some(cache_dir));
+        let next = AtomicUsize::new(0);
+        // The errors by index in `game_ids`, and how many games completed.
+        let results = Mutex::new((vec![None::<String>; game_ids.len()], 0));
+        std::thread::scope(|scope| {
+            for _ in 0..concurrency.clamp(1, game_ids.len().max(1)) {
+                scope.spawn(|| {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&game_id) = game_ids.get(index) else {
+                            break;
+                        };
+                        let error = DSAPI::with_game_list(game_id, Some(cache_dir.join(game_id)), self.game_list.clone(), self.options.clone())
+                            .and_then(|mut dsapi| dsapi.download_content())
+                            .err();
+                        let mut results = results.lock().unwrap();
+                        results.0[index] = error.clone();
+                        results.1 += 1;
+                        on_progress(&PrefetchProgress { game_id: game_id.to_string(), completed: results.1, total: game_ids.len(), error });
+                    }
+                });
+            }
+        });
+        let (errors, _) = results.into_inner().unwrap();
+        let failed: Vec<String> = game_ids.iter().zip(errors)
+            .filter_map(|(game_id, error)| Some(format!("{}: {}", game_id, error?)))
+            .collect();
+        if !failed.is_empty() {
+            return Err(format!("Failed to prefetch {} of {} games: {}", failed.len(), game_ids.len(), failed.join("; ")));
+        }
+        Ok(())
+    }
+}
+
+impl DSAPI {
+    /// Downloads and caches the dumps of several games in one go, e.g. on a good connection before going
+    /// to an offline environment. The cache directory has the layout of a [`DsManager`]: the game list, and
+    /// a subdirectory per game. Load them later with a long game list TTL so the game list isn't fetched either.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// use std::time::Duration;
+    /// DSAPI::prefetch(&["6b77eceb", "5f1f5f5a"], "dumpspace_cache").unwrap();
+    /// // Later, offline:
+    /// let mut manager = DSAPI::builder()
+    ///     .cache_dir("dumpspace_cache")
+    ///     .game_list_ttl(Duration::from_secs(60 * 60 * 24 * 365))
+    ///     .build_manager()
+    ///     .unwrap();
+    /// let fortnite = manager.get("6b77eceb").unwrap();
+    /// ```
+    pub fn prefetch(game_ids: &[&str], cache_dir: impl Into<PathBuf>) -> Result<(), String> {
+        DSAPI::builder().cache_dir(cache_dir).build_manager()?.prefetch(game_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_manager_reuses_loaded_games() {
+        let dsapi = fixture_dsapi();
+        let mut manager = DsManager::with_game_list(dsapi.game_list.clone(), DownloadOptions::default(), None);
+        manager.loaded.insert("fixture".to_string(), SharedDsApi::new(dsapi));
+        let handle = manager.get_by_name("fixture", NameMatch::CaseInsensitive).unwrap();
+        assert_eq!(handle.get_class_size("UWorld"), Some(2536));
+        assert_eq!(manager.loaded_games().collect::<Vec<_>>(), ["fixture"]);
+        assert!(manager.get("missing").unwrap_err().contains("Game not found"));
+        assert!(manager.unload("fixture"));
+    }
+
+    #[test]
+    fn test_prefetch() {
+        let cache_dir = PathBuf::from("temp/test_prefetch");
+        let base_url = crate::tests::fixture_server();
+        let manager = DSAPI::builder().base_url(&base_url).cache_dir(&cache_dir).build_manager().unwrap();
+        let completed = Mutex::new(Vec::new());
+        let error = manager.prefetch_all_with_progress(&["missing", "fixture", "other"], 2, |progress| {
+            assert_eq!(progress.total, 3);
+            completed.lock().unwrap().push(progress.completed);
+        }).unwrap_err();
+        assert_eq!(error, "Failed to prefetch 2 of 3 games: missing: Game not found: missing; other: Game not found: other");
+        assert_eq!(completed.into_inner().unwrap(), [1, 2, 3]);
+        assert!(cache_dir.join("fixture/dsapi_cache.json").exists());
+        assert!(game_list_cache::load(&base_url, std::time::Duration::from_secs(60), Some(&cache_dir)).is_some());
+
+        let mut manager = DSAPI::builder()
+            .base_url(&base_url)
+            .cache_dir(&cache_dir)
+            .game_list_ttl(std::time::Duration::from_secs(3600))
+            .build_manager()
+            .unwrap();
+        assert_eq!(manager.get("fixture").unwrap().get_class_size("UWorld"), Some(2536));
+        let cached = manager.cache_store().unwrap().list().unwrap();
+        assert_eq!(cached.iter().map(|game| game.game_id.as_str()).collect::<Vec<_>>(), ["fixture"]);
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,163 @@
+//! Checking a manifest of expected offsets against the dump.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_derive::Deserialize;
+
+use crate::DSAPI;
+
+/// An entry of a manifest that doesn't match the dump, see [`DSAPI::verify_manifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Drift {
+    /// The member is at a different offset than expected.
+    MemberMoved { class: String, member: String, expected: u64, actual: u64 },
+    /// The member (or its class) is not in the dump anymore.
+    MemberMissing { class: String, member: String, expected: u64 },
+    /// The global offset differs from the expected one.
+    OffsetMoved { name: String, expected: u64, actual: u64 },
+    /// The global offset is not in the dump anymore.
+    OffsetMissing { name: String, expected: u64 },
+}
+
+impl std::fmt::Display for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Drift::MemberMoved { class, member, expected, actual } => write!(f, "{}::{} moved from 0x{:x} to 0x{:x}", class, member, expected, actual),
+            Drift::MemberMissing { class, member, expected } => write!(f, "{}::{} (expected at 0x{:x}) is missing", class, member, expected),
+            Drift::OffsetMoved { name, expected, actual } => write!(f, "{} moved from 0x{:x} to 0x{:x}", name, expected, actual),
+            Drift::OffsetMissing { name, expected } => write!(f, "{} (expected 0x{:x}) is missing", name, expected),
+        }
+    }
+}
+
+/// An expected offset, as a number or a (hex) string like `"0x228"`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestValue {
+    Number(u64),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestEntry {
+    Offset(ManifestValue),
+    Class(BTreeMap<String, ManifestValue>),
+}
+
+impl ManifestValue {
+    fn parse(&self, name: &str) -> Result<u64, String> {
+        match self {
+            ManifestValue::Number(value) => Ok(*value),
+            ManifestValue::Text(text) => {
+                let parsed = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+                    Some(hex) => u64::from_str_radix(hex, 16),
+                    None => text.parse(),
+                };
+                parsed.map_err(|e| format!("Invalid offset for {}: {}", name, e))
+            }
+        }
+    }
+}
+
+impl DSAPI {
+    /// Checks a manifest of expected offsets, e.g. kept in your repository, and returns every entry that
+    /// moved or disappeared. An empty result means the dump still matches what your code was written against.
+    ///
+    /// Tables are classes with their member offsets, plain values are global offsets. Values are numbers or
+    /// strings like `"0x228"`. Files ending in `.toml` are read as TOML (requires the `toml` feature), anything
+    /// else as JSON:
+    /// ```toml
+    /// OFFSET_GWORLD = 0x14942840
+    ///
+    /// [UWorld]
+    /// OwningGameInstance = 0x228
+    /// ```
+    /// or `{"OFFSET_GWORLD": "0x14942840", "UWorld": {"OwningGameInstance": 552}}`.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// for drift in dsapi.verify_manifest("offsets.toml").unwrap() {
+    ///     eprintln!("{}", drift);
+    /// }
+    /// ```
+    pub fn verify_manifest(&self, path: impl AsRef<Path>) -> Result<Vec<Drift>, String> {
+        let path = path.as_ref();
+        let serialized = std::fs::read_to_string(path).map_err(|e| format!("Failed to read manifest: {}", e))?;
+        let entries: BTreeMap<String, ManifestEntry> = match path.extension().and_then(|extension| extension.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => toml::from_str(&serialized).map_err(|e| format!("Failed to parse manifest: {}", e))?,
+            #[cfg(not(feature = "toml"))]
+            Some("toml") => return Err("TOML manifests require the `toml` feature".to_string()),
+            _ => serde_json::from_str(&serialized).map_err(|e| format!("Failed to parse manifest: {}", e))?,
+        };
+        self.verify_entries(&entries)
+    }
+    fn verify_entries(&self, entries: &BTreeMap<String, ManifestEntry>) -> Result<Vec<Drift>, String> {
+        let mut drifts = Vec::new();
+        for (name, entry) in entries {
+            match entry {
+                ManifestEntry::Offset(value) => {
+                    let expected = value.parse(name)?;
+                    match self.get_offset(name) {
+                        Some(actual) if actual == expected => {}
+                        Some(actual) => drifts.push(Drift::OffsetMoved { name: name.clone(), expected, actual }),
+                        None => drifts.push(Drift::OffsetMissing { name: name.clone(), expected }),
+                    }
+                }
+                ManifestEntry::Class(members) => {
+                    for (member, value) in members {
+                        let expected = value.parse(&format!("{}::{}", name, member))?;
+                        let (class, member) = (name.clone(), member.clone());
+                        match self.get_member_offset(&class, &member).map(|info| info.offset as u64) {
+                            Some(actual) if actual == expected => {}
+                            Some(actual) => drifts.push(Drift::MemberMoved { class, member, expected, actual }),
+                            None => drifts.push(Drift::MemberMissing { class, member, expected }),
+                        }
+                    }
+                }
+            }
+        }
+        Ok(drifts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_manifest() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let manifest = r#"{
+            "OFFSET_GWORLD": "0x14942840",
+            "OFFSET_GONE": 16,
+            "UWorld": {"OwningGameInstance": 552, "PersistentLevel": "0x38", "Removed": "0x10"}
+        }"#;
+        let drifts = dsapi.verify_entries(&serde_json::from_str(manifest).unwrap()).unwrap();
+        assert_eq!(drifts, [
+            Drift::OffsetMissing { name: "OFFSET_GONE".to_string(), expected: 16 },
+            Drift::MemberMoved { class: "UWorld".to_string(), member: "PersistentLevel".to_string(), expected: 0x38, actual: 0x30 },
+            Drift::MemberMissing { class: "UWorld".to_string(), member: "Removed".to_string(), expected: 0x10 },
+        ]);
+        assert_eq!(drifts[1].to_string(), "UWorld::PersistentLevel moved from 0x38 to 0x30");
+
+        let invalid = serde_json::from_str(r#"{"OFFSET_GWORLD": "0xzz"}"#).unwrap();
+        assert!(dsapi.verify_entries(&invalid).unwrap_err().starts_with("Invalid offset for OFFSET_GWORLD"));
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn test_verify_toml_manifest() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let path = Path::new("temp/test_manifest.toml");
+        std::fs::create_dir_all("temp").unwrap();
+        std::fs::write(path, "OFFSET_GWORLD = 0x14942840\n\n[UWorld]\nOwningGameInstance = 0x230\n").unwrap();
+        let drifts = dsapi.verify_manifest(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(drifts, [Drift::MemberMoved { class: "UWorld".to_string(), member: "OwningGameInstance".to_string(), expected: 0x230, actual: 0x228 }]);
+    }
+}
@@ -0,0 +1,265 @@
+//! A binary snapshot format for [`FrozenDump`] that is looked up in place through a memory map.
+//!
+//! All integers are little-endian. The file is a header of ten `u32`s (magic, version, the number of names,
+//! members, class sizes, enum values and offsets, the length of the string table, of the engine and of the
+//! location), the engine and location, the end of every name in the string table as `u32`, the string table,
+//! and then fixed-size records sorted like the `Vec`s of `FrozenDump`. Names are sorted, so a name's index
+//! compares like the name itself.
+
+use std::fs::File;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::intern::Sym;
+use crate::{FrozenDump, OffsetInfo};
+
+const MAGIC: &[u8; 4] = b"DSMM";
+const VERSION: u32 = 1;
+const HEADER_LEN: usize = 40;
+const MEMBER_LEN: usize = 32;
+const CLASS_SIZE_LEN: usize = 8;
+const ENUM_NAME_LEN: usize = 16;
+const OFFSET_LEN: usize = 16;
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+impl FrozenDump {
+    /// Writes the frozen dump to a binary snapshot file for [`MappedDump::open`]. Requires the `mmap` feature.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::{DSAPI, MappedDump};
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.freeze().save_mapped("fortnite.dsmm").unwrap();
+    /// let mapped = MappedDump::open("fortnite.dsmm").unwrap();
+    /// println!("{:?}", mapped.get_member_offset("UWorld", "OwningGameInstance"));
+    /// ```
+    pub fn save_mapped(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        // Offset names go into the same sorted name table as everything else.
+        let mut names: Vec<&str> = self.names.iter().map(|name| &**name).chain(self.offsets.iter().map(|(name, _)| &**name)).collect();
+        names.sort_unstable();
+        names.dedup();
+        let remap = |sym: Sym| names.binary_search(&self.resolve(sym)).unwrap() as u32;
+
+        let strings_len: usize = names.iter().map(|name| name.len()).sum();
+        let counts = [names.len(), self.members.len(), self.class_sizes.len(), self.enum_names.len(), self.offsets.len()];
+        let mut bytes = Vec::with_capacity(HEADER_LEN + strings_len + names.len() * 4 + self.members.len() * MEMBER_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        for count in counts.into_iter().chain([strings_len, self.engine.len(), self.location.len()]) {
+            let count = u32::try_from(count).map_err(|_| "Dump is too large for a mapped snapshot".to_string())?;
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+        bytes.extend_from_slice(self.engine.as_bytes());
+        bytes.extend_from_slice(self.location.as_bytes());
+        let mut end = 0u32;
+        for name in &names {
+            end += name.len() as u32;
+            bytes.extend_from_slice(&end.to_le_bytes());
+        }
+        for name in &names {
+            bytes.extend_from_slice(name.as_bytes());
+        }
+        for (class, member, info) in &self.members {
+            bytes.extend_from_slice(&remap(*class).to_le_bytes());
+            bytes.extend_from_slice(&remap(*member).to_le_bytes());
+            bytes.extend_from_slice(&info.offset.to_le_bytes());
+            bytes.extend_from_slice(&info.size.to_le_bytes());
+            bytes.extend_from_slice(&info.bit_offset.to_le_bytes());
+            bytes.extend_from_slice(&(info.is_bit as u32 | (info.valid as u32) << 1).to_le_bytes());
+        }
+        for (class, size) in &self.class_sizes {
+            bytes.extend_from_slice(&remap(*class).to_le_bytes());
+            bytes.extend_from_slice(&size.to_le_bytes());
+        }
+        for (enum_name, value, value_name) in &self.enum_names {
+            bytes.extend_from_slice(&remap(*enum_name).to_le_bytes());
+            bytes.extend_from_slice(&remap(*value_name).to_le_bytes());
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        for (name, offset) in &self.offsets {
+            let name = names.binary_search(&&**name).unwrap() as u32;
+            bytes.extend_from_slice(&name.to_le_bytes());
+            bytes.extend_from_slice(&0u32.to_le_bytes());
+            bytes.extend_from_slice(&offset.to_le_bytes());
+        }
+        std::fs::write(path, bytes).map_err(|e| format!("Failed to write snapshot file: {}", e))
+    }
+}
+
+/// A snapshot written by [`FrozenDump::save_mapped`], memory-mapped instead of read into the heap.
+/// The OS pages the data in as lookups touch it, and processes mapping the same file share its memory.
+/// Lookups binary-search the file in place, like [`FrozenDump`]. Requires the `mmap` feature.
+///
+/// The file must not be modified while it is mapped.
+#[derive(Debug)]
+pub struct MappedDump {
+    map: memmap2::Mmap,
+    names: usize,
+    name_ends: usize,
+    strings: usize,
+    /// Start and record count of the members, class sizes, enum values and offsets.
+    tables: [(usize, usize); 4],
+    engine: Range<usize>,
+    location: Range<usize>,
+}
+
+impl MappedDump {
+    /// Maps a snapshot file written by [`FrozenDump::save_mapped`]. Fails if it isn't one or is truncated.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| format!("Failed to open snapshot file: {}", e))?;
+        // SAFETY: the mapping is only read, and the documentation requires the file to stay unmodified.
+        let map = unsafe { memmap2::Mmap::map(&file) }.map_err(|e| format!("Failed to map snapshot file: {}", e))?;
+        Self::from_map(map)
+    }
+
+    fn from_map(map: memmap2::Mmap) -> Result<Self, String> {
+        if map.len() < HEADER_LEN || &map[..4] != MAGIC {
+            return Err("Not a mapped snapshot file".to_string());
+        }
+        if read_u32(&map, 4) != VERSION {
+            return Err(format!("Unsupported mapped snapshot version {}", read_u32(&map, 4)));
+        }
+        let field = |index: usize| read_u32(&map, 8 + index * 4) as usize;
+        let [names, members, class_sizes, enum_names, offsets, strings_len, engine_len, location_len] = std::array::from_fn(field);
+        let engine = HEADER_LEN..HEADER_LEN + engine_len;
+        let location = engine.end..engine.end + location_len;
+        let name_ends = location.end;
+        let strings = name_ends + names * 4;
+        let mut start = strings + strings_len;
+        let mut tables = [(0, 0); 4];
+        for (table, (count, len)) in tables.iter_mut().zip([(members, MEMBER_LEN), (class_sizes, CLASS_SIZE_LEN), (enum_names, ENUM_NAME_LEN), (offsets, OFFSET_LEN)]) {
+            *table = (start, count);
+            start += count * len;
+        }
+        if start != map.len() {
+            return Err("Mapped snapshot file is truncated or corrupt".to_string());
+        }
+        // Name lookups slice the string table with these, so check them once up front.
+        let mut previous = 0;
+        for index in 0..names {
+            let end = read_u32(&map, name_ends + index * 4) as usize;
+            if end < previous || end > strings_len {
+                return Err("Mapped snapshot file is truncated or corrupt".to_string());
+            }
+            previous = end;
+        }
+        Ok(MappedDump { map, names, name_ends, strings, tables, engine, location })
+    }
+
+    fn name(&self, index: usize) -> &[u8] {
+        let start = if index == 0 { 0 } else { read_u32(&self.map, self.name_ends + (index - 1) * 4) as usize };
+        let end = read_u32(&self.map, self.name_ends + index * 4) as usize;
+        &self.map[self.strings + start..self.strings + end]
+    }
+
+    fn sym(&self, name: &str) -> Option<u32> {
+        let (mut low, mut high) = (0, self.names);
+        while low < high {
+            let middle = (low + high) / 2;
+            match self.name(middle).cmp(name.as_bytes()) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return Some(middle as u32),
+            }
+        }
+        None
+    }
+
+    /// Binary-searches table `table` of records `len` bytes long for the record whose first `key.len()` bytes,
+    /// read as little-endian `u32`/`i64` fields by `read_key`, equal `key`. Returns the record's bytes.
+    fn find<K: Ord>(&self, table: usize, len: usize, key: K, read_key: impl Fn(&[u8]) -> K) -> Option<&[u8]> {
+        let (start, count) = self.tables[table];
+        let (mut low, mut high) = (0, count);
+        while low < high {
+            let middle = (low + high) / 2;
+            let record = &self.map[start + middle * len..start + (middle + 1) * len];
+            match read_key(record).cmp(&key) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return Some(record),
+            }
+        }
+        None
+    }
+
+    pub fn engine(&self) -> &str {
+        std::str::from_utf8(&self.map[self.engine.clone()]).unwrap_or_default()
+    }
+    pub fn location(&self) -> &str {
+        std::str::from_utf8(&self.map[self.location.clone()]).unwrap_or_default()
+    }
+    /// Returns the offset info for a class member as an `Option<OffsetInfo>`.
+    pub fn get_member_offset(&self, class_name: &str, member_name: &str) -> Option<OffsetInfo> {
+        let key = (self.sym(class_name)?, self.sym(member_name)?);
+        let record = self.find(0, MEMBER_LEN, key, |record| (read_u32(record, 0), read_u32(record, 4)))?;
+        let flags = read_u32(record, 28);
+        Some(OffsetInfo {
+            offset: read_u64(record, 8) as i64,
+            size: read_u64(record, 16) as i64,
+            bit_offset: read_u32(record, 24) as i32,
+            is_bit: flags & 1 != 0,
+            valid: flags & 2 != 0,
+        })
+    }
+    /// Returns the size of a class as an `Option<i32>`.
+    /// Returns `None` if the class is not found.
+    pub fn get_class_size(&self, class_name: &str) -> Option<i32> {
+        let record = self.find(1, CLASS_SIZE_LEN, self.sym(class_name)?, |record| read_u32(record, 0))?;
+        Some(read_u32(record, 4) as i32)
+    }
+    /// Returns the name of an enum value as an `Option<String>`.
+    /// Returns `None` if the enum name or value is not found.
+    pub fn get_enum_name(&self, enum_name: &str, enum_value: i64) -> Option<String> {
+        let key = (self.sym(enum_name)?, enum_value);
+        let record = self.find(2, ENUM_NAME_LEN, key, |record| (read_u32(record, 0), read_u64(record, 8) as i64))?;
+        Some(String::from_utf8_lossy(self.name(read_u32(record, 4) as usize)).into_owned())
+    }
+    /// Returns the offset of a specific offset name as an `Option<u64>`.
+    /// Returns `None` if the offset name is not found.
+    pub fn get_offset(&self, offset_name: &str) -> Option<u64> {
+        let record = self.find(3, OFFSET_LEN, self.sym(offset_name)?, |record| read_u32(record, 0))?;
+        Some(read_u64(record, 8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapped_matches_frozen() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let frozen = dsapi.freeze();
+        std::fs::create_dir_all("temp/test_mapped").unwrap();
+        frozen.save_mapped("temp/test_mapped/fixture.dsmm").expect("Failed to save snapshot");
+        let mapped = MappedDump::open("temp/test_mapped/fixture.dsmm").expect("Failed to map snapshot");
+
+        for (class, member, _) in dsapi.members() {
+            assert_eq!(mapped.get_member_offset(class, member), frozen.get_member_offset(class, member));
+        }
+        for (class, size) in dsapi.class_sizes() {
+            assert_eq!(mapped.get_class_size(class), Some(size));
+        }
+        assert_eq!(mapped.get_member_offset("UWorld", "bIsWorldInitialized").unwrap().bit_offset, 2);
+        assert_eq!(mapped.get_member_offset("UWorld", "X"), None);
+        assert_eq!(mapped.get_enum_name("EFortRarity", 3), Some("EFortRarity__Epic".to_string()));
+        assert_eq!(mapped.get_enum_name("EFortRarity", 99), None);
+        assert_eq!(mapped.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert_eq!(mapped.get_offset("UWorld"), None);
+        assert_eq!(mapped.engine(), dsapi.engine);
+
+        std::fs::write("temp/test_mapped/truncated.dsmm", &mapped.map[..mapped.map.len() - 1]).unwrap();
+        assert_eq!(MappedDump::open("temp/test_mapped/truncated.dsmm").unwrap_err(), "Mapped snapshot file is truncated or corrupt");
+        frozen.save_snapshot("temp/test_mapped/fixture.snapshot").unwrap();
+        assert_eq!(MappedDump::open("temp/test_mapped/fixture.snapshot").unwrap_err(), "Not a mapped snapshot file");
+        drop(mapped);
+        std::fs::remove_dir_all("temp/test_mapped").expect("Failed to clean up snapshot directory");
+    }
+}
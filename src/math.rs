@@ -0,0 +1,120 @@
+//! Readers for Unreal Engine math structs, which are `float`s in UE4 and `double`s in UE5.
+
+use crate::{DSAPI, MemoryReader};
+
+/// An `FVector`, read with [`DSAPI::read_vector`]. UE4 `float` components are widened to `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FVector {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// An `FRotator` in degrees, read with [`DSAPI::read_rotator`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FRotator {
+    pub pitch: f64,
+    pub yaw: f64,
+    pub roll: f64,
+}
+
+/// An `FQuat`, the rotation of an [`FTransform`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FQuat {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+/// An `FTransform`, read with [`DSAPI::read_transform`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FTransform {
+    pub rotation: FQuat,
+    pub translation: FVector,
+    pub scale: FVector,
+}
+
+impl DSAPI {
+    /// Returns `true` if the math structs of this game use `double`s, as in UE5 with large world coordinates.
+    /// Decided by the size of `FVector` in the dump, or if the dump doesn't contain it, `false` only for UE4 games.
+    pub fn uses_double_precision(&self) -> bool {
+        match self.get_class_size("FVector") {
+            Some(size) => size == 24,
+            None => !self.engine.eq_ignore_ascii_case("Unreal-Engine-4"),
+        }
+    }
+    /// Reads `N` consecutive `float`s or `double`s at `address`, depending on the game's precision.
+    fn read_components<const N: usize>(&self, reader: &impl MemoryReader, address: u64) -> Result<[f64; N], String> {
+        if self.uses_double_precision() {
+            reader.read_value::<[f64; N]>(address)
+        } else {
+            Ok(reader.read_value::<[f32; N]>(address)?.map(f64::from))
+        }
+    }
+    /// Reads the `FVector` at `address`, e.g. `USceneComponent::RelativeLocation`, with the precision of the game.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::{DSAPI, MemoryReader};
+    /// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, root_component: u64) -> Result<(), String> {
+    /// let offset = dsapi.get_member_offset("USceneComponent", "RelativeLocation").unwrap().offset as u64;
+    /// let location = dsapi.read_vector(reader, root_component + offset)?;
+    /// println!("{} {} {}", location.x, location.y, location.z);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_vector(&self, reader: &impl MemoryReader, address: u64) -> Result<FVector, String> {
+        let [x, y, z] = self.read_components(reader, address)?;
+        Ok(FVector { x, y, z })
+    }
+    /// Reads the `FRotator` at `address` with the precision of the game.
+    pub fn read_rotator(&self, reader: &impl MemoryReader, address: u64) -> Result<FRotator, String> {
+        let [pitch, yaw, roll] = self.read_components(reader, address)?;
+        Ok(FRotator { pitch, yaw, roll })
+    }
+    /// Reads the `FTransform` at `address` with the precision of the game. The translation and scale are read
+    /// at the offsets of `FTransform::Translation` and `FTransform::Scale3D` if the dump has them.
+    pub fn read_transform(&self, reader: &impl MemoryReader, address: u64) -> Result<FTransform, String> {
+        // The quaternion is aligned to 16 bytes, and so are the vectors after it.
+        let stride = if self.uses_double_precision() { 0x20 } else { 0x10 };
+        let member_offset = |member: &str, default: u64| {
+            self.get_member_offset("FTransform", member).map_or(default, |info| info.offset as u64)
+        };
+        let [x, y, z, w] = self.read_components(reader, address.wrapping_add(member_offset("Rotation", 0)))?;
+        Ok(FTransform {
+            rotation: FQuat { x, y, z, w },
+            translation: self.read_vector(reader, address.wrapping_add(member_offset("Translation", stride)))?,
+            scale: self.read_vector(reader, address.wrapping_add(member_offset("Scale3D", 2 * stride)))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::tests::Buffer;
+    use crate::{DsApiMock, Game};
+
+    #[test]
+    fn test_read_math() {
+        let ue5 = crate::tests::fixture_dsapi();
+        assert!(ue5.uses_double_precision());
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x100] };
+        memory.write(0x1000, [0.0_f64, 0.0, 0.0, 1.0]);
+        memory.write(0x1020, [1.5_f64, -2.0, 1e10]);
+        memory.write(0x1040, [1.0_f64, 1.0, 1.0]);
+        let transform = ue5.read_transform(&memory, 0x1000).unwrap();
+        assert_eq!(transform.rotation.w, 1.0);
+        assert_eq!(transform.translation, FVector { x: 1.5, y: -2.0, z: 1e10 });
+        assert_eq!(transform.scale.z, 1.0);
+
+        let ue4 = DsApiMock::new().with_game(Game::new("ue4", "UE4 Game", "Unreal-Engine-4", "UE4Game")).build();
+        assert!(!ue4.uses_double_precision());
+        memory.write(0x1080, [90.0_f32, 45.5, 0.0]);
+        assert_eq!(ue4.read_rotator(&memory, 0x1080).unwrap(), FRotator { pitch: 90.0, yaw: 45.5, roll: 0.0 });
+        memory.write(0x1090, [0.0_f32, 0.0, 0.0, 1.0, 3.0, 4.0, 5.0, 0.0, 2.0, 2.0, 2.0]);
+        let transform = ue4.read_transform(&memory, 0x1090).unwrap();
+        assert_eq!((transform.translation.y, transform.scale.x), (4.0, 2.0));
+        assert!(ue4.read_transform(&memory, u64::MAX - 8).is_err());
+    }
+}
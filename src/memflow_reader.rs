@@ -0,0 +1,59 @@
+//! [`MemoryReader`] over memflow, for DMA devices, virtual machines and anything else memflow has a connector for.
+
+use std::sync::Mutex;
+
+use memflow::prelude::v1::{Address, MemoryView, Process};
+
+use crate::MemoryReader;
+
+/// Reads memory through a memflow [`MemoryView`]: a process, a connector's physical memory or a virtual
+/// translation on top of it. Requires the `memflow` feature.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, MemflowReader, MemoryReader};
+/// use memflow::prelude::v1::*;
+/// # fn example(os: &mut OsInstanceArcBox<'static>) -> memflow::error::Result<()> {
+/// let process = os.process_by_name("FortniteClient-Win64-Shipping.exe")?;
+/// let reader = MemflowReader::new(process);
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// dsapi.set_module_base(reader.module_base("FortniteClient-Win64-Shipping.exe").unwrap());
+/// let world: u64 = reader.read_ptr(dsapi.get_offset_rebased("OFFSET_GWORLD").unwrap()).unwrap();
+/// let game_instance: u64 = dsapi.read_member(&reader, world, "UWorld", "OwningGameInstance").unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MemflowReader<V> {
+    // memflow reads through `&mut self`, `MemoryReader` through `&self`.
+    view: Mutex<V>,
+}
+
+impl<V: MemoryView> MemflowReader<V> {
+    pub fn new(view: V) -> Self {
+        MemflowReader { view: Mutex::new(view) }
+    }
+    /// Returns the wrapped memory view.
+    pub fn into_inner(self) -> V {
+        self.view.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<P: MemoryView + Process> MemflowReader<P> {
+    /// Returns the base address of a module of the process, for [`crate::DSAPI::set_module_base`].
+    /// The name is compared case-insensitively.
+    pub fn module_base(&self, module_name: &str) -> Result<u64, String> {
+        let module = self.view.lock().unwrap_or_else(|e| e.into_inner())
+            .module_by_name_ignore_ascii_case(module_name)
+            .map_err(|e| format!("Module {} not found: {}", module_name, e))?;
+        Ok(module.base.to_umem())
+    }
+}
+
+impl<V: MemoryView> MemoryReader for MemflowReader<V> {
+    fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String> {
+        self.view.lock().unwrap_or_else(|e| e.into_inner())
+            .read_raw_into(Address::from(address), buf)
+            .map_err(|e| format!("Failed to read 0x{:x}: {}", address, e))
+    }
+}
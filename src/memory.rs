@@ -0,0 +1,204 @@
+use crate::{DSAPI, OffsetInfo};
+
+/// Reads memory of a (usually foreign) process, e.g. through `ReadProcessMemory` or a driver.
+/// Combined with the dump offsets this allows reading live values, see [`DSAPI::read_member`].
+/// # Example:
+/// ```
+/// use dumpspace_api::MemoryReader;
+/// /// Reads from a local buffer that is mapped at `base`.
+/// struct Snapshot { base: u64, bytes: Vec<u8> }
+/// impl MemoryReader for Snapshot {
+///     fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String> {
+///         let start = address.checked_sub(self.base).ok_or("Address out of range")? as usize;
+///         let bytes = self.bytes.get(start..start + buf.len()).ok_or("Address out of range")?;
+///         buf.copy_from_slice(bytes);
+///         Ok(())
+///     }
+/// }
+/// let snapshot = Snapshot { base: 0x1000, bytes: 42u32.to_le_bytes().to_vec() };
+/// assert_eq!(snapshot.read_value::<u32>(0x1000), Ok(42));
+/// ```
+pub trait MemoryReader {
+    /// Fills `buf` with the memory at `address`. Fails if any of it can't be read.
+    fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String>;
+
+    /// Reads a plain value at `address`.
+    fn read_value<T: Pod>(&self, address: u64) -> Result<T, String> {
+        let mut value = std::mem::MaybeUninit::<T>::zeroed();
+        // SAFETY: the buffer covers exactly the value, which is zero-initialized.
+        let buf = unsafe { std::slice::from_raw_parts_mut(value.as_mut_ptr().cast::<u8>(), size_of::<T>()) };
+        self.read(address, buf)?;
+        // SAFETY: `Pod` types are valid for any bit pattern.
+        Ok(unsafe { value.assume_init() })
+    }
+
+    /// Reads a 64-bit pointer at `address`.
+    fn read_ptr(&self, address: u64) -> Result<u64, String> {
+        self.read_value(address)
+    }
+}
+
+impl<R: MemoryReader + ?Sized> MemoryReader for &R {
+    fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String> {
+        (**self).read(address, buf)
+    }
+}
+
+/// Types that can be read from raw memory because every bit pattern is a valid value.
+/// # Safety
+/// Implement this only for `Copy` types without padding, references, `bool`s or enums,
+/// e.g. `#[repr(C)]` structs made of integers and floats.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(unsafe impl Pod for $ty {})*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+impl OffsetInfo {
+    /// Reads this member from a snapshot of an object's bytes, e.g. one read with a single [`MemoryReader::read`].
+    /// Returns `None` if the member is a bitfield, is smaller than `T`, or lies outside of `buffer`.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::DSAPI;
+    /// # fn example(dsapi: &DSAPI, world_bytes: &[u8]) {
+    /// let info = dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap();
+    /// let game_instance: Option<u64> = info.read_from(world_bytes);
+    /// # }
+    /// ```
+    pub fn read_from<T: Pod>(&self, buffer: &[u8]) -> Option<T> {
+        if self.is_bit || (size_of::<T>() as i64) > self.size {
+            return None;
+        }
+        let start = usize::try_from(self.offset).ok()?;
+        let bytes = buffer.get(start..start.checked_add(size_of::<T>())?)?;
+        // SAFETY: `bytes` holds exactly one `T`, and `Pod` types are valid for any bit pattern.
+        Some(unsafe { bytes.as_ptr().cast::<T>().read_unaligned() })
+    }
+    /// Reads this member as a bool from a snapshot of an object's bytes, honouring the bit offset of bitfields.
+    /// Returns `None` if the member lies outside of `buffer` or its bit offset isn't within a byte.
+    pub fn read_bit_from(&self, buffer: &[u8]) -> Option<bool> {
+        let byte = *buffer.get(usize::try_from(self.offset).ok()?)?;
+        match self.is_bit {
+            true => Some(byte & self.bit_mask()? != 0),
+            false => Some(byte != 0),
+        }
+    }
+    /// The mask of the bitfield's bit in its byte, `None` for bit offsets outside of `0..8` from a malformed dump.
+    fn bit_mask(&self) -> Option<u8> {
+        1u8.checked_shl(u32::try_from(self.bit_offset).ok()?)
+    }
+}
+
+impl DSAPI {
+    /// Reads a member of the object at `object_address` using the dump's offset for it.
+    /// Fails if the member is unknown, is a bitfield, or is smaller than `T`.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::{DSAPI, MemoryReader};
+    /// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, world: u64) -> Result<(), String> {
+    /// let game_instance = dsapi.read_member::<u64>(reader, world, "UWorld", "OwningGameInstance")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_member<T: Pod>(&self, reader: &impl MemoryReader, object_address: u64, class_name: &str, member_name: &str) -> Result<T, String> {
+        let info = self.get_member_offset(class_name, member_name)
+            .ok_or_else(|| format!("Member not found: {}::{}", class_name, member_name))?;
+        if info.is_bit {
+            return Err(format!("{}::{} is a bitfield, use read_member_bit", class_name, member_name));
+        }
+        if (size_of::<T>() as i64) > info.size {
+            return Err(format!("{}::{} is {} bytes, can't read {} bytes", class_name, member_name, info.size, size_of::<T>()));
+        }
+        reader.read_value(object_address.wrapping_add(info.offset as u64))
+    }
+    /// Reads a bitfield member of the object at `object_address`.
+    pub fn read_member_bit(&self, reader: &impl MemoryReader, object_address: u64, class_name: &str, member_name: &str) -> Result<bool, String> {
+        let info = self.get_member_offset(class_name, member_name)
+            .ok_or_else(|| format!("Member not found: {}::{}", class_name, member_name))?;
+        let byte: u8 = reader.read_value(object_address.wrapping_add(info.offset as u64))?;
+        if info.is_bit {
+            let mask = info.bit_mask()
+                .ok_or_else(|| format!("{}::{} has an invalid bit offset: {}", class_name, member_name, info.bit_offset))?;
+            Ok(byte & mask != 0)
+        } else {
+            Ok(byte != 0)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A fake process whose memory is a byte buffer mapped at `base`.
+    pub(crate) struct Buffer {
+        pub(crate) base: u64,
+        pub(crate) bytes: Vec<u8>,
+    }
+
+    impl Buffer {
+        pub(crate) fn write<T: Pod>(&mut self, address: u64, value: T) {
+            let start = (address - self.base) as usize;
+            // SAFETY: `Pod` values have no padding.
+            let bytes = unsafe { std::slice::from_raw_parts((&value as *const T).cast::<u8>(), size_of::<T>()) };
+            self.bytes[start..start + bytes.len()].copy_from_slice(bytes);
+        }
+    }
+
+    impl MemoryReader for Buffer {
+        fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String> {
+            let start = address.checked_sub(self.base).ok_or("Address out of range")? as usize;
+            let bytes = self.bytes.get(start..start + buf.len()).ok_or("Address out of range")?;
+            buf.copy_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_member() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x1000] };
+        memory.write(0x1000 + 0x228, 0xdead_beef_u64);
+        memory.write(0x1000 + 0x230, 0b100_u8);
+
+        assert_eq!(dsapi.read_member::<u64>(&memory, 0x1000, "UWorld", "OwningGameInstance"), Ok(0xdead_beef));
+        assert!(dsapi.read_member_bit(&memory, 0x1000, "UWorld", "bIsWorldInitialized").unwrap());
+        assert!(dsapi.read_member::<u8>(&memory, 0x1000, "UWorld", "bIsWorldInitialized").is_err());
+        assert!(dsapi.read_member::<[u64; 2]>(&memory, 0x1000, "UWorld", "OwningGameInstance").is_err());
+        assert!(dsapi.read_member::<u64>(&memory, 0x1000, "UWorld", "Missing").is_err());
+        assert!(dsapi.read_member::<u64>(&memory, 0x10000, "UWorld", "OwningGameInstance").is_err());
+
+        let malformed = crate::DsApiMock::new()
+            .with_member_info("UWorld", "bHigh", OffsetInfo::member(0x230, 1).with_bit(8))
+            .with_member_info("UWorld", "bNegative", OffsetInfo::member(0x230, 1).with_bit(-1))
+            .build();
+        assert_eq!(malformed.read_member_bit(&memory, 0x1000, "UWorld", "bHigh").unwrap_err(), "UWorld::bHigh has an invalid bit offset: 8");
+        assert!(malformed.read_member_bit(&memory, 0x1000, "UWorld", "bNegative").is_err());
+    }
+
+    #[test]
+    fn test_read_from() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let mut memory = Buffer { base: 0, bytes: vec![0; 0x240] };
+        memory.write(0x227, 0xdead_beef_u64);
+        memory.write(0x230, 0b100_u8);
+        let member = |name| dsapi.get_member_offset("UWorld", name).unwrap();
+
+        let mut unaligned = member("OwningGameInstance");
+        unaligned.offset -= 1;
+        assert_eq!(unaligned.read_from::<u64>(&memory.bytes), Some(0xdead_beef));
+        assert_eq!(member("OwningGameInstance").read_from::<[u64; 2]>(&memory.bytes), None);
+        assert_eq!(member("OwningGameInstance").read_from::<u64>(&memory.bytes[..0x22f]), None);
+        assert_eq!(member("bIsWorldInitialized").read_from::<u8>(&memory.bytes), None);
+        assert_eq!(member("bIsWorldInitialized").read_bit_from(&memory.bytes), Some(true));
+        assert_eq!(member("bIsWorldInitialized").read_bit_from(&memory.bytes[..0x230]), None);
+        assert_eq!(OffsetInfo::member(0x230, 1).with_bit(8).read_bit_from(&memory.bytes), None);
+        assert_eq!(OffsetInfo::member(0x230, 1).with_bit(-1).read_bit_from(&memory.bytes), None);
+    }
+}
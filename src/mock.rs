@@ -0,0 +1,147 @@
+//! Hand-built dumps for the unit tests of code that takes a [`DSAPI`].
+
+use crate::{DSAPI, FunctionInfo, Game, OffsetInfo};
+
+/// Builds a real [`DSAPI`] from a few hand-written entries, without touching the network,
+/// so code that takes a `DSAPI` can be unit tested.
+/// # Example:
+/// ```
+/// use dumpspace_api::DsApiMock;
+/// let dsapi = DsApiMock::new()
+///     .with_member("UWorld", "OwningGameInstance", 0x228, 8)
+///     .with_class_size("UWorld", 0x9e8)
+///     .with_enum_value("EFortRarity", 4, "EFortRarity__Legendary")
+///     .with_offset("OFFSET_GWORLD", 0x14942840)
+///     .build();
+/// assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+/// assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DsApiMock {
+    game: Game,
+    members: Vec<(String, String, OffsetInfo)>,
+    class_sizes: Vec<(String, i32)>,
+    super_classes: Vec<(String, Vec<String>)>,
+    enum_values: Vec<(String, i64, String)>,
+    functions: Vec<(String, String, FunctionInfo)>,
+    offsets: Vec<(String, u64)>,
+}
+
+impl Default for DsApiMock {
+    fn default() -> Self {
+        DsApiMock {
+            game: Game::new("mock", "Mock", "Unreal-Engine-5", "Mock"),
+            members: Vec::new(),
+            class_sizes: Vec::new(),
+            super_classes: Vec::new(),
+            enum_values: Vec::new(),
+            functions: Vec::new(),
+            offsets: Vec::new(),
+        }
+    }
+}
+
+impl DsApiMock {
+    /// Starts an empty dump of a game with the ID `mock`.
+    pub fn new() -> Self {
+        DsApiMock::default()
+    }
+    /// Replaces the game the dump belongs to, e.g. for code that checks [`DSAPI::game`].
+    pub fn with_game(mut self, game: Game) -> Self {
+        self.game = game;
+        self
+    }
+    /// Adds a valid, byte-aligned class member.
+    pub fn with_member(self, class_name: &str, member_name: &str, offset: i64, size: i64) -> Self {
+        self.with_member_info(class_name, member_name, OffsetInfo::member(offset, size))
+    }
+    /// Adds a class member with full offset info, e.g. for bit fields.
+    pub fn with_member_info(mut self, class_name: &str, member_name: &str, info: OffsetInfo) -> Self {
+        self.members.push((class_name.to_string(), member_name.to_string(), info));
+        self
+    }
+    /// Sets the size of a class.
+    pub fn with_class_size(mut self, class_name: &str, size: i32) -> Self {
+        self.class_sizes.push((class_name.to_string(), size));
+        self
+    }
+    /// Sets the super classes of a class, starting with the direct parent.
+    pub fn with_super_classes(mut self, class_name: &str, super_classes: &[&str]) -> Self {
+        let super_classes = super_classes.iter().map(|super_class| super_class.to_string()).collect();
+        self.super_classes.push((class_name.to_string(), super_classes));
+        self
+    }
+    /// Adds a named enum value.
+    pub fn with_enum_value(mut self, enum_name: &str, enum_value: i64, value_name: &str) -> Self {
+        self.enum_values.push((enum_name.to_string(), enum_value, value_name.to_string()));
+        self
+    }
+    /// Adds a function of a class.
+    pub fn with_function(mut self, class_name: &str, function_name: &str, info: FunctionInfo) -> Self {
+        self.functions.push((class_name.to_string(), function_name.to_string(), info));
+        self
+    }
+    /// Adds a global offset.
+    pub fn with_offset(mut self, offset_name: &str, offset: u64) -> Self {
+        self.offsets.push((offset_name.to_string(), offset));
+        self
+    }
+    /// Builds the `DSAPI`. It has no blob metadata and can't be refreshed, everything else
+    /// (lookups, aliases, overlays, codegen, memory reads) works like on a downloaded dump.
+    pub fn build(self) -> DSAPI {
+        let mut dsapi = DSAPI::from_game(self.game);
+        for (class_name, member_name, info) in self.members {
+            let key = (dsapi.names.intern(&class_name), dsapi.names.intern(&member_name));
+            dsapi.class_member_map.insert(key, info);
+        }
+        for (class_name, size) in self.class_sizes {
+            let class = dsapi.names.intern(&class_name);
+            dsapi.class_size_map.insert(class, size);
+        }
+        for (class_name, super_classes) in self.super_classes {
+            let class = dsapi.names.intern(&class_name);
+            let supers = super_classes.iter().map(|super_class| dsapi.names.intern(super_class)).collect();
+            dsapi.class_super_map.insert(class, supers);
+        }
+        for (enum_name, enum_value, value_name) in self.enum_values {
+            let key = (dsapi.names.intern(&enum_name), enum_value);
+            let value_name = dsapi.names.intern(&value_name);
+            dsapi.enum_name_map.insert(key, value_name);
+        }
+        for (class_name, function_name, info) in self.functions {
+            let key = (dsapi.names.intern(&class_name), dsapi.names.intern(&function_name));
+            dsapi.function_map.insert(key, info);
+        }
+        dsapi.offset_map.extend(self.offsets);
+        dsapi.diagnose_key_collisions();
+        dsapi.apply_overlay();
+        dsapi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock() {
+        let dsapi = DsApiMock::new()
+            .with_member("UWorld", "OwningGameInstance", 0x228, 8)
+            .with_member_info("AActor", "bHidden", OffsetInfo::member(0x58, 1).with_bit(3))
+            .with_class_size("UWorld", 0x9e8)
+            .with_super_classes("UWorld", &["UObject"])
+            .with_enum_value("EFortRarity", 4, "EFortRarity__Legendary")
+            .with_function("AActor", "K2_GetActorLocation", FunctionInfo::new(0x1234))
+            .with_offset("OFFSET_GWORLD", 0x14942840)
+            .build();
+        assert_eq!(dsapi.game().hash, "mock");
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert!(dsapi.get_member_offset("AActor", "bHidden").unwrap().is_bit);
+        assert_eq!(dsapi.get_class_size("UWorld"), Some(0x9e8));
+        assert_eq!(dsapi.get_super_classes("UWorld"), Some(vec!["UObject".to_string()]));
+        assert_eq!(dsapi.get_enum_name_short("EFortRarity", 4), Some("Legendary".to_string()));
+        assert_eq!(dsapi.get_function_offset("AActor", "K2_GetActorLocation"), Some(0x1234));
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert_eq!(dsapi.get_offset("OFFSET_GOBJECTS"), None);
+    }
+}
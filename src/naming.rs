@@ -0,0 +1,81 @@
+//! Unreal Engine naming conventions, so consumers don't each reimplement the prefix heuristics.
+
+/// What an Unreal Engine type name denotes according to its prefix, see [`classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UeKind {
+    /// `A`: a class deriving from `AActor`, e.g. `AFortPlayerPawn`.
+    Actor,
+    /// `U`: any other `UObject` class, e.g. `UWorld`.
+    Object,
+    /// `F`: a struct, e.g. `FVector`.
+    Struct,
+    /// `E`: an enum, e.g. `EFortRarity`.
+    Enum,
+    /// `I`: an interface, e.g. `IFortInventoryOwnerInterface`.
+    Interface,
+    /// `Default__`: the class default object of a class, e.g. `Default__FortPlayerPawn`.
+    DefaultObject,
+    /// None of the conventions apply.
+    Unknown,
+}
+
+/// Classifies a type or object name by the Unreal Engine naming conventions. A prefix only counts when it is
+/// followed by another uppercase letter or digit, so `Engine` is not mistaken for an enum.
+/// Blueprint-generated classes (`_C`) are classified by their prefix too, see [`is_blueprint_generated`].
+/// # Example:
+/// ```
+/// use dumpspace_api::{UeKind, classify};
+/// assert_eq!(classify("AFortPlayerPawn"), UeKind::Actor);
+/// assert_eq!(classify("FVector"), UeKind::Struct);
+/// assert_eq!(classify("Default__FortPlayerPawnAthena"), UeKind::DefaultObject);
+/// assert_eq!(classify("Engine"), UeKind::Unknown);
+/// ```
+pub fn classify(name: &str) -> UeKind {
+    if name.starts_with("Default__") {
+        return UeKind::DefaultObject;
+    }
+    let mut chars = name.chars();
+    let (Some(prefix), Some(next)) = (chars.next(), chars.next()) else {
+        return UeKind::Unknown;
+    };
+    if !(next.is_ascii_uppercase() || next.is_ascii_digit()) {
+        return UeKind::Unknown;
+    }
+    match prefix {
+        'A' => UeKind::Actor,
+        'U' => UeKind::Object,
+        'F' => UeKind::Struct,
+        'E' => UeKind::Enum,
+        'I' => UeKind::Interface,
+        _ => UeKind::Unknown,
+    }
+}
+
+/// Returns `true` for classes generated from blueprints (`BP_PlayerPawn_C`, `ABP_Athena_C`), including their
+/// class default objects and the `SKEL_` classes the editor compiles them from.
+pub fn is_blueprint_generated(name: &str) -> bool {
+    let name = name.strip_prefix("Default__").unwrap_or(name);
+    name.len() > 2 && name.ends_with("_C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify("UWorld"), UeKind::Object);
+        assert_eq!(classify("EFortRarity"), UeKind::Enum);
+        assert_eq!(classify("IFortInventoryOwnerInterface"), UeKind::Interface);
+        assert_eq!(classify("ABP_PlayerPawn_Athena_C"), UeKind::Actor);
+        assert_eq!(classify("F2DVector"), UeKind::Struct);
+        assert_eq!(classify("A"), UeKind::Unknown);
+        assert_eq!(classify("Actor"), UeKind::Unknown);
+        assert_eq!(classify("TArray"), UeKind::Unknown);
+        assert!(is_blueprint_generated("BP_PlayerPawn_C"));
+        assert!(is_blueprint_generated("Default__BP_PlayerPawn_C"));
+        assert!(is_blueprint_generated("SKEL_BP_PlayerPawn_C"));
+        assert!(!is_blueprint_generated("_C"));
+        assert!(!is_blueprint_generated("AFortPlayerPawn"));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{DSAPI, FNamePool, MemoryReader};
+
+/// Classes deeper than this are assumed to be a corrupt `SuperStruct` chain.
+const MAX_RUNTIME_SUPERS: usize = 64;
+
+/// Adds `offset` to an address read from the target, failing instead of overflowing on garbage pointers.
+fn offset_address(address: u64, offset: u64) -> Result<u64, String> {
+    address.checked_add(offset).ok_or_else(|| format!("Address 0x{:x} + 0x{:x} is out of the address space", address, offset))
+}
+
+/// Where the parts of `GUObjectArray` (`OFFSET_GOBJECTS`) are. The defaults match UE4.20+ and UE5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectArrayLayout {
+    /// Offset of the chunked `TUObjectArray` in `FUObjectArray`.
+    pub objects_offset: u64,
+    /// Size of an `FUObjectItem`.
+    pub item_size: u64,
+    /// Number of `FUObjectItem`s per chunk.
+    pub elements_per_chunk: u64,
+}
+
+impl Default for ObjectArrayLayout {
+    fn default() -> Self {
+        ObjectArrayLayout { objects_offset: 0x10, item_size: 0x18, elements_per_chunk: 0x10000 }
+    }
+}
+
+/// A live `UObject`, yielded by [`DSAPI::iter_objects`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectInfo {
+    /// Index in `GUObjectArray`.
+    pub index: usize,
+    pub address: u64,
+    /// Comparison index of the object's `NamePrivate`, see [`crate::FNamePool::resolve`].
+    pub name_index: u32,
+    /// Address of the object's `UClass`.
+    pub class: u64,
+}
+
+/// Iterator over the objects in `GUObjectArray`, created with [`DSAPI::iter_objects`].
+/// Empty slots and objects that can't be read (e.g. because they were just freed) are skipped.
+pub struct ObjectIter<'a, R: MemoryReader> {
+    reader: &'a R,
+    layout: ObjectArrayLayout,
+    chunks: u64,
+    num_elements: usize,
+    index: usize,
+    /// The chunk index and address of the last chunk read.
+    chunk: Option<(u64, u64)>,
+    name_offset: u64,
+    class_offset: u64,
+}
+
+impl<R: MemoryReader> ObjectIter<'_, R> {
+    fn read_object(&mut self, index: usize) -> Result<Option<ObjectInfo>, String> {
+        let chunk_index = index as u64 / self.layout.elements_per_chunk;
+        let chunk = match self.chunk {
+            Some((cached, address)) if cached == chunk_index => address,
+            _ => {
+                let address = self.reader.read_ptr(offset_address(self.chunks, chunk_index * 8)?)?;
+                self.chunk = Some((chunk_index, address));
+                address
+            }
+        };
+        if chunk == 0 {
+            return Ok(None);
+        }
+        let item = offset_address(chunk, (index as u64 % self.layout.elements_per_chunk) * self.layout.item_size)?;
+        let address = self.reader.read_ptr(item)?;
+        if address == 0 {
+            return Ok(None);
+        }
+        Ok(Some(ObjectInfo {
+            index,
+            address,
+            name_index: self.reader.read_value(offset_address(address, self.name_offset)?)?,
+            class: self.reader.read_ptr(offset_address(address, self.class_offset)?)?,
+        }))
+    }
+}
+
+impl<R: MemoryReader> Iterator for ObjectIter<'_, R> {
+    type Item = ObjectInfo;
+
+    fn next(&mut self) -> Option<ObjectInfo> {
+        while self.index < self.num_elements {
+            let index = self.index;
+            self.index += 1;
+            if let Ok(Some(object)) = self.read_object(index) {
+                return Some(object);
+            }
+        }
+        None
+    }
+}
+
+impl DSAPI {
+    /// Iterates over all objects in `GUObjectArray`, found through the dump's `OFFSET_GOBJECTS`.
+    /// The name and class are read at the offsets of `UObject::NamePrivate` and `UObject::ClassPrivate`.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::{DSAPI, FNamePool, MemoryReader};
+    /// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, module_base: u64) -> Result<(), String> {
+    /// let names = FNamePool::from_dsapi(dsapi, module_base)?;
+    /// for object in dsapi.iter_objects(reader, module_base)? {
+    ///     println!("0x{:x} {}", object.address, names.resolve(reader, object.name_index)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_objects<'a, R: MemoryReader>(&self, reader: &'a R, module_base: u64) -> Result<ObjectIter<'a, R>, String> {
+        self.iter_objects_with_layout(reader, module_base, ObjectArrayLayout::default())
+    }
+    /// Like [`DSAPI::iter_objects`], for games with a customized `GUObjectArray`.
+    /// Fails if `layout` has a zero `item_size` or `elements_per_chunk`.
+    pub fn iter_objects_with_layout<'a, R: MemoryReader>(&self, reader: &'a R, module_base: u64, layout: ObjectArrayLayout) -> Result<ObjectIter<'a, R>, String> {
+        if layout.item_size == 0 || layout.elements_per_chunk == 0 {
+            return Err(format!("Invalid object array layout: {:?}", layout));
+        }
+        let gobjects = offset_address(module_base, self.get_offset("OFFSET_GOBJECTS").ok_or("OFFSET_GOBJECTS not found")?)?;
+        let member_offset = |member: &str| self.get_member_offset("UObject", member)
+            .map(|info| info.offset as u64)
+            .ok_or_else(|| format!("Member not found: UObject::{}", member));
+        let array = offset_address(gobjects, layout.objects_offset)?;
+        let num_elements: i32 = reader.read_value(offset_address(array, 0x14)?)?;
+        if num_elements < 0 {
+            return Err(format!("Invalid GUObjectArray at 0x{:x}: {} elements", gobjects, num_elements));
+        }
+        Ok(ObjectIter {
+            reader,
+            layout,
+            chunks: reader.read_ptr(array)?,
+            num_elements: num_elements as usize,
+            index: 0,
+            chunk: None,
+            name_offset: member_offset("NamePrivate")?,
+            class_offset: member_offset("ClassPrivate")?,
+        })
+    }
+    /// Reads the class of the object at `object_address` and returns it followed by its super classes, nearest first,
+    /// so code holding an `AActor*` can check whether it really is an `AFortPlayerPawn`.
+    /// The runtime name (`Actor`) is matched to the dump's prefixed name (`AActor`). Classes the dump doesn't know,
+    /// like most blueprint classes, are followed up their `UStruct::SuperStruct` until one the dump knows.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::{DSAPI, FNamePool, MemoryReader};
+    /// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, module_base: u64, actor: u64) -> Result<(), String> {
+    /// let names = FNamePool::from_dsapi(dsapi, module_base)?;
+    /// if dsapi.downcast_chain(reader, &names, actor)?.iter().any(|class| class == "AFortPlayerPawn") {
+    ///     let health: f32 = dsapi.read_member(reader, actor, "AFortPawn", "CurrentHealth")?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn downcast_chain(&self, reader: &impl MemoryReader, names: &FNamePool, object_address: u64) -> Result<Vec<String>, String> {
+        let member_offset = |class: &str, member: &str| self.get_member_offset(class, member)
+            .map(|info| info.offset as u64)
+            .ok_or_else(|| format!("Member not found: {}::{}", class, member));
+        let name_offset = member_offset("UObject", "NamePrivate")?;
+        let mut class = reader.read_ptr(offset_address(object_address, member_offset("UObject", "ClassPrivate")?)?)?;
+        let mut chain = Vec::new();
+        while class != 0 && chain.len() < MAX_RUNTIME_SUPERS {
+            let name = names.resolve_fname(reader, offset_address(class, name_offset)?)?;
+            let dump_name = ["A", "U", ""].iter()
+                .map(|prefix| format!("{}{}", prefix, name))
+                .find(|candidate| self.class_sym(candidate).is_some());
+            if let Some(dump_name) = dump_name {
+                let supers = self.get_super_classes(&dump_name).unwrap_or_default();
+                chain.push(dump_name);
+                chain.extend(supers);
+                return Ok(chain);
+            }
+            chain.push(name);
+            class = reader.read_ptr(offset_address(class, member_offset("UStruct", "SuperStruct")?)?)?;
+        }
+        Err(format!("No class of the object at 0x{:x} is in the dump", object_address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory::tests::Buffer;
+
+    #[test]
+    fn test_iter_objects() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let module_base = 0x10000;
+        let gobjects = module_base + dsapi.get_offset("OFFSET_GOBJECTS").unwrap();
+        let mut memory = Buffer { base: gobjects, bytes: vec![0; 0x1000] };
+        memory.write(gobjects + 0x10, gobjects + 0x100);
+        memory.write(gobjects + 0x10 + 0x14, 3_i32);
+        memory.write(gobjects + 0x100, gobjects + 0x200);
+        memory.write(gobjects + 0x200, gobjects + 0x400);
+        memory.write(gobjects + 0x200 + 0x30, gobjects + 0x480);
+        memory.write(gobjects + 0x400 + 0x10, 0xc1a55_u64);
+        memory.write(gobjects + 0x400 + 0x18, 7_u32);
+        memory.write(gobjects + 0x480 + 0x18, 9_u32);
+        // Garbage pointers are skipped like any other unreadable object.
+        memory.write(gobjects + 0x200 + 0x18, u64::MAX - 8);
+
+        let objects: Vec<_> = dsapi.iter_objects(&memory, module_base).unwrap().collect();
+        assert_eq!(objects.len(), 2);
+        assert_eq!((objects[0].index, objects[0].address, objects[0].name_index, objects[0].class), (0, gobjects + 0x400, 7, 0xc1a55));
+        assert_eq!((objects[1].index, objects[1].name_index), (2, 9));
+
+        let layout = crate::ObjectArrayLayout { elements_per_chunk: 0, ..Default::default() };
+        assert!(dsapi.iter_objects_with_layout(&memory, module_base, layout).err().unwrap().starts_with("Invalid object array layout"));
+        let layout = crate::ObjectArrayLayout { item_size: 0, ..Default::default() };
+        assert!(dsapi.iter_objects_with_layout(&memory, module_base, layout).is_err());
+        assert!(dsapi.iter_objects(&memory, u64::MAX).err().unwrap().ends_with("is out of the address space"));
+        let layout = crate::ObjectArrayLayout { objects_offset: u64::MAX, ..Default::default() };
+        assert!(dsapi.iter_objects_with_layout(&memory, module_base, layout).err().unwrap().ends_with("is out of the address space"));
+    }
+
+    #[test]
+    fn test_downcast_chain() {
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x2000] };
+        let (names, _, actor) = crate::fname::tests::name_pool(&mut memory);
+        memory.write(0x2010, 8_u16 << 6);
+        memory.bytes[0x1012..0x101a].copy_from_slice(b"MyPawn_C");
+        let dsapi = crate::DsApiMock::new()
+            .with_member("UObject", "ClassPrivate", 0x10, 8)
+            .with_member("UObject", "NamePrivate", 0x18, 8)
+            .with_member("UStruct", "SuperStruct", 0x40, 8)
+            .with_super_classes("AActor", &["UObject"])
+            .build();
+        // A blueprint class the dump doesn't know, deriving from AActor.
+        memory.write(0x2800 + 0x10, 0x2900_u64);
+        memory.write(0x2900 + 0x18, 8_u32);
+        memory.write(0x2900 + 0x40, 0x2a00_u64);
+        memory.write(0x2a00 + 0x18, actor);
+        assert_eq!(dsapi.downcast_chain(&memory, &names, 0x2800).unwrap(), ["MyPawn_C", "AActor", "UObject"]);
+        memory.write(0x2800 + 0x10, 0x2a00_u64);
+        assert_eq!(dsapi.downcast_chain(&memory, &names, 0x2800).unwrap(), ["AActor", "UObject"]);
+        memory.write(0x2900 + 0x40, 0_u64);
+        memory.write(0x2800 + 0x10, 0x2900_u64);
+        assert!(dsapi.downcast_chain(&memory, &names, 0x2800).is_err());
+        // A corrupt SuperStruct chain fails instead of overflowing.
+        memory.write(0x2900 + 0x40, u64::MAX - 8);
+        assert!(dsapi.downcast_chain(&memory, &names, 0x2800).unwrap_err().contains("out of the address space"));
+    }
+}
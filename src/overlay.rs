@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{DSAPI, OffsetInfo};
+
+/// Hand-made offsets that take precedence over the dumpspace data, see [`DSAPI::overlay`].
+///
+/// Can be built in code or loaded from a JSON file like
+/// `{"members": {"UWorld": {"OwningGameInstance": {"offset": 560, "size": 8, "is_bit": false, "bit_offset": 0, "valid": true}}}, "offsets": {"OFFSET_GWORLD": 345253952}}`.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, DumpOverlay, OffsetInfo};
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// dsapi.overlay(DumpOverlay::new()
+///     .member("UWorld", "OwningGameInstance", OffsetInfo::member(0x230, 8))
+///     .offset("OFFSET_GWORLD", 0x14942900));
+/// assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942900));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DumpOverlay {
+    members: HashMap<String, HashMap<String, OffsetInfo>>,
+    class_sizes: HashMap<String, i32>,
+    enums: HashMap<String, HashMap<i64, String>>,
+    offsets: HashMap<String, u64>,
+}
+
+impl DumpOverlay {
+    pub fn new() -> Self {
+        DumpOverlay::default()
+    }
+    /// Loads an overlay from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let serialized = std::fs::read_to_string(path).map_err(|e| format!("Failed to read overlay: {}", e))?;
+        serde_json::from_str(&serialized).map_err(|e| format!("Failed to parse overlay: {}", e))
+    }
+    /// Writes the overlay to a JSON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let serialized = serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize overlay: {}", e))?;
+        std::fs::write(path, serialized).map_err(|e| format!("Failed to write overlay: {}", e))
+    }
+    /// Overrides the offset info of a class member.
+    pub fn member(mut self, class_name: &str, member_name: &str, info: OffsetInfo) -> Self {
+        self.members.entry(class_name.to_string()).or_default().insert(member_name.to_string(), info);
+        self
+    }
+    /// Overrides the size of a class.
+    pub fn class_size(mut self, class_name: &str, size: i32) -> Self {
+        self.class_sizes.insert(class_name.to_string(), size);
+        self
+    }
+    /// Overrides the name of an enum value.
+    pub fn enum_name(mut self, enum_name: &str, enum_value: i64, value_name: &str) -> Self {
+        self.enums.entry(enum_name.to_string()).or_default().insert(enum_value, value_name.to_string());
+        self
+    }
+    /// Overrides a global offset.
+    pub fn offset(mut self, offset_name: &str, offset: u64) -> Self {
+        self.offsets.insert(offset_name.to_string(), offset);
+        self
+    }
+    /// Returns `true` if the overlay does not override anything.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty() && self.class_sizes.is_empty() && self.enums.is_empty() && self.offsets.is_empty()
+    }
+    /// Returns `true` if the overlay overrides data parsed from `blob`.
+    #[cfg(network)]
+    pub(crate) fn overrides(&self, blob: crate::BlobKind) -> bool {
+        use crate::BlobKind;
+        match blob {
+            BlobKind::Classes | BlobKind::Structs => !self.members.is_empty() || !self.class_sizes.is_empty(),
+            BlobKind::Enums => !self.enums.is_empty(),
+            BlobKind::Functions => false,
+            BlobKind::Offsets => !self.offsets.is_empty(),
+        }
+    }
+    /// Adds the entries of `other`, replacing entries that exist in both.
+    fn merge(&mut self, other: DumpOverlay) {
+        for (class_name, members) in other.members {
+            self.members.entry(class_name).or_default().extend(members);
+        }
+        self.class_sizes.extend(other.class_sizes);
+        for (enum_name, values) in other.enums {
+            self.enums.entry(enum_name).or_default().extend(values);
+        }
+        self.offsets.extend(other.offsets);
+    }
+}
+
+impl DSAPI {
+    /// Layers hand-made offsets on top of the dumpspace data, e.g. to patch a few offsets after a hotfix
+    /// before dumpspace is updated. Lookups return the overlay's entries first.
+    /// The overlay is kept and applied again after `download_content` and `refresh`.
+    /// Calling this again adds to the existing overlay.
+    pub fn overlay(&mut self, overlay: DumpOverlay) {
+        self.overlay.merge(overlay);
+        self.apply_overlay();
+    }
+    /// Returns the overlay set with [`DSAPI::overlay`].
+    pub fn overlay_entries(&self) -> &DumpOverlay {
+        &self.overlay
+    }
+    /// Writes the overlay's entries into the lookup maps.
+    pub(crate) fn apply_overlay(&mut self) {
+        for (class_name, members) in &self.overlay.members {
+            let class = self.names.intern(class_name);
+            for (member_name, info) in members {
+                let member = self.names.intern(member_name);
+                self.class_member_map.insert((class, member), info.clone());
+            }
+        }
+        for (class_name, size) in &self.overlay.class_sizes {
+            let class = self.names.intern(class_name);
+            self.class_size_map.insert(class, *size);
+        }
+        for (enum_name, values) in &self.overlay.enums {
+            let enum_name = self.names.intern(enum_name);
+            for (value, value_name) in values {
+                let value_name = self.names.intern(value_name);
+                self.enum_name_map.insert((enum_name, *value), value_name);
+            }
+        }
+        for (offset_name, offset) in &self.overlay.offsets {
+            self.offset_map.insert(offset_name.clone(), *offset);
+        }
+        // Every way of loading a dump ends here, so the folded names and key hashes always cover the newest data.
+        self.index_folded_names();
+        self.member_hashes = Default::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_overlay() {
+        let mut dsapi = fixture_dsapi();
+        let info = OffsetInfo { offset: 0x230, size: 8, valid: true, ..Default::default() };
+        dsapi.overlay(DumpOverlay::new()
+            .member("UWorld", "OwningGameInstance", info)
+            .member("UWorld", "MyReversedMember", OffsetInfo { offset: 0x400, ..Default::default() })
+            .offset("OFFSET_GWORLD", 0x14942900));
+        dsapi.overlay(DumpOverlay::new().class_size("UWorld", 2544).enum_name("EFortRarity", 5, "EFortRarity__Mythic"));
+
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x230);
+        assert_eq!(dsapi.get_member_offset("UWorld", "MyReversedMember").unwrap().offset, 0x400);
+        assert_eq!(dsapi.get_member_offset("UWorld", "PersistentLevel").unwrap().offset, 48);
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942900));
+        assert_eq!(dsapi.get_class_size("UWorld"), Some(2544));
+        assert_eq!(dsapi.get_enum_name("EFortRarity", 5), Some("EFortRarity__Mythic".to_string()));
+        assert_eq!(dsapi.overlay_entries().offsets.len(), 1);
+
+        let restored: DumpOverlay = serde_json::from_str(&serde_json::to_string(dsapi.overlay_entries()).unwrap()).unwrap();
+        assert_eq!(restored.enums["EFortRarity"][&5], "EFortRarity__Mythic");
+        assert!(DumpOverlay::new().is_empty());
+    }
+}
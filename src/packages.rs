@@ -0,0 +1,149 @@
+//! Classes with the same short name in different packages, for dumps that record each class's package.
+
+use crate::intern::Sym;
+use crate::{BlobKind, DSAPI, Diagnostic};
+
+impl DSAPI {
+    /// The key a parsed class is stored under: its short name, or `Package.Class` once another package
+    /// declares a class with the same short name. The class parsed first is then moved to its qualified name too,
+    /// so the short name stops resolving instead of silently picking one of them.
+    pub(crate) fn class_key(&mut self, blob: BlobKind, class_name: &str, package: Option<&str>) -> Sym {
+        let short = self.names.intern(class_name);
+        let Some(package) = package else {
+            return short;
+        };
+        let package = self.names.intern(package);
+        if self.ambiguous_class_map.contains_key(&short) {
+            let key = self.qualify(package, class_name);
+            let qualified = self.ambiguous_class_map.entry(short).or_default();
+            if !qualified.contains(&key) {
+                qualified.push(key);
+            }
+            self.class_package_map.insert(key, package);
+            return key;
+        }
+        match self.class_package_map.get(&short).copied() {
+            Some(other) if other != package => {
+                let moved = self.qualify(other, class_name);
+                self.rekey_class(short, moved);
+                let key = self.qualify(package, class_name);
+                self.class_package_map.insert(key, package);
+                self.ambiguous_class_map.insert(short, vec![moved, key]);
+                self.diagnostics.push(Diagnostic::AmbiguousClass {
+                    blob,
+                    class: class_name.to_string(),
+                    packages: (self.names.resolve(other).to_string(), self.names.resolve(package).to_string()),
+                });
+                key
+            }
+            _ => {
+                self.class_package_map.insert(short, package);
+                short
+            }
+        }
+    }
+    fn qualify(&mut self, package: Sym, class_name: &str) -> Sym {
+        let qualified = format!("{}.{}", self.names.resolve(package), class_name);
+        self.names.intern(&qualified)
+    }
+    /// Moves everything parsed for a class from one key to another.
+    fn rekey_class(&mut self, from: Sym, to: Sym) {
+        fn rekey_members<V>(map: &mut rustc_hash::FxHashMap<(Sym, Sym), V>, from: Sym, to: Sym) {
+            let members: Vec<Sym> = map.keys().filter(|(class, _)| *class == from).map(|(_, member)| *member).collect();
+            for member in members {
+                if let Some(value) = map.remove(&(from, member)) {
+                    map.insert((to, member), value);
+                }
+            }
+        }
+        rekey_members(&mut self.class_member_map, from, to);
+        rekey_members(&mut self.member_type_map, from, to);
+        if let Some(size) = self.class_size_map.remove(&from) {
+            self.class_size_map.insert(to, size);
+        }
+        if let Some(supers) = self.class_super_map.remove(&from) {
+            self.class_super_map.insert(to, supers);
+        }
+        if let Some(package) = self.class_package_map.remove(&from) {
+            self.class_package_map.insert(to, package);
+        }
+    }
+    /// The symbol of a class name as used by lookups: a short name, or a `Package.Class` name whose class
+    /// is stored under its short name because no other package declares it.
+    pub(crate) fn class_sym(&self, class_name: &str) -> Option<Sym> {
+        let exact = self.lookup_sym(class_name);
+        let Some((package, short)) = class_name.rsplit_once('.') else {
+            return exact;
+        };
+        if self.class_package_map.is_empty() || exact.is_some_and(|sym| self.class_package_map.contains_key(&sym)) {
+            return exact;
+        }
+        self.short_class_sym(package, short).or(exact)
+    }
+    /// The short name of `short` if it is stored under it and belongs to `package`.
+    fn short_class_sym(&self, package: &str, short: &str) -> Option<Sym> {
+        let sym = self.lookup_sym(short)?;
+        (self.class_package_map.get(&sym) == self.lookup_sym(package).as_ref()).then_some(sym)
+    }
+    /// Returns the package a class or struct was declared in, e.g. `Engine` for `UWorld`, if the dump records packages.
+    /// Accepts short and `Package.Class` names like the other lookups.
+    pub fn get_class_package(&self, class_name: &str) -> Option<String> {
+        let package = self.class_package_map.get(&self.class_sym(class_name)?)?;
+        Some(self.names.resolve(*package).to_string())
+    }
+    /// Returns the `Package.Class` names to look up a short class name that several packages declare,
+    /// e.g. `["Engine.World", "MyGame.World"]` for `World`. Empty if the short name is unambiguous or unknown.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::{BlobKind, DSAPI, Game};
+    /// let mut dsapi = DSAPI::from_game(Game::default());
+    /// dsapi.load_blob(BlobKind::Classes, r#"{"data":[
+    ///     {"UWorld":[{"__Package":"Engine"},{"__MDKClassSize":2536},{"OwningGameInstance":[["UGameInstance","C","*",[]],552,8,1]}]},
+    ///     {"UWidget":[{"__Package":"UMG"},{"__MDKClassSize":264},{"Slot":[["UPanelSlot","C","*",[]],40,8,1]}]},
+    ///     {"UWidget":[{"__Package":"MyGame"},{"__MDKClassSize":48},{"Owner":[["UObject","C","*",[]],40,8,1]}]}
+    /// ],"updated_at":"0","version":10202}"#).unwrap();
+    /// // Unambiguous short names keep working, and qualified names work for every class.
+    /// assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance"), dsapi.get_member_offset("Engine.UWorld", "OwningGameInstance"));
+    /// assert_eq!(dsapi.get_class_size("UWidget"), None);
+    /// assert_eq!(dsapi.get_class_size("MyGame.UWidget"), Some(48));
+    /// assert_eq!(dsapi.get_qualified_class_names("UWidget"), ["MyGame.UWidget", "UMG.UWidget"]);
+    /// ```
+    pub fn get_qualified_class_names(&self, class_name: &str) -> Vec<String> {
+        let Some(qualified) = self.names.get(class_name).and_then(|short| self.ambiguous_class_map.get(&short)) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = qualified.iter().map(|sym| self.names.resolve(*sym).to_string()).collect();
+        names.sort();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlobKind, DSAPI, Diagnostic, Game};
+
+    #[test]
+    fn test_packages() {
+        let mut dsapi = DSAPI::from_game(Game::default());
+        let blob = r#"{"data":[
+            {"UWidget":[{"__Package":"UMG"},{"__InheritInfo":["UVisual"]},{"__MDKClassSize":264},{"Slot":[["UPanelSlot","C","*",[]],40,8,1]}]},
+            {"UWidget":[{"__Package":"MyGame"},{"__MDKClassSize":48},{"Owner":[["UObject","C","*",[]],40,8,1]}]},
+            {"UWidget":[{"__Package":"Other"},{"__MDKClassSize":16}]},
+            {"UWorld":[{"__Package":"Engine"},{"__MDKClassSize":2536}]}
+        ],"updated_at":"0","version":10202}"#;
+        dsapi.load_blob(BlobKind::Classes, blob).unwrap();
+
+        // The class parsed first moved to its qualified name with everything parsed for it.
+        assert_eq!(dsapi.get_member_offset("UMG.UWidget", "Slot").unwrap().offset, 40);
+        assert_eq!(dsapi.get_super_classes("UMG.UWidget"), Some(vec!["UVisual".to_string()]));
+        assert_eq!(dsapi.get_member_offset("UWidget", "Slot"), None);
+        assert_eq!(dsapi.get_class_size("Other.UWidget"), Some(16));
+        assert_eq!(dsapi.get_qualified_class_names("UWidget"), ["MyGame.UWidget", "Other.UWidget", "UMG.UWidget"]);
+        assert_eq!(dsapi.get_class_package("MyGame.UWidget").as_deref(), Some("MyGame"));
+        assert_eq!(dsapi.get_class_package("UWorld").as_deref(), Some("Engine"));
+        assert_eq!(dsapi.get_class_size("Engine.UWorld"), Some(2536));
+        assert_eq!(dsapi.get_class_size("CoreUObject.UWorld"), None);
+        assert!(dsapi.get_qualified_class_names("UWorld").is_empty());
+        assert_eq!(dsapi.diagnostics().iter().filter(|diagnostic| matches!(diagnostic, Diagnostic::AmbiguousClass { .. })).count(), 1);
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{BlobKind, MemberType, OffsetInfo};
+
+/// Converts the member arrays of a Classes/Structs blob into offsets.
+///
+/// The layout of a member array changed between dumpspace schema versions (e.g. where the bit offset
+/// of a bitfield is stored), so every blob `version` has its own parser. 10201 and 10202 are built in,
+/// unknown versions fall back to [`FallbackParser`] instead of failing.
+/// Implement this trait to support a new or private schema version.
+/// # Example:
+/// ```
+/// use dumpspace_api::{BlobParser, OffsetInfo, RawMember};
+/// struct MyParser;
+/// impl BlobParser for MyParser {
+///     fn parse_member(&self, name: &str, member: &RawMember) -> Result<(String, OffsetInfo), String> {
+///         let info = match member.extra.get(2) {
+///             Some(bit_offset) => OffsetInfo::member(member.offset, member.size).with_bit(*bit_offset as i32),
+///             None => OffsetInfo::member(member.offset, member.size),
+///         };
+///         Ok((name.to_string(), info))
+///     }
+/// }
+/// ```
+pub trait BlobParser: Send + Sync {
+    /// Converts one member, stored under `name` in the blob, into its member name and offset info.
+    fn parse_member(&self, name: &str, member: &RawMember) -> Result<(String, OffsetInfo), String>;
+}
+
+/// A member array as stored in the blobs: `[type, offset, size, ...]`.
+/// The trailing integers differ between blob versions (array dim, bit offset).
+#[derive(Debug, Clone)]
+pub struct RawMember {
+    pub offset: i64,
+    pub size: i64,
+    /// Everything after the size.
+    pub extra: Vec<i64>,
+}
+
+impl RawMember {
+    /// Length of the original JSON array, including the type info.
+    pub fn array_len(&self) -> usize {
+        3 + self.extra.len()
+    }
+    /// The member array as JSON, with its type info left out.
+    pub(crate) fn snippet(&self) -> String {
+        let values: Vec<String> = [self.offset, self.size].iter().chain(&self.extra).map(i64::to_string).collect();
+        format!("[..., {}]", values.join(", "))
+    }
+}
+
+fn member_info(member: &RawMember, bit_offset: Option<i64>) -> OffsetInfo {
+    OffsetInfo {
+        offset: member.offset,
+        size: member.size,
+        is_bit: bit_offset.is_some(),
+        bit_offset: bit_offset.unwrap_or(0) as i32,
+        valid: true,
+    }
+}
+
+/// Version 10201: bitfields have 4 entries with the bit offset last, and their name ends with `" : 1"`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct V10201Parser;
+
+impl BlobParser for V10201Parser {
+    fn parse_member(&self, name: &str, member: &RawMember) -> Result<(String, OffsetInfo), String> {
+        if member.array_len() == 4 {
+            let name = name.rsplit_once(" : ").map_or(name, |(name, _)| name);
+            Ok((name.to_string(), member_info(member, Some(member.extra[0]))))
+        } else {
+            Ok((name.to_string(), member_info(member, None)))
+        }
+    }
+}
+
+/// Version 10202: bitfields have 5 entries with the bit offset in the 5th.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct V10202Parser;
+
+impl BlobParser for V10202Parser {
+    fn parse_member(&self, name: &str, member: &RawMember) -> Result<(String, OffsetInfo), String> {
+        let bit_offset = (member.array_len() == 5).then(|| member.extra[1]);
+        Ok((name.to_string(), member_info(member, bit_offset)))
+    }
+}
+
+/// Best-effort parser for unknown versions: recognizes bitfields by either known layout
+/// and otherwise only keeps offset and size.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FallbackParser;
+
+impl BlobParser for FallbackParser {
+    fn parse_member(&self, name: &str, member: &RawMember) -> Result<(String, OffsetInfo), String> {
+        if member.array_len() >= 5 {
+            V10202Parser.parse_member(name, member)
+        } else if member.array_len() == 4 && name.contains(" : ") {
+            V10201Parser.parse_member(name, member)
+        } else {
+            Ok((name.to_string(), member_info(member, None)))
+        }
+    }
+}
+
+/// Returns the built-in parser for a blob version, or the [`FallbackParser`].
+pub(crate) fn builtin_parser(version: u64) -> &'static dyn BlobParser {
+    match version {
+        10201 => &V10201Parser,
+        10202 => &V10202Parser,
+        _ => &FallbackParser,
+    }
+}
+
+/// Returns `true` for the blob versions with a built-in parser.
+pub(crate) fn is_known_version(version: u64) -> bool {
+    matches!(version, 10201 | 10202)
+}
+
+/// The parsers registered with [`crate::DSAPI::register_parser`], checked before the built-in ones.
+#[derive(Clone, Default)]
+pub(crate) struct ParserRegistry {
+    custom: HashMap<u64, Arc<dyn BlobParser>>,
+}
+
+impl ParserRegistry {
+    pub(crate) fn register(&mut self, version: u64, parser: Box<dyn BlobParser>) {
+        self.custom.insert(version, Arc::from(parser));
+    }
+    pub(crate) fn is_registered(&self, version: u64) -> bool {
+        self.custom.contains_key(&version)
+    }
+    pub(crate) fn get(&self, version: u64) -> &dyn BlobParser {
+        match self.custom.get(&version) {
+            Some(parser) => &**parser,
+            None => builtin_parser(version),
+        }
+    }
+}
+
+impl std::fmt::Debug for ParserRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.custom.keys()).finish()
+    }
+}
+
+/// How `download_content` handles class or struct members it can't parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Skips malformed members and records them as [`ParseWarning`]s, see [`crate::DSAPI::parse_warnings`].
+    #[default]
+    Lenient,
+    /// Fails on the first malformed member.
+    Strict,
+}
+
+/// A blob entry that was skipped (or, for unknown versions, parsed on a best-effort basis) while parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseWarning {
+    pub blob: BlobKind,
+    pub class: Option<String>,
+    pub member: Option<String>,
+    pub message: String,
+    /// The offending member array, with its type info left out.
+    #[serde(default)]
+    pub snippet: Option<String>,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.blob.file_name())?;
+        match (&self.class, &self.member) {
+            (Some(class), Some(member)) => write!(f, " {}::{}", class, member)?,
+            (Some(class), None) => write!(f, " {}", class)?,
+            _ => {}
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(snippet) = &self.snippet {
+            write!(f, " (in {})", snippet)?;
+        }
+        Ok(())
+    }
+}
+
+/// A member array that may be malformed, so a single bad member doesn't fail the whole blob.
+/// Malformed members keep the error message and a snippet of the array.
+/// The member's type is kept separately, it is `None` if it is malformed.
+#[derive(Debug)]
+pub(crate) struct MemberEntry(pub(crate) Result<RawMember, (String, String)>, pub(crate) Option<MemberType>);
+
+/// Any JSON value, keeping it only if it is an integer. Other values are kept as a short JSON snippet.
+struct Integer(Result<i64, String>);
+
+fn not_an_array(kind: &str, snippet: &str) -> MemberEntry {
+    MemberEntry(Err((format!("member is {}, expected an array", kind), snippet.to_string())), None)
+}
+
+impl<'de> serde::Deserialize<'de> for MemberEntry {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntryVisitor;
+        impl<'de> serde::de::Visitor<'de> for EntryVisitor {
+            type Value = MemberEntry;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a member array [type, offset, size, ...]")
+            }
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<MemberEntry, A::Error> {
+                let Some(ty) = seq.next_element::<serde_json::Value>()? else {
+                    return Ok(MemberEntry(Err(("member array is empty".to_string(), "[]".to_string())), None));
+                };
+                let ty = MemberType::from_json(&ty);
+                let mut values = Vec::new();
+                while let Some(Integer(value)) = seq.next_element()? {
+                    values.push(value);
+                }
+                let integers: Vec<i64> = values.iter().filter_map(|value| value.as_ref().ok().copied()).collect();
+                let error = match integers.as_slice() {
+                    _ if values.len() < 2 => format!("member array has {} elements, expected at least 3", values.len() + 1),
+                    [offset, size, extra @ ..] if integers.len() == values.len() => {
+                        return Ok(MemberEntry(Ok(RawMember { offset: *offset, size: *size, extra: extra.to_vec() }), ty));
+                    }
+                    _ if values[0].is_err() || values[1].is_err() => "offset or size is not an integer".to_string(),
+                    _ => "member array contains a value that is not an integer".to_string(),
+                };
+                let snippet: Vec<String> = values.into_iter()
+                    .map(|value| value.map_or_else(|text| text, |value| value.to_string()))
+                    .collect();
+                Ok(MemberEntry(Err((error, format!("[..., {}]", snippet.join(", ")))), ty))
+            }
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<MemberEntry, A::Error> {
+                while map.next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?.is_some() {}
+                Ok(not_an_array("an object", "{...}"))
+            }
+            fn visit_bool<E: serde::de::Error>(self, value: bool) -> Result<MemberEntry, E> {
+                Ok(not_an_array("a bool", &value.to_string()))
+            }
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<MemberEntry, E> {
+                Ok(not_an_array("a number", &value.to_string()))
+            }
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<MemberEntry, E> {
+                Ok(not_an_array("a number", &value.to_string()))
+            }
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<MemberEntry, E> {
+                Ok(not_an_array("a number", &value.to_string()))
+            }
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<MemberEntry, E> {
+                Ok(not_an_array("a string", &format!("{:?}", value)))
+            }
+            fn visit_unit<E: serde::de::Error>(self) -> Result<MemberEntry, E> {
+                Ok(not_an_array("null", "null"))
+            }
+        }
+        deserializer.deserialize_any(EntryVisitor)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Integer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IntegerVisitor;
+        impl<'de> serde::de::Visitor<'de> for IntegerVisitor {
+            type Value = Integer;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("any value")
+            }
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Integer, E> {
+                Ok(Integer(Ok(value)))
+            }
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Integer, E> {
+                Ok(Integer(i64::try_from(value).map_err(|_| value.to_string())))
+            }
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Integer, E> {
+                Ok(Integer(Err(value.to_string())))
+            }
+            fn visit_bool<E: serde::de::Error>(self, value: bool) -> Result<Integer, E> {
+                Ok(Integer(Err(value.to_string())))
+            }
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Integer, E> {
+                Ok(Integer(Err(format!("{:?}", value))))
+            }
+            fn visit_unit<E: serde::de::Error>(self) -> Result<Integer, E> {
+                Ok(Integer(Err("null".to_string())))
+            }
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Integer, A::Error> {
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(Integer(Err("[...]".to_string())))
+            }
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Integer, A::Error> {
+                while map.next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?.is_some() {}
+                Ok(Integer(Err("{...}".to_string())))
+            }
+        }
+        deserializer.deserialize_any(IntegerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallback_parser() {
+        let bitfield = RawMember { offset: 8, size: 1, extra: vec![1, 3] };
+        let (name, info) = builtin_parser(10300).parse_member("bFlag", &bitfield).unwrap();
+        assert_eq!((name.as_str(), info.is_bit, info.bit_offset), ("bFlag", true, 3));
+
+        let old_bitfield = RawMember { offset: 8, size: 1, extra: vec![4] };
+        let (name, info) = builtin_parser(10300).parse_member("bFlag : 1", &old_bitfield).unwrap();
+        assert_eq!((name.as_str(), info.is_bit, info.bit_offset), ("bFlag", true, 4));
+
+        let array = RawMember { offset: 16, size: 12, extra: vec![3] };
+        let (name, info) = builtin_parser(10300).parse_member("Values", &array).unwrap();
+        assert_eq!((name.as_str(), info.offset, info.is_bit), ("Values", 16, false));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{DSAPI, MemoryReader, read_tarray};
+
+/// One `->` separated step of a pointer path.
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    /// A global offset from OffsetsInfo, e.g. `OFFSET_GWORLD`.
+    Global(&'a str),
+    /// A pointer member, e.g. `UWorld.OwningGameInstance`, or an element of a `TArray` of pointers,
+    /// e.g. `UGameInstance.LocalPlayers[0]`.
+    Member { class: &'a str, member: &'a str, index: Option<usize> },
+}
+
+fn parse_segment(segment: &str) -> Result<Segment<'_>, String> {
+    let segment = segment.trim();
+    let Some((class, member)) = segment.split_once('.') else {
+        return match segment.is_empty() {
+            true => Err("Empty path segment".to_string()),
+            false => Ok(Segment::Global(segment)),
+        };
+    };
+    let (member, index) = match member.strip_suffix(']').and_then(|member| member.split_once('[')) {
+        Some((member, index)) => {
+            let index = index.trim().parse().map_err(|e| format!("Invalid index in {}: {}", segment, e))?;
+            (member, Some(index))
+        }
+        None => (member, None),
+    };
+    Ok(Segment::Member { class: class.trim(), member: member.trim(), index })
+}
+
+impl DSAPI {
+    /// Follows a chain of pointers described by `path` and returns the final pointer.
+    ///
+    /// Steps are separated by `->`. A global offset (e.g. `OFFSET_GWORLD`) is added to `base`, the module base,
+    /// and dereferenced. `Class.Member` dereferences a pointer member of the current object, and
+    /// `Class.Member[i]` reads the `i`th pointer of a `TArray` member. If the path starts with a member,
+    /// `base` is the address of the first object instead.
+    /// Fails on unknown offsets or members, unreadable memory, out of bounds indices and null pointers.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::{DSAPI, MemoryReader};
+    /// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, module_base: u64) -> Result<(), String> {
+    /// let local_player = dsapi.resolve_path(reader, module_base,
+    ///     "OFFSET_GWORLD -> UWorld.OwningGameInstance -> UGameInstance.LocalPlayers[0]")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve_path(&self, reader: &impl MemoryReader, base: u64, path: &str) -> Result<u64, String> {
+        let mut address = base;
+        for (i, segment) in path.split("->").enumerate() {
+            let pointer = match parse_segment(segment)? {
+                Segment::Global(name) if i == 0 => {
+                    let offset = self.get_offset(name).ok_or_else(|| format!("Offset not found: {}", name))?;
+                    address.wrapping_add(offset)
+                }
+                Segment::Global(name) => return Err(format!("Global offset {} must be the first step of the path", name)),
+                Segment::Member { class, member, index } => {
+                    let info = self.get_member_offset(class, member)
+                        .ok_or_else(|| format!("Member not found: {}::{}", class, member))?;
+                    let member_address = address.wrapping_add(info.offset as u64);
+                    match index {
+                        Some(index) => read_tarray(reader, member_address)?
+                            .element_address(index, 8)
+                            .ok_or_else(|| format!("Index out of bounds in {}", segment.trim()))?,
+                        None => member_address,
+                    }
+                }
+            };
+            address = reader.read_ptr(pointer)?;
+            if address == 0 {
+                return Err(format!("Null pointer at {}", segment.trim()));
+            }
+        }
+        Ok(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::tests::Buffer;
+    use crate::parse_blob;
+
+    #[test]
+    fn test_parse_segment() {
+        assert_eq!(parse_segment(" OFFSET_GWORLD ").unwrap(), Segment::Global("OFFSET_GWORLD"));
+        assert_eq!(parse_segment("UGameInstance.LocalPlayers[1]").unwrap(),
+            Segment::Member { class: "UGameInstance", member: "LocalPlayers", index: Some(1) });
+        assert!(parse_segment("UGameInstance.LocalPlayers[x]").is_err());
+        assert!(parse_segment("").is_err());
+    }
+
+    #[test]
+    fn test_resolve_path() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        let blob = r#"{"data":[{"UGameInstance":[{"__MDKClassSize":424},{"LocalPlayers":[["TArray","C","",[["ULocalPlayer","C","*",[]]]],56,16,1]}]}],"updated_at":"0","version":10202}"#;
+        dsapi.parse_class_info(crate::BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+
+        let module_base = 0x10000;
+        let gworld = module_base + dsapi.get_offset("OFFSET_GWORLD").unwrap();
+        let mut memory = Buffer { base: gworld, bytes: vec![0; 0x2000] };
+        let (world, game_instance, players) = (gworld + 0x100, gworld + 0x400, gworld + 0x600);
+        memory.write(gworld, world);
+        memory.write(world + 0x228, game_instance);
+        memory.write(game_instance + 56, players);
+        memory.write(game_instance + 56 + 8, 2_i32);
+        memory.write(game_instance + 56 + 12, 4_i32);
+        memory.write(players + 8, 0x1234_u64);
+
+        let path = "OFFSET_GWORLD -> UWorld.OwningGameInstance -> UGameInstance.LocalPlayers[1]";
+        assert_eq!(dsapi.resolve_path(&memory, module_base, path), Ok(0x1234));
+        assert_eq!(dsapi.resolve_path(&memory, world, "UWorld.OwningGameInstance"), Ok(game_instance));
+        assert!(dsapi.resolve_path(&memory, module_base, &path.replace("[1]", "[2]")).is_err());
+        assert_eq!(dsapi.resolve_path(&memory, module_base, &path.replace("[1]", "[0]")),
+            Err("Null pointer at UGameInstance.LocalPlayers[0]".to_string()));
+        assert!(dsapi.resolve_path(&memory, module_base, "OFFSET_NOPE").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! Checking whether a dumpspace server is reachable before depending on it.
+
+use std::io::Read;
+use std::time::{Duration, Instant};
+
+use crate::{DSAPIBuilder, GameList};
+
+/// How a server answered [`ping`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerStatus {
+    /// Time until the response headers arrived.
+    pub latency: Duration,
+    /// HTTP status of the game list request.
+    pub status: u16,
+    /// Number of games in the game list, `None` if the request failed or the body isn't a valid game list.
+    pub games: Option<usize>,
+}
+
+impl ServerStatus {
+    /// Returns `true` if the server sent a valid game list, so downloads can be expected to work.
+    pub fn is_healthy(&self) -> bool {
+        (200..300).contains(&self.status) && self.games.is_some()
+    }
+}
+
+/// Requests the game list of the server at `base_url` once, with the timeout of [`crate::configure`],
+/// so a launcher can fall back to cached data up front instead of failing halfway through initialization.
+/// Fails if the server is unreachable; other problems are reported in the [`ServerStatus`].
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DEFAULT_BASE_URL, ping};
+/// match ping(DEFAULT_BASE_URL) {
+///     Ok(status) if status.is_healthy() => println!("dumpspace answered in {:?}", status.latency),
+///     Ok(status) => println!("dumpspace is unhealthy ({}), using cached data", status.status),
+///     Err(e) => println!("dumpspace unreachable, using cached data: {}", e),
+/// }
+/// ```
+pub fn ping(base_url: &str) -> Result<ServerStatus, String> {
+    let options = DSAPIBuilder::configured_options()?;
+    let url = format!("{}/Games/GameList.json", base_url.trim_end_matches('/'));
+    let start = Instant::now();
+    let (status, mut body) = options.client.get_or_create()?.get_status(&url)?;
+    let latency = start.elapsed();
+    let mut text = String::new();
+    let games = body.read_to_string(&mut text).ok()
+        .and_then(|_| serde_json::from_str::<GameList>(&text).ok())
+        .map(|list| list.games.len());
+    Ok(ServerStatus { latency, status, games })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ping() {
+        let base_url = crate::tests::fixture_server();
+        let status = ping(&format!("{}/", base_url)).unwrap();
+        assert!(status.is_healthy());
+        assert_eq!((status.status, status.games), (200, Some(1)));
+
+        let status = ping(&format!("{}/mirror", base_url)).unwrap();
+        assert!(!status.is_healthy());
+        assert_eq!((status.status, status.games), (404, None));
+
+        assert!(ping("http://127.0.0.1:9").is_err());
+    }
+}
@@ -0,0 +1,11 @@
+//! The types most code using this crate needs, for a single glob import.
+//! ```
+//! use dumpspace_api::prelude::*;
+//! let dsapi = DsApiMock::new().with_member_info("UWorld", "OwningGameInstance", OffsetInfo::member(0x228, 8)).build();
+//! assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance"), Some(OffsetInfo::member(0x228, 8)));
+//! ```
+
+pub use crate::{
+    BlobKind, DSAPI, DSAPIBuilder, DsApiMock, DumpOverlay, FunctionInfo, Game, GameList, MemoryReader, NameMatch,
+    OffsetInfo,
+};
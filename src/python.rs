@@ -0,0 +1,93 @@
+//! Python bindings, enabled with the `python` feature. Build the `dumpspace` module with `maturin build`.
+//! ```python
+//! import dumpspace
+//! dsapi = dumpspace.DSAPI("6b77eceb")
+//! print(hex(dsapi.get_member_offset("UWorld", "OwningGameInstance").offset))
+//! ```
+
+use std::path::PathBuf;
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+use crate::{DSAPI, OffsetInfo};
+
+/// A downloaded dump of one game. Creating it downloads the dump (or restores it from `cache_dir`).
+#[pyclass(name = "DSAPI", module = "dumpspace")]
+struct PyDsApi {
+    inner: DSAPI,
+}
+
+/// The offset info of a class member.
+#[pyclass(name = "OffsetInfo", module = "dumpspace", frozen, get_all)]
+struct PyOffsetInfo {
+    offset: i64,
+    size: i64,
+    is_bit: bool,
+    bit_offset: i32,
+}
+
+#[pymethods]
+impl PyOffsetInfo {
+    fn __repr__(&self) -> String {
+        format!("OffsetInfo(offset=0x{:x}, size={}, is_bit={}, bit_offset={})",
+            self.offset, self.size, if self.is_bit { "True" } else { "False" }, self.bit_offset)
+    }
+}
+
+impl From<OffsetInfo> for PyOffsetInfo {
+    fn from(info: OffsetInfo) -> Self {
+        PyOffsetInfo { offset: info.offset, size: info.size, is_bit: info.is_bit, bit_offset: info.bit_offset }
+    }
+}
+
+fn runtime_error(error: String) -> PyErr {
+    PyRuntimeError::new_err(error)
+}
+
+#[pymethods]
+impl PyDsApi {
+    #[new]
+    #[pyo3(signature = (game_id, cache_dir = None))]
+    fn new(py: Python<'_>, game_id: &str, cache_dir: Option<PathBuf>) -> PyResult<Self> {
+        py.detach(|| {
+            let mut builder = DSAPI::builder().game_id(game_id);
+            if let Some(cache_dir) = cache_dir {
+                builder = builder.cache_dir(cache_dir);
+            }
+            let mut inner = builder.build()?;
+            inner.download_content()?;
+            Ok(PyDsApi { inner })
+        }).map_err(runtime_error)
+    }
+    /// Downloads a newer dump if there is one. Returns `True` if it was applied.
+    fn refresh(&mut self, py: Python<'_>) -> PyResult<bool> {
+        py.detach(|| self.inner.refresh()).map_err(runtime_error)
+    }
+    fn get_member_offset(&self, class_name: &str, member_name: &str) -> Option<PyOffsetInfo> {
+        self.inner.get_member_offset(class_name, member_name).map(PyOffsetInfo::from)
+    }
+    fn get_class_size(&self, class_name: &str) -> Option<i32> {
+        self.inner.get_class_size(class_name)
+    }
+    fn get_enum_name(&self, enum_name: &str, enum_value: i64) -> Option<String> {
+        self.inner.get_enum_name(enum_name, enum_value)
+    }
+    fn get_offset(&self, offset_name: &str) -> Option<u64> {
+        self.inner.get_offset(offset_name)
+    }
+    fn get_super_classes(&self, class_name: &str) -> Option<Vec<String>> {
+        self.inner.get_super_classes(class_name)
+    }
+    fn __repr__(&self) -> String {
+        format!("DSAPI({:?})", self.inner.game().name)
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "dumpspace")]
+fn dumpspace(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDsApi>()?;
+    module.add_class::<PyOffsetInfo>()?;
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! `Class::Member` style queries that work out what kind of entry they refer to, and wildcard searches, for the CLI.
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{DSAPI, FunctionInfo, OffsetInfo, short_enum_name};
+
+/// What a query passed to [`DSAPI::query`] resolved to.
+/// Serialized with its kind in a `kind` field, e.g. `{"kind": "global_offset", "name": "OFFSET_GWORLD", "offset": 345253952}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QueryMatch {
+    /// `UWorld::OwningGameInstance`
+    Member { class: String, member: String, info: OffsetInfo },
+    /// `AActor::K2_GetActorLocation`
+    Function { class: String, function: String, info: FunctionInfo },
+    /// `EFortRarity::4` or `EFortRarity::Legendary`
+    EnumValue { enum_name: String, value: i64, value_name: String },
+    /// `UWorld`
+    #[serde(rename = "class")]
+    ClassSize { class: String, size: i32 },
+    /// `OFFSET_GWORLD`
+    #[serde(rename = "global_offset")]
+    Offset { name: String, offset: u64 },
+}
+
+impl QueryMatch {
+    /// What kind of entry this is, e.g. `member` or `enum value`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            QueryMatch::Member { .. } => "member",
+            QueryMatch::Function { .. } => "function",
+            QueryMatch::EnumValue { .. } => "enum value",
+            QueryMatch::ClassSize { .. } => "class",
+            QueryMatch::Offset { .. } => "global offset",
+        }
+    }
+}
+
+impl std::fmt::Display for QueryMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QueryMatch::Member { class, member, info } if info.is_bit => {
+                write!(f, "{}::{} = 0x{:x} (bit {})", class, member, info.offset, info.bit_offset)
+            }
+            QueryMatch::Member { class, member, info } => write!(f, "{}::{} = 0x{:x} (size 0x{:x})", class, member, info.offset, info.size),
+            QueryMatch::Function { class, function, info } => write!(f, "{}::{} = 0x{:x}", class, function, info.offset),
+            QueryMatch::EnumValue { enum_name, value, value_name } => write!(f, "{}::{} = {}", enum_name, value_name, value),
+            QueryMatch::ClassSize { class, size } => write!(f, "sizeof({}) = 0x{:x}", class, size),
+            QueryMatch::Offset { name, offset } => write!(f, "{} = 0x{:x}", name, offset),
+        }
+    }
+}
+
+/// Parses a decimal or `0x` hexadecimal enum value, optionally negative.
+fn parse_value(value: &str) -> Option<i64> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let value = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None => digits.parse().ok()?,
+    };
+    Some(if negative { -value } else { value })
+}
+
+impl DSAPI {
+    /// Looks up `UWorld::OwningGameInstance` (a member or function), `EFortRarity::4` or
+    /// `EFortRarity::Legendary` (an enum value by number or name), `UWorld` (a class size) or
+    /// `OFFSET_GWORLD` (a global offset), working out which one is meant.
+    /// Fails if nothing matches, or if the query matches entries of different kinds.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::{DsApiMock, QueryMatch};
+    /// let dsapi = DsApiMock::new().with_enum_value("EFortRarity", 4, "EFortRarity__Legendary").build();
+    /// let found = dsapi.query("EFortRarity::4").unwrap();
+    /// assert_eq!(found.to_string(), "EFortRarity::EFortRarity__Legendary = 4");
+    /// ```
+    pub fn query(&self, query: &str) -> Result<QueryMatch, String> {
+        let query = query.trim();
+        let mut matches = Vec::new();
+        match query.split_once("::") {
+            Some((_, rest)) if rest.contains("::") => return Err(format!("Invalid query {}: expected Class::Member", query)),
+            Some((scope, name)) => {
+                let (scope, name) = (scope.trim(), name.trim());
+                if let Some(info) = self.get_member_offset(scope, name) {
+                    matches.push(QueryMatch::Member { class: scope.to_string(), member: name.to_string(), info });
+                }
+                if let Some(info) = self.get_function_info(scope, name) {
+                    matches.push(QueryMatch::Function { class: scope.to_string(), function: name.to_string(), info });
+                }
+                if let Some(value) = parse_value(name) {
+                    if let Some(value_name) = self.get_enum_name(scope, value) {
+                        matches.push(QueryMatch::EnumValue { enum_name: scope.to_string(), value, value_name });
+                    }
+                } else if let Some((value, value_name)) = self.find_enum_value(scope, name) {
+                    matches.push(QueryMatch::EnumValue { enum_name: scope.to_string(), value, value_name });
+                }
+            }
+            None => {
+                if let Some(offset) = self.get_offset(query) {
+                    matches.push(QueryMatch::Offset { name: query.to_string(), offset });
+                }
+                if let Some(size) = self.get_class_size(query) {
+                    matches.push(QueryMatch::ClassSize { class: query.to_string(), size });
+                }
+            }
+        }
+        match matches.len() {
+            0 => Err(format!("Nothing named {} in the dump", query)),
+            1 => Ok(matches.remove(0)),
+            _ => {
+                let kinds: Vec<&str> = matches.iter().map(QueryMatch::kind).collect();
+                Err(format!("Ambiguous query {}: matches a {}", query, kinds.join(" and a ")))
+            }
+        }
+    }
+    /// Finds every member, function, enum value, class and global offset whose name matches the wildcard
+    /// `pattern` (`*` and `?`, case-sensitive), sorted by kind and name. Patterns with `::` are matched against
+    /// `Class::Member`, `Class::Function` and `Enum::Value`, others against class, offset, member and function names.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let dsapi = DsApiMock::new().with_member("UWorld", "OwningGameInstance", 0x228, 8).build();
+    /// assert_eq!(dsapi.search("*GameInstance").len(), 1);
+    /// assert_eq!(dsapi.search("UWorld::*").len(), 1);
+    /// ```
+    pub fn search(&self, pattern: &str) -> Vec<QueryMatch> {
+        let pattern = pattern.trim();
+        let qualified = pattern.contains("::");
+        let scoped = |scope: &str, name: &str| match qualified {
+            true => crate::glob::matches(pattern, &format!("{}::{}", scope, name)),
+            false => crate::glob::matches(pattern, name),
+        };
+        let mut found: Vec<QueryMatch> = self.members()
+            .filter(|(class, member, _)| scoped(class, member))
+            .map(|(class, member, info)| QueryMatch::Member { class: class.to_string(), member: member.to_string(), info: info.clone() })
+            .chain(self.functions()
+                .filter(|(class, function, _)| scoped(class, function))
+                .map(|(class, function, info)| QueryMatch::Function { class: class.to_string(), function: function.to_string(), info: info.clone() }))
+            .collect();
+        if qualified {
+            found.extend(self.enum_name_map.iter()
+                .map(|((enum_name, value), value_name)| (self.names.resolve(*enum_name), *value, self.names.resolve(*value_name)))
+                .filter(|(enum_name, _, value_name)| scoped(enum_name, short_enum_name(enum_name, value_name)) || scoped(enum_name, value_name))
+                .map(|(enum_name, value, value_name)| QueryMatch::EnumValue { enum_name: enum_name.to_string(), value, value_name: value_name.to_string() }));
+        } else {
+            found.extend(self.class_sizes()
+                .filter(|(class, _)| crate::glob::matches(pattern, class))
+                .map(|(class, size)| QueryMatch::ClassSize { class: class.to_string(), size }));
+            found.extend(self.sorted_offsets().into_iter()
+                .filter(|(name, _)| crate::glob::matches(pattern, name))
+                .map(|(name, offset)| QueryMatch::Offset { name: name.to_string(), offset }));
+        }
+        found.sort_by_cached_key(|found| (found.kind(), found.to_string()));
+        found
+    }
+    /// The value of an enum's value name, given with or without the `Enum__` prefix.
+    fn find_enum_value(&self, enum_name: &str, value_name: &str) -> Option<(i64, String)> {
+        let enum_sym = self.names.get(enum_name)?;
+        self.enum_name_map.iter()
+            .filter(|((owner, _), _)| *owner == enum_sym)
+            .map(|((_, value), name)| (*value, self.names.resolve(*name)))
+            .find(|(_, name)| *name == value_name || short_enum_name(enum_name, name) == value_name)
+            .map(|(value, name)| (value, name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_query() {
+        let dsapi = fixture_dsapi();
+        assert!(matches!(dsapi.query("UWorld::OwningGameInstance"), Ok(QueryMatch::Member { info, .. }) if info.offset == 0x228));
+        assert!(matches!(dsapi.query("AActor::K2_GetActorLocation"), Ok(QueryMatch::Function { .. })));
+        assert_eq!(dsapi.query("EFortRarity::4").unwrap().to_string(), "EFortRarity::EFortRarity__Legendary = 4");
+        assert_eq!(dsapi.query("EFortRarity::0x4"), dsapi.query("EFortRarity::Legendary"));
+        assert_eq!(dsapi.query("UWorld").unwrap().to_string(), "sizeof(UWorld) = 0x9e8");
+        assert_eq!(dsapi.query(" OFFSET_GWORLD ").unwrap().to_string(), "OFFSET_GWORLD = 0x14942840");
+        assert_eq!(serde_json::to_value(dsapi.query("OFFSET_GWORLD").unwrap()).unwrap()["kind"], "global_offset");
+        assert_eq!(dsapi.query("UWorld::Nope").unwrap_err(), "Nothing named UWorld::Nope in the dump");
+        assert!(dsapi.query("A::B::C").is_err());
+
+        let kinds = |pattern: &str| dsapi.search(pattern).iter().map(QueryMatch::kind).collect::<Vec<_>>();
+        assert_eq!(kinds("UWorld::Owning*"), ["member"]);
+        assert_eq!(kinds("EFortRarity::Leg*"), ["enum value"]);
+        assert_eq!(kinds("OFFSET_G*"), ["global offset"; 3]);
+        assert_eq!(kinds("UWorld"), ["class"]);
+        assert_eq!(kinds("K2_Get*"), ["function"]);
+        assert!(dsapi.search("Nope*").is_empty());
+
+        let mut dsapi = dsapi;
+        dsapi.overlay(crate::DumpOverlay::new().member("AActor", "K2_GetActorLocation", OffsetInfo::member(0x10, 8)));
+        assert_eq!(dsapi.query("AActor::K2_GetActorLocation").unwrap_err(), "Ambiguous query AActor::K2_GetActorLocation: matches a member and a function");
+    }
+}
@@ -0,0 +1,47 @@
+use crate::DSAPI;
+
+impl DSAPI {
+    /// Sets the address the game's main module is loaded at, used by the `*_rebased`/`*_address` getters
+    /// to turn the image-relative offsets from OffsetsInfo into absolute addresses.
+    /// Kept across [`DSAPI::download_content`] and [`DSAPI::refresh`].
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.set_module_base(0x7ff6_1234_0000);
+    /// let gworld = dsapi.get_offset_rebased("OFFSET_GWORLD").unwrap();
+    /// ```
+    pub fn set_module_base(&mut self, module_base: u64) {
+        self.module_base = Some(module_base);
+    }
+    /// Returns the module base set with [`DSAPI::set_module_base`].
+    pub fn module_base(&self) -> Option<u64> {
+        self.module_base
+    }
+    /// Returns the absolute address of a global offset as an `Option<u64>`.
+    /// Returns `None` if the offset name is not found or no module base is set.
+    pub fn get_offset_rebased(&self, offset_name: &str) -> Option<u64> {
+        Some(self.module_base?.wrapping_add(self.get_offset(offset_name)?))
+    }
+    /// Returns the absolute address of a function as an `Option<u64>`.
+    /// Returns `None` if the function is not found or no module base is set.
+    pub fn get_function_address(&self, function_class: &str, function_name: &str) -> Option<u64> {
+        Some(self.module_base?.wrapping_add(self.get_function_offset(function_class, function_name)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_rebase() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(dsapi.get_offset_rebased("OFFSET_GWORLD"), None);
+        dsapi.set_module_base(0x7ff0_0000_0000);
+        assert_eq!(dsapi.module_base(), Some(0x7ff0_0000_0000));
+        assert_eq!(dsapi.get_offset_rebased("OFFSET_GWORLD"), Some(0x7ff0_1494_2840));
+        assert_eq!(dsapi.get_offset_rebased("OFFSET_NOPE"), None);
+        assert_eq!(dsapi.get_function_address("AActor", "K2_GetActorLocation"), Some(0x7ff0_02a1_b3c0));
+        assert_eq!(dsapi.get_function_address("AActor", "K2_Nope"), None);
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use crate::{DSAPI, MemberType, OffsetInfo};
+
+/// A member that was likely renamed between two dumps, found with [`DSAPI::match_renames`].
+#[derive(Debug, Clone)]
+pub struct MemberRename {
+    pub class: String,
+    pub old_name: String,
+    pub new_name: String,
+    pub old: OffsetInfo,
+    pub new: OffsetInfo,
+}
+
+/// A member's name, position and type, if the dump has one.
+type Member<'a> = (&'a str, &'a OffsetInfo, Option<&'a MemberType>);
+
+type ClassLayout<'a> = BTreeMap<&'a str, Vec<Member<'a>>>;
+
+/// Every class's members, sorted by position.
+fn layouts(dsapi: &DSAPI) -> ClassLayout<'_> {
+    let mut layouts: ClassLayout = BTreeMap::new();
+    for (class, member, info) in dsapi.members() {
+        layouts.entry(class).or_default().push((member, info, dsapi.get_member_type(class, member)));
+    }
+    for members in layouts.values_mut() {
+        members.sort_by_key(|(member, info, _)| (info.offset, info.bit_offset, *member));
+    }
+    layouts
+}
+
+/// Matches the members of a class that only exist in one of the two dumps.
+/// A removed member is paired with an added one of the same size and type that sits between the same
+/// unchanged neighbours, preferring the one closest to where the neighbours moved it.
+/// Types are only compared if both dumps have one for the member.
+fn match_class(class: &str, old: &[Member], new: &[Member]) -> Vec<MemberRename> {
+    let new_by_name: BTreeMap<&str, &OffsetInfo> = new.iter().map(|(name, info, _)| (*name, *info)).collect();
+    let kept = |name: &str| new_by_name.contains_key(name);
+    let mut added: Vec<Member> = new.iter()
+        .copied()
+        .filter(|(name, _, _)| !old.iter().any(|(old_name, _, _)| old_name == name))
+        .collect();
+
+    let mut renames = Vec::new();
+    for (index, (old_name, old_info, old_type)) in old.iter().enumerate() {
+        if kept(old_name) {
+            continue;
+        }
+        let prev = old[..index].iter().rev().find(|(name, _, _)| kept(name));
+        let next = old[index + 1..].iter().find(|(name, _, _)| kept(name));
+        let lower = prev.map_or(i64::MIN, |(name, _, _)| new_by_name[name].offset);
+        let upper = next.map_or(i64::MAX, |(name, _, _)| new_by_name[name].offset);
+        let shift = prev.or(next).map_or(0, |(name, info, _)| new_by_name[name].offset - info.offset);
+        let expected = old_info.offset + shift;
+
+        let mut candidates: Vec<(i64, usize)> = added.iter()
+            .enumerate()
+            .filter(|(_, (_, info, _))| info.size == old_info.size && info.is_bit == old_info.is_bit)
+            .filter(|(_, (_, info, _))| !info.is_bit || info.bit_offset == old_info.bit_offset)
+            .filter(|(_, (_, _, ty))| ty.zip(*old_type).is_none_or(|(ty, old_type)| ty == old_type))
+            .filter(|(_, (_, info, _))| (lower..=upper).contains(&info.offset))
+            .map(|(candidate, (_, info, _))| ((info.offset - expected).abs(), candidate))
+            .collect();
+        candidates.sort_unstable();
+        let best = match candidates.as_slice() {
+            [] => continue,
+            [(_, best)] => *best,
+            [(distance, best), (next_distance, _), ..] if distance < next_distance => *best,
+            _ => continue, // ambiguous
+        };
+        let (new_name, new_info, _) = added.remove(best);
+        renames.push(MemberRename {
+            class: class.to_string(),
+            old_name: old_name.to_string(),
+            new_name: new_name.to_string(),
+            old: (*old_info).clone(),
+            new: new_info.clone(),
+        });
+    }
+    renames
+}
+
+impl DSAPI {
+    /// Guesses which members of this dump were renamed in a `newer` dump of the same game.
+    /// A member counts as renamed if it disappeared and a member of the same size, kind and type appeared
+    /// between the same unchanged neighbours. Ambiguous cases are left out.
+    /// The result is sorted by class and can be fed into [`DSAPI::add_member_alias`].
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// # let (old, mut new) = (DSAPI::new("6b77eceb", None), DSAPI::new("6b77eceb", None));
+    /// for rename in old.match_renames(&new) {
+    ///     println!("{}::{} is now called {}", rename.class, rename.old_name, rename.new_name);
+    ///     new.add_member_alias(&rename.class, &rename.new_name, &[&rename.old_name]);
+    /// }
+    /// ```
+    pub fn match_renames(&self, newer: &DSAPI) -> Vec<MemberRename> {
+        let old = layouts(self);
+        let new = layouts(newer);
+        old.iter()
+            .filter_map(|(class, old_members)| Some((class, old_members, new.get(class)?)))
+            .flat_map(|(class, old_members, new_members)| match_class(class, old_members, new_members))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlobKind, parse_blob};
+
+    #[test]
+    fn test_match_renames() {
+        let old = crate::tests::fixture_dsapi();
+        assert!(old.match_renames(&old).is_empty());
+
+        let mut new = crate::tests::fixture_dsapi();
+        let key = (new.names.get("UWorld").unwrap(), new.names.get("OwningGameInstance").unwrap());
+        new.class_member_map.remove(&key);
+        let blob = r#"{"data":[{"UWorld":[{"GameInstance":[["UGameInstance","C","*",[]],560,8,1]},{"Unrelated":[["int32","D","",[]],600,4,1]}]}],"updated_at":"0","version":10202}"#;
+        new.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+
+        let renames = old.match_renames(&new);
+        assert_eq!(renames.len(), 1);
+        assert_eq!((renames[0].class.as_str(), renames[0].old_name.as_str(), renames[0].new_name.as_str()), ("UWorld", "OwningGameInstance", "GameInstance"));
+        assert_eq!((renames[0].old.offset, renames[0].new.offset), (0x228, 0x230));
+
+        // Same size and place, but a different type.
+        let mut retyped = crate::tests::fixture_dsapi();
+        retyped.class_member_map.remove(&key);
+        let blob = r#"{"data":[{"UWorld":[{"GameInstance":[["UObject","C","*",[]],560,8,1]}]}],"updated_at":"0","version":10202}"#;
+        retyped.parse_class_info(BlobKind::Classes, parse_blob(blob.as_bytes()).unwrap()).unwrap();
+        assert!(old.match_renames(&retyped).is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+//! A local HTTP server answering lookups on a parsed dump as JSON, enabled with the `serve` feature.
+
+use serde_json::json;
+
+use crate::{DSAPI, QueryMatch};
+
+impl DSAPI {
+    /// Listens on `addr` (e.g. `"127.0.0.1:8080"`) and answers lookups as JSON until the process exits,
+    /// so tools in other languages can read offsets without linking this crate. Endpoints:
+    ///
+    /// * `/member/UWorld/OwningGameInstance`, `/function/AActor/K2_GetActorLocation`
+    /// * `/enum/EFortRarity/4` or `/enum/EFortRarity/Legendary`
+    /// * `/class/UWorld` for the class size, `/offset/OFFSET_GWORLD`
+    /// * `/query/UWorld::OwningGameInstance` and `/search/UWorld::*`, see [`DSAPI::query`] and [`DSAPI::search`]
+    /// * `/game` for the game the dump belongs to
+    ///
+    /// Results are [`QueryMatch`]es serialized like `dumpspace get --format json` prints them,
+    /// failures a `{"error": "..."}` object with a 404 or 400 status.
+    /// Requests are answered one at a time; lookups take microseconds, so this is rarely a bottleneck.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// // curl http://127.0.0.1:8080/offset/OFFSET_GWORLD
+    /// dsapi.serve("127.0.0.1:8080").unwrap();
+    /// ```
+    pub fn serve(&self, addr: &str) -> Result<(), String> {
+        let server = tiny_http::Server::http(addr).map_err(|e| format!("Failed to listen on {}: {}", addr, e))?;
+        let header = tiny_http::Header::from_bytes("Content-Type", "application/json").unwrap();
+        for request in server.incoming_requests() {
+            let (status, body) = match request.method() {
+                tiny_http::Method::Get => match self.answer(request.url()) {
+                    Ok(body) => (200, body),
+                    Err((status, error)) => (status, json!({ "error": error })),
+                },
+                _ => (405, json!({ "error": "Only GET requests are supported" })),
+            };
+            let response = tiny_http::Response::from_string(body.to_string()).with_status_code(status).with_header(header.clone());
+            // The client hung up, nothing to do about it.
+            let _ = request.respond(response);
+        }
+        Ok(())
+    }
+    /// The JSON answer to a request for `url`. Errors carry the HTTP status to answer with.
+    pub(crate) fn answer(&self, url: &str) -> Result<serde_json::Value, (u16, String)> {
+        let path = url.split_once('?').map_or(url, |(path, _)| path);
+        let segments = path.trim_matches('/').split('/').map(percent_decode).collect::<Option<Vec<_>>>()
+            .ok_or_else(|| (400, format!("Invalid URL: {}", url)))?;
+        let not_found = |query: String| (404, format!("Nothing named {} in the dump", query));
+        let found = match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+            ["member", class, member] => self.get_member_offset(class, member)
+                .map(|info| QueryMatch::Member { class: class.to_string(), member: member.to_string(), info })
+                .ok_or_else(|| not_found(format!("{}::{}", class, member)))?,
+            ["function", class, function] => self.get_function_info(class, function)
+                .map(|info| QueryMatch::Function { class: class.to_string(), function: function.to_string(), info })
+                .ok_or_else(|| not_found(format!("{}::{}", class, function)))?,
+            ["enum", enum_name, value] => match self.query(&format!("{}::{}", enum_name, value)) {
+                Ok(found @ QueryMatch::EnumValue { .. }) => found,
+                _ => return Err(not_found(format!("{}::{}", enum_name, value))),
+            },
+            ["class", class] => self.get_class_size(class)
+                .map(|size| QueryMatch::ClassSize { class: class.to_string(), size })
+                .ok_or_else(|| not_found(class.to_string()))?,
+            ["offset", name] => self.get_offset(name)
+                .map(|offset| QueryMatch::Offset { name: name.to_string(), offset })
+                .ok_or_else(|| not_found(name.to_string()))?,
+            ["query", query] => self.query(query).map_err(|e| (404, e))?,
+            ["search", pattern] => return Ok(json!(self.search(pattern))),
+            ["game"] => return Ok(json!(self.game())),
+            _ => return Err((404, format!("Unknown endpoint: {}", path))),
+        };
+        Ok(json!(found))
+    }
+}
+
+/// Decodes `%XX` escapes, e.g. `UWorld%3A%3A*` from clients that escape `:` and `*`.
+fn percent_decode(segment: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_answer() {
+        let dsapi = fixture_dsapi();
+        assert_eq!(dsapi.answer("/member/UWorld/OwningGameInstance").unwrap()["info"]["offset"], 0x228);
+        assert_eq!(dsapi.answer("/offset/OFFSET_GWORLD").unwrap()["offset"], 0x14942840);
+        assert_eq!(dsapi.answer("/class/UWorld").unwrap()["size"], 0x9e8);
+        assert_eq!(dsapi.answer("/enum/EFortRarity/Legendary").unwrap()["value"], 4);
+        assert_eq!(dsapi.answer("/function/AActor/K2_GetActorLocation").unwrap()["kind"], "function");
+        assert_eq!(dsapi.answer("/query/UWorld%3A%3AOwningGameInstance"), dsapi.answer("/member/UWorld/OwningGameInstance"));
+        assert_eq!(dsapi.answer("/search/UWorld::Owning*").unwrap().as_array().unwrap().len(), 1);
+        assert_eq!(dsapi.answer("/game").unwrap()["hash"], dsapi.game().hash);
+        assert_eq!(dsapi.answer("/member/UWorld/Nope").unwrap_err(), (404, "Nothing named UWorld::Nope in the dump".to_string()));
+        assert_eq!(dsapi.answer("/enum/UWorld/OwningGameInstance").unwrap_err().0, 404);
+        assert_eq!(dsapi.answer("/offset/%zz").unwrap_err().0, 400);
+        assert_eq!(dsapi.answer("/members").unwrap_err().0, 404);
+    }
+}
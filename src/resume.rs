@@ -0,0 +1,298 @@
+//! Downloads into the cache directory that continue where an interrupted attempt stopped, with HTTP Range requests.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::builder::DownloadOptions;
+use crate::http::{Body, HttpClient, RangeResponse};
+
+/// The partial download of `file` and the validator of the bytes in it.
+fn partial_paths(file: &Path) -> (PathBuf, PathBuf) {
+    let name = file.file_name().unwrap_or_default().to_string_lossy();
+    (file.with_file_name(format!("{}.part", name)), file.with_file_name(format!("{}.part.validator", name)))
+}
+
+/// A download that is written to its partial file while it is read. The bytes an earlier attempt left in
+/// the partial file are read back first, and a dropped connection is resumed where it stopped as long as
+/// retries are left. The partial file is removed once the whole resource was read.
+pub(crate) struct ResumableDownload {
+    client: HttpClient,
+    url: String,
+    retries: u32,
+    validator: Option<String>,
+    partial: PathBuf,
+    validator_file: PathBuf,
+    earlier: std::io::Take<File>,
+    file: File,
+    len: u64,
+    body: Body,
+    done: bool,
+}
+
+impl ResumableDownload {
+    /// Requests the rest of the resource after the connection dropped.
+    fn reconnect(&mut self) -> Result<(), String> {
+        // Without a validator the server can't tell whether the bytes read so far are still current.
+        let validator = self.validator.as_deref().ok_or("The connection dropped and the server sent no validator to resume with")?;
+        match self.client.get_range(&self.url, self.len, Some(validator))? {
+            Some(response) if response.partial => {
+                self.body = response.body;
+                Ok(())
+            }
+            _ => Err(format!("{} changed while it was downloaded", self.url)),
+        }
+    }
+    fn finish(&mut self) {
+        self.done = true;
+        let _ = std::fs::remove_file(&self.partial);
+        let _ = std::fs::remove_file(&self.validator_file);
+    }
+}
+
+impl Read for ResumableDownload {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.earlier.read(buf)?;
+        if read > 0 || buf.is_empty() {
+            return Ok(read);
+        }
+        loop {
+            match self.body.read(buf) {
+                Ok(0) => {
+                    self.finish();
+                    return Ok(0);
+                }
+                Ok(read) => {
+                    self.file.write_all(&buf[..read])?;
+                    self.len += read as u64;
+                    return Ok(read);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) if self.retries == 0 => return Err(e),
+                Err(_) => {
+                    self.retries -= 1;
+                    self.reconnect().map_err(std::io::Error::other)?;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ResumableDownload {
+    /// Decoders stop at the end of their data and may never ask for the end of the response.
+    /// Reading the rest completes the partial file so it can be removed.
+    fn drop(&mut self) {
+        if !self.done {
+            let _ = std::io::copy(self, &mut std::io::sink());
+        }
+    }
+}
+
+impl DownloadOptions {
+    /// Downloads `url` through a partial file next to `file`, returning the body and its validator.
+    /// A failed attempt keeps the bytes received so far, and the next attempt (a retry or a later call)
+    /// only requests the rest. The server sends the whole resource again if it changed in between.
+    pub(crate) fn download_resumable(&self, url: &str, file: &Path) -> Result<(ResumableDownload, Option<String>), String> {
+        let (partial, validator_file) = partial_paths(file);
+        if let Some(dir) = file.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        }
+        let client = self.client.get_or_create()?;
+        let mut attempt = 0;
+        let (response, start, file) = loop {
+            let error = match self.resume(client, url, &partial, &validator_file) {
+                Ok(resumed) => break resumed,
+                Err(e) => e,
+            };
+            if attempt >= self.retries {
+                return Err(error);
+            }
+            attempt += 1;
+        };
+        let earlier = File::open(&partial).map_err(|e| format!("Failed to read partial download: {}", e))?.take(start);
+        let validator = response.validator.clone();
+        let download = ResumableDownload {
+            client: client.clone(),
+            url: url.to_string(),
+            retries: self.retries - attempt,
+            validator: response.validator,
+            partial,
+            validator_file,
+            earlier,
+            file,
+            len: start,
+            body: response.body,
+            done: false,
+        };
+        Ok((download, validator))
+    }
+    /// Requests the bytes of `url` missing from `partial`, or all of them if there is no usable partial download.
+    /// Returns the response, how many bytes of `partial` it continues and `partial` opened for appending the rest.
+    fn resume(&self, client: &HttpClient, url: &str, partial: &Path, validator_file: &Path) -> Result<(RangeResponse, u64, File), String> {
+        // Without a validator the server can't tell whether the partial bytes are still current.
+        let validator = std::fs::read_to_string(validator_file).ok();
+        let start = match validator {
+            Some(_) => std::fs::metadata(partial).map_or(0, |metadata| metadata.len()),
+            None => 0,
+        };
+        let response = match client.get_range(url, start, validator.as_deref())? {
+            Some(response) => response,
+            // The partial download is longer than the resource, which must have changed.
+            None => client.get_range(url, 0, None)?.ok_or("Request failed with status: 416")?,
+        };
+        let file = match response.partial {
+            true => OpenOptions::new().append(true).open(partial),
+            false => File::create(partial),
+        }.map_err(|e| format!("Failed to write partial download: {}", e))?;
+        match &response.validator {
+            Some(validator) => std::fs::write(validator_file, validator),
+            None => std::fs::remove_file(validator_file).or_else(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => Ok(()),
+                _ => Err(e),
+            }),
+        }.map_err(|e| format!("Failed to write partial download: {}", e))?;
+        let start = if response.partial { start } else { 0 };
+        Ok((response, start, file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::builder::DownloadOptions;
+    use crate::http::{HttpClient, LazyClient};
+
+    /// Fails after `left` bytes, to simulate a dropped connection.
+    struct Truncated {
+        data: std::io::Cursor<Vec<u8>>,
+        left: usize,
+    }
+
+    impl Read for Truncated {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.left == 0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection dropped"));
+            }
+            let len = buf.len().min(self.left);
+            let read = self.data.read(&mut buf[..len])?;
+            self.left -= read;
+            Ok(read)
+        }
+    }
+
+    /// Serves `data` with an `ETag` and Range support. The first response is cut off after half the body.
+    /// Records the `Range` header of every request.
+    fn flaky_server(data: Vec<u8>, ranges: Arc<Mutex<Vec<Option<String>>>>) -> String {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let range = request.headers().iter().find(|header| header.field.equiv("Range")).map(|header| header.value.to_string());
+                let first = ranges.lock().unwrap().is_empty();
+                ranges.lock().unwrap().push(range.clone());
+                let start: usize = range.as_deref()
+                    .and_then(|range| range.strip_prefix("bytes=")?.strip_suffix('-')?.parse().ok())
+                    .unwrap_or(0);
+                let status = if start > 0 { 206 } else { 200 };
+                let body = data[start..].to_vec();
+                let len = body.len();
+                let left = if first { len / 2 } else { len };
+                let etag = tiny_http::Header::from_bytes("ETag", "\"v1\"").unwrap();
+                let response = tiny_http::Response::new(status.into(), vec![etag], Truncated { data: std::io::Cursor::new(body), left }, Some(len), None)
+                    .with_chunked_threshold(usize::MAX);
+                let _ = request.respond(response);
+            }
+        });
+        format!("http://127.0.0.1:{}/ClassesInfo.json.gz", port)
+    }
+
+    #[test]
+    fn test_resume_after_interruption() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let url = flaky_server(data.clone(), ranges.clone());
+        let dir = std::env::temp_dir().join(format!("dsapi_resume_{}", std::process::id()));
+        let file = dir.join("ClassesInfo.json.gz");
+
+        // The cut off response is only noticed once the request times out.
+        let client = HttpClient::new(Some(Duration::from_secs(1))).unwrap();
+        let options = DownloadOptions { client: LazyClient::ready(client), ..Default::default() };
+        let (mut download, _) = options.download_resumable(&url, &file).unwrap();
+        assert!(download.read_to_end(&mut Vec::new()).is_err());
+        drop(download);
+        assert!(dir.join("ClassesInfo.json.gz.part").exists());
+
+        let (mut download, validator) = options.download_resumable(&url, &file).unwrap();
+        let mut bytes = Vec::new();
+        download.read_to_end(&mut bytes).unwrap();
+        assert_eq!(bytes, data);
+        assert_eq!(validator.as_deref(), Some("\"v1\""));
+        let ranges = ranges.lock().unwrap();
+        assert_eq!(ranges[0], Some("bytes=0-".to_string()));
+        assert!(ranges[1].as_ref().is_some_and(|range| range != "bytes=0-"));
+        assert!(!dir.join("ClassesInfo.json.gz.part").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_resume_while_reading() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let url = flaky_server(data.clone(), ranges.clone());
+        let dir = std::env::temp_dir().join(format!("dsapi_resume_reading_{}", std::process::id()));
+        let file = dir.join("ClassesInfo.json.gz");
+        let client = HttpClient::new(Some(Duration::from_secs(1))).unwrap();
+        let options = DownloadOptions { client: LazyClient::ready(client), retries: 1, ..Default::default() };
+
+        let (mut download, _) = options.download_resumable(&url, &file).unwrap();
+        let mut bytes = Vec::new();
+        download.read_to_end(&mut bytes).unwrap();
+        assert_eq!(bytes, data);
+        assert_eq!(ranges.lock().unwrap().len(), 2);
+        drop(download);
+        assert!(!dir.join("ClassesInfo.json.gz.part").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cached_download_streams() {
+        let dir = std::env::temp_dir().join(format!("dsapi_resume_cached_{}", std::process::id()));
+        let mut dsapi = crate::DSAPI::builder().base_url(&crate::tests::fixture_server()).game_id("fixture").cache_dir(&dir).build().unwrap();
+        dsapi.download_content().unwrap();
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        // The partial files are gone even though the parser never asked for the end of the gzip streams.
+        let leftovers: Vec<_> = std::fs::read_dir(&dir).unwrap().flatten().filter(|entry| entry.file_name().to_string_lossy().contains(".part")).collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_content_resumes() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        std::io::Write::write_all(&mut encoder, include_bytes!("../tests/fixtures/ClassesInfo.json")).unwrap();
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let url = flaky_server(encoder.finish().unwrap(), ranges.clone());
+        let dir = std::env::temp_dir().join(format!("dsapi_resume_content_{}", std::process::id()));
+        let client = HttpClient::new(Some(Duration::from_secs(1))).unwrap();
+        let options = DownloadOptions {
+            client: LazyClient::ready(client),
+            base_url: url.trim_end_matches("/ClassesInfo.json.gz").to_string(),
+            blobs: vec![crate::BlobKind::Classes],
+            ..Default::default()
+        };
+        let fixture = crate::tests::fixture_dsapi();
+        let mut dsapi = crate::DSAPI::empty(fixture.game().clone(), Some(dir.clone()), fixture.game_list.clone(), options);
+
+        assert!(dsapi.download_content().is_err());
+        assert!(dir.join("ClassesInfo.json.gz.part").exists());
+        dsapi.download_content().unwrap();
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert!(ranges.lock().unwrap()[1].as_ref().is_some_and(|range| range != "bytes=0-"));
+        assert!(dir.join("dsapi_cache.json").exists());
+        assert!(!dir.join("ClassesInfo.json.gz.part").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
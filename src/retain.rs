@@ -0,0 +1,181 @@
+//! Dropping everything but a whitelist from a parsed dump, for shipping only what a tool uses.
+
+use std::collections::BTreeMap;
+use std::hash::Hash;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_derive::Deserialize;
+
+use crate::DSAPI;
+use crate::intern::{Interner, Sym};
+
+/// What [`DSAPI::retain`] keeps, e.g. the `[retain]` part of a config file:
+/// ```toml
+/// classes = ["UGameInstance"]
+/// enums = ["EFortRarity"]
+/// offsets = ["OFFSET_GWORLD"]
+///
+/// [members]
+/// UWorld = ["PersistentLevel", "OwningGameInstance"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RetainSpec {
+    /// Classes and structs to keep with all their members and functions.
+    #[serde(default)]
+    pub classes: Vec<String>,
+    /// Classes and structs to keep with only the listed members or functions.
+    #[serde(default)]
+    pub members: BTreeMap<String, Vec<String>>,
+    /// Enums to keep with all their values.
+    #[serde(default)]
+    pub enums: Vec<String>,
+    /// Global offsets to keep.
+    #[serde(default)]
+    pub offsets: Vec<String>,
+}
+
+/// Applies `keep` to every entry of a map, renaming the symbols of the kept ones, and releases the unused capacity.
+fn retain_map<K: Eq + Hash, V>(map: &mut FxHashMap<K, V>, keep: impl Fn(&K) -> bool, mut rekey: impl FnMut(K) -> K) {
+    *map = std::mem::take(map).into_iter().filter(|(key, _)| keep(key)).map(|(key, value)| (rekey(key), value)).collect();
+    map.shrink_to_fit();
+}
+
+impl DSAPI {
+    /// Drops every class, member, function, enum and global offset that `spec` doesn't list, along with
+    /// the names only they used, so a shipped dump or cache holds the few hundred entries a tool reads
+    /// instead of the whole game's reflection data. Super classes and packages of kept classes are kept.
+    /// Fails without changing anything if a listed name is not in the dump, so typos and entries removed
+    /// by a patch are caught before shipping.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::{DsApiMock, RetainSpec};
+    /// let mut dsapi = DsApiMock::new()
+    ///     .with_member("UWorld", "OwningGameInstance", 0x228, 8)
+    ///     .with_member("UWorld", "PersistentLevel", 0x30, 8)
+    ///     .with_offset("OFFSET_GWORLD", 0x14942840)
+    ///     .with_offset("OFFSET_GNAMES", 0x147fa680)
+    ///     .build();
+    /// let mut spec = RetainSpec { offsets: vec!["OFFSET_GWORLD".to_string()], ..Default::default() };
+    /// spec.members.insert("UWorld".to_string(), vec!["OwningGameInstance".to_string()]);
+    /// dsapi.retain(&spec).unwrap();
+    /// assert!(dsapi.get_member_offset("UWorld", "OwningGameInstance").is_some());
+    /// assert!(dsapi.get_member_offset("UWorld", "PersistentLevel").is_none());
+    /// assert!(dsapi.get_offset("OFFSET_GNAMES").is_none());
+    /// ```
+    pub fn retain(&mut self, spec: &RetainSpec) -> Result<(), String> {
+        let mut missing = Vec::new();
+        let mut whole_classes = FxHashSet::default();
+        for class_name in &spec.classes {
+            match self.class_sym(class_name) {
+                Some(class) => _ = whole_classes.insert(class),
+                None => missing.push(class_name.clone()),
+            }
+        }
+        let mut classes = whole_classes.clone();
+        let mut members = FxHashSet::default();
+        for (class_name, member_names) in &spec.members {
+            let Some(class) = self.class_sym(class_name) else {
+                missing.push(class_name.clone());
+                continue;
+            };
+            classes.insert(class);
+            for member_name in member_names {
+                let member = self.lookup_sym(member_name)
+                    .filter(|member| self.class_member_map.contains_key(&(class, *member)) || self.function_map.contains_key(&(class, *member)));
+                match member {
+                    Some(member) => _ = members.insert((class, member)),
+                    None => missing.push(format!("{}::{}", class_name, member_name)),
+                }
+            }
+        }
+        let mut enums = FxHashSet::default();
+        for enum_name in &spec.enums {
+            match self.lookup_sym(enum_name).filter(|sym| self.enum_name_map.keys().any(|(enum_sym, _)| enum_sym == sym)) {
+                Some(enum_sym) => _ = enums.insert(enum_sym),
+                None => missing.push(enum_name.clone()),
+            }
+        }
+        let mut offsets = FxHashSet::default();
+        for offset_name in &spec.offsets {
+            match self.offset_map.get_key_value(offset_name.as_str()).or_else(|| self.offset_map.get_key_value(self.lookup_offset_name(offset_name)?)) {
+                Some((name, _)) => _ = offsets.insert(name.clone()),
+                None => missing.push(offset_name.clone()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(format!("Not in the dump: {}", missing.join(", ")));
+        }
+
+        let old_names = std::mem::take(&mut self.names);
+        let mut names = Interner::default();
+        let mut rename = |sym: Sym| names.intern(old_names.resolve(sym));
+        let keep_member = |(class, member): &(Sym, Sym)| whole_classes.contains(class) || members.contains(&(*class, *member));
+        retain_map(&mut self.class_member_map, keep_member, |(class, member)| (rename(class), rename(member)));
+        retain_map(&mut self.member_type_map, keep_member, |(class, member)| (rename(class), rename(member)));
+        retain_map(&mut self.function_map, keep_member, |(class, member)| (rename(class), rename(member)));
+        retain_map(&mut self.function_sig_map, keep_member, |(class, member)| (rename(class), rename(member)));
+        retain_map(&mut self.class_size_map, |class| classes.contains(class), &mut rename);
+        retain_map(&mut self.class_package_map, |class| classes.contains(class), &mut rename);
+        retain_map(&mut self.class_super_map, |class| classes.contains(class), &mut rename);
+        retain_map(&mut self.enum_name_map, |(enum_sym, _)| enums.contains(enum_sym), |(enum_sym, value)| (rename(enum_sym), value));
+        retain_map(&mut self.ambiguous_class_map, |_| true, &mut rename);
+        // Values hold symbols too, and are renamed after their keys.
+        for supers in self.class_super_map.values_mut() {
+            supers.iter_mut().for_each(|super_class| *super_class = rename(*super_class));
+        }
+        for package in self.class_package_map.values_mut() {
+            *package = rename(*package);
+        }
+        for value_name in self.enum_name_map.values_mut() {
+            *value_name = rename(*value_name);
+        }
+        for qualified in self.ambiguous_class_map.values_mut() {
+            qualified.retain(|class| classes.contains(class));
+            qualified.iter_mut().for_each(|class| *class = rename(*class));
+        }
+        self.ambiguous_class_map.retain(|_, qualified| !qualified.is_empty());
+        self.ambiguous_class_map.shrink_to_fit();
+        self.offset_map.retain(|name, _| offsets.contains(name));
+        self.offset_map.shrink_to_fit();
+        self.names = names;
+        self.index_folded_names();
+        self.member_hashes = Default::default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture_dsapi;
+
+    #[test]
+    fn test_retain() {
+        let mut dsapi = fixture_dsapi();
+        let before = dsapi.memory_usage().total_bytes();
+        let world_size = dsapi.get_class_size("UWorld");
+        let mut spec = RetainSpec {
+            classes: vec!["AActor".to_string()],
+            enums: vec!["EFortRarity".to_string()],
+            offsets: vec!["OFFSET_GWORLD".to_string()],
+            ..Default::default()
+        };
+        spec.members.insert("UWorld".to_string(), vec!["OwningGameInstance".to_string()]);
+
+        let mut typo = spec.clone();
+        typo.members.insert("UWorld".to_string(), vec!["OwningGameInstanc".to_string()]);
+        assert_eq!(dsapi.retain(&typo).unwrap_err(), "Not in the dump: UWorld::OwningGameInstanc");
+        assert_eq!(dsapi.memory_usage().total_bytes(), before);
+
+        dsapi.retain(&spec).unwrap();
+        assert_eq!(dsapi.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset, 0x228);
+        assert_eq!(dsapi.get_class_size("UWorld"), world_size);
+        assert_eq!(dsapi.get_super_classes("UWorld"), Some(vec!["UObject".to_string()]));
+        assert!(dsapi.get_function_offset("AActor", "K2_GetActorLocation").is_some());
+        assert_eq!(dsapi.get_enum_name_short("EFortRarity", 4).as_deref(), Some("Legendary"));
+        assert_eq!(dsapi.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        assert!(dsapi.get_offset("OFFSET_GNAMES").is_none());
+        assert!(dsapi.get_class_size("UObject").is_none());
+        assert!(dsapi.memory_usage().total_bytes() < before);
+    }
+}
@@ -0,0 +1,194 @@
+//! Export of the dump as IDA and Ghidra scripts.
+
+use std::fmt::Write;
+
+use crate::DSAPI;
+
+/// Helpers the generated script calls, so every entry is a single line.
+const IDA_PRELUDE: &str = r#"import idaapi
+import idc
+
+base = idaapi.get_imagebase()
+
+def ds_label(offset, name):
+    idc.set_name(base + offset, name, idc.SN_NOWARN | idc.SN_NOCHECK | idc.SN_FORCE)
+
+def ds_struct(name, size, members):
+    sid = idc.get_struc_id(name)
+    if sid != idc.BADADDR:
+        idc.del_struc(sid)
+    sid = idc.add_struc(-1, name, 0)
+    flags = {1: idc.FF_BYTE, 2: idc.FF_WORD, 4: idc.FF_DWORD, 8: idc.FF_QWORD}
+    for member, offset, member_size in members:
+        flag = flags.get(member_size, idc.FF_BYTE) | idc.FF_DATA
+        idc.add_struc_member(sid, member, offset, flag, -1, member_size)
+    if size > 0 and idc.get_struc_size(sid) < size:
+        idc.add_struc_member(sid, "__pad_end", size - 1, idc.FF_BYTE | idc.FF_DATA, -1, 1)
+"#;
+
+/// Helpers the generated Ghidra script calls, so every entry is a single line.
+const GHIDRA_PRELUDE: &str = r#"# Generated by dumpspace-api, run with the Script Manager (Jython or PyGhidra).
+from ghidra.program.model.data import ArrayDataType, ByteDataType, CategoryPath, DataTypeConflictHandler
+from ghidra.program.model.data import DWordDataType, EnumDataType, QWordDataType, StructureDataType, WordDataType
+from ghidra.program.model.symbol import SourceType
+
+base = currentProgram.getImageBase()
+dtm = currentProgram.getDataTypeManager()
+category = CategoryPath("/dumpspace")
+
+def ds_label(offset, name):
+    createLabel(base.add(offset), name, True, SourceType.USER_DEFINED)
+
+def ds_function(offset, name):
+    address = base.add(offset)
+    function = getFunctionAt(address)
+    if function is None:
+        createFunction(address, name)
+    else:
+        function.setName(name, SourceType.USER_DEFINED)
+
+def ds_type(size):
+    types = {1: ByteDataType.dataType, 2: WordDataType.dataType, 4: DWordDataType.dataType, 8: QWordDataType.dataType}
+    return types.get(size) or ArrayDataType(ByteDataType.dataType, size, 1)
+
+def ds_struct(name, size, members):
+    struct = StructureDataType(category, name, size)
+    for member, offset, member_size in members:
+        if offset + member_size <= size:
+            struct.replaceAtOffset(offset, ds_type(member_size), member_size, member, None)
+    dtm.addDataType(struct, DataTypeConflictHandler.REPLACE_HANDLER)
+
+def ds_enum(name, size, values):
+    enum = EnumDataType(category, name, size)
+    for value_name, value in values:
+        enum.add(value_name, value)
+    dtm.addDataType(enum, DataTypeConflictHandler.REPLACE_HANDLER)
+"#;
+
+/// Turns a name into a valid IDA/Ghidra/Python identifier.
+pub(crate) fn identifier(name: &str) -> String {
+    name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' }).collect()
+}
+
+impl DSAPI {
+    /// The members of a class as a Python list of `(name, offset, size)` tuples.
+    /// Bitfields sharing a byte are merged into one member named after the first bit.
+    fn script_members(&self, class_name: &str) -> String {
+        let mut members = Vec::new();
+        let mut last_offset = None;
+        for (member, info) in self.class_members(class_name) {
+            if last_offset == Some(info.offset) {
+                continue;
+            }
+            last_offset = Some(info.offset);
+            members.push(format!("(\"{}\", 0x{:x}, {})", identifier(member), info.offset, info.size.max(1)));
+        }
+        format!("[{}]", members.join(", "))
+    }
+    /// All functions as `(label, offset)`, sorted by label.
+    fn script_functions(&self) -> Vec<(String, u64)> {
+        let mut functions: Vec<_> = self.functions()
+            .map(|(class, function, info)| (format!("{}_{}", identifier(class), identifier(function)), info.offset))
+            .collect();
+        functions.sort();
+        functions
+    }
+    /// Generates an IDAPython script that annotates a database of the game binary:
+    /// every global offset becomes a label, every function is named `Class_Function`, and a struct
+    /// is created for each of `classes` (replacing structs with the same name).
+    ///
+    /// Bitfields share a byte in the struct, named after the first bit. Unknown classes are skipped.
+    /// Run the script with File > Script file... after the initial auto-analysis.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("annotate.py", dsapi.export_ida_script(&["UWorld", "AActor"])).unwrap();
+    /// ```
+    pub fn export_ida_script(&self, classes: &[&str]) -> String {
+        let mut script = String::from(IDA_PRELUDE);
+        script.push('\n');
+        for (name, offset) in self.sorted_offsets() {
+            writeln!(script, "ds_label(0x{:x}, \"{}\")", offset, identifier(name)).unwrap();
+        }
+        for (label, offset) in self.script_functions() {
+            writeln!(script, "ds_label(0x{:x}, \"{}\")", offset, label).unwrap();
+        }
+        for class in classes {
+            if let Some(size) = self.get_class_size(class) {
+                writeln!(script, "ds_struct(\"{}\", 0x{:x}, {})", identifier(class), size, self.script_members(class)).unwrap();
+            }
+        }
+        script
+    }
+    /// Generates a Ghidra script (Python) that annotates a program of the game binary:
+    /// every global offset becomes a label, every function is created or renamed to `Class_Function`,
+    /// every enum becomes an enum data type, and a structure is created for each of `classes`.
+    /// Data types are put in the `/dumpspace` category, replacing existing ones with the same name.
+    ///
+    /// Bitfields share a byte in the structure, named after the first bit. Unknown classes are skipped.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// std::fs::write("annotate.py", dsapi.export_ghidra_script(&["UWorld", "AActor"])).unwrap();
+    /// ```
+    pub fn export_ghidra_script(&self, classes: &[&str]) -> String {
+        let mut script = String::from(GHIDRA_PRELUDE);
+        script.push('\n');
+        for (name, offset) in self.sorted_offsets() {
+            writeln!(script, "ds_label(0x{:x}, \"{}\")", offset, identifier(name)).unwrap();
+        }
+        for (label, offset) in self.script_functions() {
+            writeln!(script, "ds_function(0x{:x}, \"{}\")", offset, label).unwrap();
+        }
+        for (enum_name, values) in self.sorted_enums() {
+            let (min, max) = (values[0].0, values[values.len() - 1].0);
+            let size = match (min, max) {
+                (0.., ..=0xff) => 1,
+                (-0x8000_0000.., ..=0xffff_ffff) => 4,
+                _ => 8,
+            };
+            let values: Vec<String> = values.iter()
+                .map(|(value, value_name)| format!("(\"{}\", {})", identifier(value_name), value))
+                .collect();
+            writeln!(script, "ds_enum(\"{}\", {}, [{}])", identifier(enum_name), size, values.join(", ")).unwrap();
+        }
+        for class in classes {
+            if let Some(size) = self.get_class_size(class) {
+                writeln!(script, "ds_struct(\"{}\", 0x{:x}, {})", identifier(class), size, self.script_members(class)).unwrap();
+            }
+        }
+        script
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_ida_script() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let script = dsapi.export_ida_script(&["UWorld", "Missing"]);
+        assert!(script.starts_with("import idaapi"));
+        assert!(script.contains("ds_label(0x14942840, \"OFFSET_GWORLD\")"));
+        let world = script.lines().find(|line| line.starts_with("ds_struct(\"UWorld\"")).unwrap();
+        assert!(world.starts_with("ds_struct(\"UWorld\", 0x9e8, [(\"PersistentLevel\", 0x30, 8), "));
+        assert!(world.contains("(\"OwningGameInstance\", 0x228, 8), (\"bIsWorldInitialized\", 0x230, 1)"));
+        assert!(!script.contains("Missing"));
+        assert_eq!(identifier("Foo<Bar> Baz"), "Foo_Bar__Baz");
+    }
+
+    #[test]
+    fn test_export_ghidra_script() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let script = dsapi.export_ghidra_script(&["UWorld"]);
+        assert!(script.contains("ds_label(0x14942840, \"OFFSET_GWORLD\")"));
+        assert!(script.contains("ds_struct(\"UWorld\", 0x9e8, [(\"PersistentLevel\", 0x30, 8), "));
+        let rarity = script.lines().find(|line| line.starts_with("ds_enum(\"EFortRarity\"")).unwrap();
+        assert!(rarity.starts_with("ds_enum(\"EFortRarity\", 1, [(\"EFortRarity__Common\", 0), "));
+    }
+}
@@ -0,0 +1,169 @@
+//! A caching mirror of the dumpspace server, enabled with the `serve` feature.
+
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{DownloadOptions, GameList, unix_secs};
+
+const GAME_LIST_PATH: &str = "Games/GameList.json";
+
+/// An HTTP server with the same URL structure as dumpspace (`/Games/GameList.json`,
+/// `/Games/<engine>/<location>/<blob>.json.gz`, or `.json` for uncompressed blobs) that answers from a directory on disk and only goes
+/// upstream when something is missing or outdated. Point several tools or developers at it with
+/// [`DSAPIBuilder::base_url`](crate::DSAPIBuilder::base_url) and the blobs are downloaded once.
+///
+/// The game list is refreshed when it is older than the builder's `game_list_ttl` (5 minutes by default),
+/// blobs when the game list says their game was uploaded after they were downloaded.
+/// Adding `?refresh` to a URL fetches it upstream regardless. If upstream is unreachable, the cached
+/// copy is served even if it is outdated.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::DSAPI;
+/// let server = DSAPI::builder().cache_dir("dumpspace_mirror").build_server().unwrap();
+/// server.serve("0.0.0.0:8080").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct CacheServer {
+    cache_dir: PathBuf,
+    options: DownloadOptions,
+    game_list_ttl: Duration,
+}
+
+impl CacheServer {
+    pub(crate) fn new(cache_dir: PathBuf, options: DownloadOptions, game_list_ttl: Option<Duration>) -> Self {
+        CacheServer { cache_dir, options, game_list_ttl: game_list_ttl.unwrap_or(Duration::from_secs(300)) }
+    }
+    /// Listens on `addr` (e.g. `"127.0.0.1:8080"`) and answers requests one at a time until the process exits.
+    pub fn serve(&self, addr: &str) -> Result<(), String> {
+        let server = tiny_http::Server::http(addr).map_err(|e| format!("Failed to listen on {}: {}", addr, e))?;
+        for request in server.incoming_requests() {
+            let response = match self.fetch(request.url()) {
+                Ok(bytes) => {
+                    let content_type = if request.url().contains(".gz") { "application/gzip" } else { "application/json" };
+                    let header = tiny_http::Header::from_bytes("Content-Type", content_type).unwrap();
+                    tiny_http::Response::from_data(bytes).with_header(header)
+                }
+                Err((status, error)) => tiny_http::Response::from_data(error.into_bytes()).with_status_code(status),
+            };
+            // The client hung up, nothing to do about it.
+            let _ = request.respond(response);
+        }
+        Ok(())
+    }
+    /// Returns the file at `url` from the cache, downloading it first if needed.
+    /// Errors carry the HTTP status to answer with.
+    pub(crate) fn fetch(&self, url: &str) -> Result<Vec<u8>, (u16, String)> {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let path = path.trim_start_matches('/');
+        let file = self.cache_file(path).ok_or_else(|| (404, format!("Not found: /{}", path)))?;
+        let refresh = query.split('&').any(|param| param == "refresh");
+        let fresh = !refresh && match path {
+            GAME_LIST_PATH => modified_secs(&file).is_some_and(|modified| now_secs().saturating_sub(modified) < self.game_list_ttl.as_secs()),
+            _ => self.blob_is_current(path, &file),
+        };
+        if let (true, Ok(bytes)) = (fresh, std::fs::read(&file)) {
+            return Ok(bytes);
+        }
+        match self.download(path, &file) {
+            Ok(bytes) => Ok(bytes),
+            Err(error) => std::fs::read(&file).map_err(|_| (502, error)),
+        }
+    }
+    /// Where `path` is cached, or `None` if it isn't a dumpspace file.
+    fn cache_file(&self, path: &str) -> Option<PathBuf> {
+        let relative = Path::new(path);
+        let valid = path.starts_with("Games/")
+            && (path.ends_with(".json") || path.ends_with(".json.gz"))
+            && relative.components().all(|component| matches!(component, Component::Normal(_)));
+        valid.then(|| self.cache_dir.join(relative))
+    }
+    /// Whether the cached blob was downloaded after its game was last uploaded.
+    fn blob_is_current(&self, path: &str, file: &Path) -> bool {
+        let Some(modified) = modified_secs(file) else {
+            return false;
+        };
+        let game_list = self.fetch(GAME_LIST_PATH).ok()
+            .and_then(|bytes| serde_json::from_slice::<GameList>(&bytes).ok());
+        let mut segments = path.split('/').skip(1);
+        let (engine, location) = (segments.next().unwrap_or_default(), segments.next().unwrap_or_default());
+        let uploaded = game_list.as_ref()
+            .and_then(|list| list.games.iter().find(|game| game.engine == engine && game.location == location))
+            .map(|game| unix_secs(game.uploaded));
+        uploaded.is_none_or(|uploaded| modified >= uploaded)
+    }
+    /// Downloads `path` from upstream into `file`.
+    fn download(&self, path: &str, file: &Path) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        self.options.get(&format!("{}/{}", self.options.base_url, path))?
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+        // Written next to the target first, so a concurrent reader never sees half a file.
+        let partial = file.with_extension("partial");
+        std::fs::create_dir_all(file.parent().unwrap()).map_err(|e| format!("Failed to create cache directory: {}", e))?;
+        std::fs::write(&partial, &bytes).map_err(|e| format!("Failed to write cache file: {}", e))?;
+        std::fs::rename(&partial, file).map_err(|e| format!("Failed to write cache file: {}", e))?;
+        Ok(bytes)
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+fn modified_secs(file: &Path) -> Option<u64> {
+    let modified = std::fs::metadata(file).and_then(|metadata| metadata.modified()).ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Serves `files` on a random local port, counting the requests. Returns the base URL.
+    fn upstream(files: Vec<(&'static str, Vec<u8>)>, hits: Arc<AtomicUsize>) -> String {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                hits.fetch_add(1, Ordering::SeqCst);
+                let response = match files.iter().find(|(path, _)| *path == request.url()) {
+                    Some((_, bytes)) => tiny_http::Response::from_data(bytes.clone()),
+                    None => tiny_http::Response::from_data(Vec::new()).with_status_code(404),
+                };
+                let _ = request.respond(response);
+            }
+        });
+        format!("http://127.0.0.1:{}", port)
+    }
+
+    #[test]
+    fn test_cache_server() {
+        let game_list = br#"{"games":[{"hash":"h","name":"Game","engine":"UE5","location":"Game","uploaded":1,"uploader":{"name":"","link":""}}]}"#;
+        let hits = Arc::new(AtomicUsize::new(0));
+        let base_url = upstream(vec![
+            ("/Games/GameList.json", game_list.to_vec()),
+            ("/Games/UE5/Game/OffsetsInfo.json.gz", b"offsets".to_vec()),
+        ], hits.clone());
+        let cache_dir = PathBuf::from("temp/test_cache_server");
+        let _ = std::fs::remove_dir_all(&cache_dir);
+        let options = DownloadOptions { base_url, ..Default::default() };
+        let server = CacheServer::new(cache_dir.clone(), options, None);
+
+        assert_eq!(server.fetch("/Games/UE5/Game/OffsetsInfo.json.gz").unwrap(), b"offsets");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        // Checking whether the blob is current fetches the game list once.
+        assert_eq!(server.fetch("/Games/UE5/Game/OffsetsInfo.json.gz").unwrap(), b"offsets");
+        assert_eq!(server.fetch("/Games/GameList.json").unwrap(), game_list);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(server.fetch("/Games/GameList.json?refresh").unwrap(), game_list);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+        assert_eq!(server.fetch("/Games/../secret.json.gz").unwrap_err().0, 404);
+        assert_eq!(server.fetch("/index.html").unwrap_err().0, 404);
+        assert_eq!(server.fetch("/Games/UE5/Game/EnumsInfo.json.gz").unwrap_err().0, 502);
+        std::fs::remove_dir_all(&cache_dir).expect("Failed to clean up cache directory");
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+#[cfg(network)]
+use std::time::Duration;
+
+use arc_swap::ArcSwap;
+
+use crate::{DSAPI, OffsetInfo};
+#[cfg(network)]
+use crate::{DumpDiff, Watcher};
+
+/// A cheaply clonable handle to a `DSAPI` that can be shared between threads.
+///
+/// Lookups are lock-free: they read the current dump through an atomic pointer, while
+/// [`SharedDsApi::refresh`] or [`SharedDsApi::store`] swap in a new dump. Readers that are in the
+/// middle of a lookup keep using the old dump until they are done.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, SharedDsApi};
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// let shared = SharedDsApi::new(dsapi);
+/// let reader = shared.clone();
+/// std::thread::spawn(move || println!("{:?}", reader.get_member_offset("UWorld", "OwningGameInstance")));
+/// shared.refresh().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedDsApi {
+    inner: Arc<ArcSwap<DSAPI>>,
+}
+
+impl SharedDsApi {
+    pub fn new(dsapi: DSAPI) -> Self {
+        SharedDsApi { inner: Arc::new(ArcSwap::from_pointee(dsapi)) }
+    }
+    /// Returns the current dump. The returned `Arc` stays valid even if a newer dump is swapped in.
+    pub fn load(&self) -> Arc<DSAPI> {
+        self.inner.load_full()
+    }
+    /// Replaces the current dump for all handles.
+    pub fn store(&self, dsapi: DSAPI) {
+        self.inner.store(Arc::new(dsapi));
+    }
+    /// Like [`DSAPI::refresh`], but readers are never blocked while the new dump is downloaded and swapped in.
+    #[cfg(network)]
+    pub fn refresh(&self) -> Result<bool, String> {
+        match self.inner.load().fetch_update()? {
+            Some(refreshed) => {
+                self.store(refreshed);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+    /// Starts a background thread that refreshes this handle, see [`DSAPI::watch`].
+    #[cfg(network)]
+    pub fn watch<F>(&self, interval: Duration, callback: F) -> Watcher
+    where
+        F: FnMut(&DSAPI, &DumpDiff) + Send + 'static,
+    {
+        Watcher::spawn(self.clone(), interval, callback)
+    }
+
+    /// Returns the offset info for a class member as an `Option<OffsetInfo>`.
+    pub fn get_member_offset(&self, class_name: &str, member_name: &str) -> Option<OffsetInfo> {
+        self.inner.load().get_member_offset(class_name, member_name)
+    }
+    /// Returns the size of a class as an `Option<i32>`.
+    pub fn get_class_size(&self, class_name: &str) -> Option<i32> {
+        self.inner.load().get_class_size(class_name)
+    }
+    /// Returns the name of an enum value as an `Option<String>`.
+    pub fn get_enum_name(&self, enum_name: &str, enum_value: i64) -> Option<String> {
+        self.inner.load().get_enum_name(enum_name, enum_value)
+    }
+    /// Returns the offset of a specific offset name as an `Option<u64>`.
+    pub fn get_offset(&self, offset_name: &str) -> Option<u64> {
+        self.inner.load().get_offset(offset_name)
+    }
+    /// Returns the first of several spellings of a global offset that exists, see [`DSAPI::get_offset_any`].
+    pub fn get_offset_any(&self, offset_names: &[&str]) -> Option<u64> {
+        self.inner.load().get_offset_any(offset_names)
+    }
+}
+
+impl From<DSAPI> for SharedDsApi {
+    fn from(dsapi: DSAPI) -> Self {
+        SharedDsApi::new(dsapi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_swap() {
+        let shared = SharedDsApi::new(crate::tests::fixture_dsapi());
+        let old = shared.load();
+        let readers: Vec<_> = (0..4).map(|_| {
+            let shared = shared.clone();
+            std::thread::spawn(move || shared.get_member_offset("UWorld", "OwningGameInstance").unwrap().offset)
+        }).collect();
+        for reader in readers {
+            assert_eq!(reader.join().unwrap(), 0x228);
+        }
+
+        let mut updated = crate::tests::fixture_dsapi();
+        updated.offset_map.insert("OFFSET_GWORLD".to_string(), 0x1000);
+        shared.store(updated);
+        assert_eq!(shared.get_offset("OFFSET_GWORLD"), Some(0x1000));
+        assert_eq!(old.get_offset("OFFSET_GWORLD"), Some(0x14942840));
+    }
+}
@@ -0,0 +1,143 @@
+//! Byte-pattern signatures that find global offsets in the game's module when the dump lacks them.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{DSAPI, MemoryReader};
+
+/// Bytes read from the module at once while scanning.
+const SCAN_CHUNK: usize = 0x10000;
+
+type Resolver = Arc<dyn Fn(u64, &[u8]) -> Option<u64> + Send + Sync>;
+
+/// A parsed pattern like `48 8B 1D ?? ?? ?? ??`, `None` for wildcards, and how to turn a match into an address.
+#[derive(Clone)]
+struct Signature {
+    pattern: Vec<Option<u8>>,
+    resolver: Resolver,
+}
+
+/// The signatures of a `DSAPI` by offset name.
+#[derive(Clone, Default)]
+pub(crate) struct Signatures(HashMap<String, Signature>);
+
+impl std::fmt::Debug for Signatures {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} signatures", self.0.len())
+    }
+}
+
+/// Parses space-separated hex bytes, with `?` or `??` as wildcards.
+fn parse_pattern(pattern: &str) -> Result<Vec<Option<u8>>, String> {
+    let bytes: Vec<_> = pattern.split_whitespace()
+        .map(|byte| match byte {
+            "?" | "??" => Ok(None),
+            _ => u8::from_str_radix(byte, 16).map(Some).map_err(|_| format!("Invalid byte in signature: {}", byte)),
+        })
+        .collect::<Result<_, _>>()?;
+    if bytes.first().is_none_or(Option::is_none) {
+        return Err("Signatures must start with a byte that is not a wildcard".to_string());
+    }
+    Ok(bytes)
+}
+
+/// Returns the positions in `haystack` where `pattern` matches.
+fn find_all<'a>(haystack: &'a [u8], pattern: &'a [Option<u8>]) -> impl Iterator<Item = usize> + 'a {
+    haystack.windows(pattern.len())
+        .enumerate()
+        .filter(move |(_, window)| window.iter().zip(pattern).all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected)))
+        .map(|(position, _)| position)
+}
+
+/// A resolver for [`DSAPI::register_signature`] that follows the 32-bit RIP-relative displacement
+/// `displacement` bytes into the match, of an instruction that is `instruction_len` bytes long.
+/// `rip_relative(3, 7)` resolves `mov rbx, [rip + disp32]` (`48 8B 1D ?? ?? ?? ??`) to the address it loads.
+pub fn rip_relative(displacement: usize, instruction_len: u64) -> impl Fn(u64, &[u8]) -> Option<u64> + Send + Sync + 'static {
+    move |address, bytes| {
+        let displacement = i32::from_le_bytes(bytes.get(displacement..displacement + 4)?.try_into().ok()?);
+        Some(address.wrapping_add(instruction_len).wrapping_add_signed(displacement.into()))
+    }
+}
+
+impl DSAPI {
+    /// Registers a byte pattern (hex bytes, `??` for wildcards) that locates a global offset in the game's module,
+    /// for [`DSAPI::scan_offset`] and [`DSAPI::get_offset_or_scan`]. The resolver gets the address of a match
+    /// and the matched bytes and returns the absolute address of the global, see [`rip_relative`].
+    /// Replaces an earlier signature for the same offset. Signatures are kept across `refresh`.
+    /// # Example:
+    /// ```no_run
+    /// # use dumpspace_api::{DSAPI, MemoryReader, rip_relative};
+    /// # fn example(dsapi: &mut DSAPI, reader: &impl MemoryReader, module_size: usize) {
+    /// dsapi.register_signature("OFFSET_GWORLD", "48 8B 1D ?? ?? ?? ?? 48 85 DB 74", rip_relative(3, 7)).unwrap();
+    /// // Uses the dump's offset if it has one, and scans the module otherwise.
+    /// let gworld = dsapi.get_offset_or_scan("OFFSET_GWORLD", reader, module_size);
+    /// # }
+    /// ```
+    pub fn register_signature(
+        &mut self,
+        offset_name: &str,
+        pattern: &str,
+        resolver: impl Fn(u64, &[u8]) -> Option<u64> + Send + Sync + 'static,
+    ) -> Result<(), String> {
+        let signature = Signature { pattern: parse_pattern(pattern)?, resolver: Arc::new(resolver) };
+        self.signatures.0.insert(offset_name.to_string(), signature);
+        Ok(())
+    }
+    /// Scans the first `module_size` bytes of the module at [`DSAPI::module_base`] for the signature of
+    /// `offset_name` and returns the offset relative to the module base, even if the dump has the offset too,
+    /// e.g. to check a suspicious dump. Fails without a signature, a module base or a match.
+    /// Unreadable parts of the module are skipped.
+    pub fn scan_offset(&self, offset_name: &str, reader: &impl MemoryReader, module_size: usize) -> Result<u64, String> {
+        let signature = self.signatures.0.get(offset_name).ok_or_else(|| format!("No signature registered for {}", offset_name))?;
+        let module_base = self.module_base.ok_or("No module base set")?;
+        let overlap = signature.pattern.len() - 1;
+        let mut chunk = vec![0; SCAN_CHUNK + overlap];
+        for start in (0..module_size).step_by(SCAN_CHUNK) {
+            let len = (SCAN_CHUNK + overlap).min(module_size - start);
+            let address = module_base + start as u64;
+            if reader.read(address, &mut chunk[..len]).is_err() {
+                continue;
+            }
+            for position in find_all(&chunk[..len], &signature.pattern) {
+                let bytes = &chunk[position..position + signature.pattern.len()];
+                if let Some(resolved) = (signature.resolver)(address + position as u64, bytes) {
+                    return Ok(resolved.wrapping_sub(module_base));
+                }
+            }
+        }
+        Err(format!("Signature of {} not found", offset_name))
+    }
+    /// Returns the offset of the dump like [`DSAPI::get_offset`], or scans for it with its registered signature
+    /// if the dump doesn't have it. Returns `None` if neither finds it.
+    pub fn get_offset_or_scan(&self, offset_name: &str, reader: &impl MemoryReader, module_size: usize) -> Option<u64> {
+        self.get_offset(offset_name).or_else(|| self.scan_offset(offset_name, reader, module_size).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::tests::Buffer;
+
+    #[test]
+    fn test_scan_offset() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        let module_base = 0x7ff0_0000_0000;
+        dsapi.set_module_base(module_base);
+        let mut memory = Buffer { base: module_base, bytes: vec![0; 2 * SCAN_CHUNK] };
+        // `mov rbx, [rip + 0x1000]` at the end of the first chunk, so the match straddles two reads.
+        let instruction = module_base + SCAN_CHUNK as u64 - 3;
+        memory.write(instruction, [0x48_u8, 0x8B, 0x1D]);
+        memory.write(instruction + 3, 0x1000_i32);
+        let module_size = memory.bytes.len();
+
+        assert!(dsapi.register_signature("OFFSET_GOBJECTS", "?? 8B", rip_relative(3, 7)).is_err());
+        assert!(dsapi.register_signature("OFFSET_GOBJECTS", "48 XX", rip_relative(3, 7)).is_err());
+        dsapi.register_signature("OFFSET_MISSING", "48 8B 1D ?? ?? ?? ??", rip_relative(3, 7)).unwrap();
+        let expected = SCAN_CHUNK as u64 - 3 + 7 + 0x1000;
+        assert_eq!(dsapi.scan_offset("OFFSET_MISSING", &memory, module_size), Ok(expected));
+        assert_eq!(dsapi.get_offset_or_scan("OFFSET_MISSING", &memory, module_size), Some(expected));
+        assert_eq!(dsapi.get_offset_or_scan("OFFSET_GWORLD", &memory, module_size), Some(0x14942840));
+        assert!(dsapi.scan_offset("OFFSET_GWORLD", &memory, module_size).is_err());
+    }
+}
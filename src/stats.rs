@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(network)]
+use crate::counting::BlobTransfer;
+use crate::{BlobKind, DSAPI};
+
+/// Counts and timings of the parsed dump, see [`DSAPI::stats`].
+#[derive(Debug, Clone, Default)]
+pub struct DumpStats {
+    pub classes: usize,
+    pub structs: usize,
+    /// Members of all classes and structs.
+    pub members: usize,
+    pub enums: usize,
+    pub enum_values: usize,
+    pub offsets: usize,
+    /// Compressed bytes of all blobs.
+    pub bytes_downloaded: u64,
+    /// Per-blob numbers, in download order.
+    pub blobs: Vec<BlobStats>,
+}
+
+/// Size and timings of one downloaded blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlobStats {
+    pub blob: BlobKind,
+    /// Classes, structs, enums or offsets in the blob.
+    pub entries: usize,
+    pub compressed_bytes: u64,
+    pub decompressed_bytes: u64,
+    /// Time spent downloading, decompressing and deserializing the blob.
+    pub download_time: Duration,
+    /// Time spent converting the blob into the lookup maps.
+    pub parse_time: Duration,
+}
+
+impl DSAPI {
+    /// Returns counts and timings of the dump, e.g. to catch a truncated blob by a sudden drop in counts.
+    /// Blob numbers are kept when the dump is restored from the cache.
+    pub fn stats(&self) -> DumpStats {
+        let entries = |blob: BlobKind| self.blob_stats.iter().find(|stats| stats.blob == blob).map_or(0, |stats| stats.entries);
+        DumpStats {
+            classes: entries(BlobKind::Classes),
+            structs: entries(BlobKind::Structs),
+            members: self.class_member_map.len(),
+            enums: entries(BlobKind::Enums),
+            enum_values: self.enum_name_map.len(),
+            offsets: self.offset_map.len(),
+            bytes_downloaded: self.blob_stats.iter().map(|stats| stats.compressed_bytes).sum(),
+            blobs: self.blob_stats.clone(),
+        }
+    }
+    #[cfg(network)]
+    pub(crate) fn record_blob_stats(&mut self, blob: BlobKind, entries: usize, transfer: &BlobTransfer, parse_time: Duration) {
+        self.blob_stats.retain(|stats| stats.blob != blob);
+        self.blob_stats.push(BlobStats {
+            blob,
+            entries,
+            compressed_bytes: transfer.compressed_bytes,
+            decompressed_bytes: transfer.decompressed_bytes,
+            download_time: transfer.request_time + transfer.decode_time,
+            parse_time,
+        });
+    }
+}
+
+#[cfg(all(test, network))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        let transfer = BlobTransfer { compressed_bytes: 100, decompressed_bytes: 400, ..Default::default() };
+        dsapi.record_blob_stats(BlobKind::Classes, 3, &transfer, Duration::from_millis(2));
+        dsapi.record_blob_stats(BlobKind::Structs, 1, &transfer, Duration::from_millis(1));
+        let stats = dsapi.stats();
+        assert_eq!((stats.classes, stats.structs, stats.members), (3, 1, 11));
+        assert_eq!((stats.enum_values, stats.offsets), (13, 3));
+        assert_eq!(stats.bytes_downloaded, 200);
+        assert_eq!(stats.blobs[1].blob, BlobKind::Structs);
+    }
+}
@@ -0,0 +1,24 @@
+//! Instrumentation macros that compile to nothing without the `tracing` feature.
+
+/// Enters an info-level span until the returned guard is dropped.
+macro_rules! trace_span {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        let guard = tracing::info_span!($($arg)*).entered();
+        #[cfg(not(feature = "tracing"))]
+        let guard = $crate::trace::NoSpan;
+        guard
+    }};
+}
+
+/// Emits a debug-level event.
+macro_rules! trace_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    };
+}
+
+/// Stand-in for an entered span when the `tracing` feature is disabled.
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct NoSpan;
@@ -0,0 +1,248 @@
+//! Interactive terminal browser for a parsed dump, enabled with the `tui` feature.
+
+use std::collections::BTreeMap;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, List, ListState, Paragraph, Tabs};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::DSAPI;
+
+const TAB_TITLES: [&str; 3] = ["Classes", "Enums", "Offsets"];
+const CLASSES: usize = 0;
+const ENUMS: usize = 1;
+const HELP: &str = "q quit  / search  Tab switch  Up/Down select  PgUp/PgDn page  J/K scroll members";
+
+/// State of the browser: the entries of every tab, the search and the selection.
+struct Browser<'a> {
+    dsapi: &'a DSAPI,
+    enums: BTreeMap<&'a str, Vec<(i64, &'a str)>>,
+    /// `(name, summary)` of every entry, per tab.
+    entries: [Vec<(&'a str, String)>; 3],
+    tab: usize,
+    query: String,
+    searching: bool,
+    /// Indices into the current tab's entries that match the query.
+    visible: Vec<usize>,
+    list: ListState,
+    detail: Vec<String>,
+    scroll: u16,
+    quit: bool,
+}
+
+impl<'a> Browser<'a> {
+    fn new(dsapi: &'a DSAPI) -> Self {
+        let enums = dsapi.sorted_enums();
+        let classes = dsapi.class_names().into_iter()
+            .map(|class| (class, dsapi.get_class_size(class).map(|size| format!("0x{:x}", size)).unwrap_or_default()))
+            .collect();
+        let enum_entries = enums.iter().map(|(name, values)| (*name, format!("{} values", values.len()))).collect();
+        let offsets = dsapi.sorted_offsets().into_iter().map(|(name, offset)| (name, format!("0x{:x}", offset))).collect();
+        let mut browser = Browser {
+            dsapi,
+            enums,
+            entries: [classes, enum_entries, offsets],
+            tab: CLASSES,
+            query: String::new(),
+            searching: false,
+            visible: Vec::new(),
+            list: ListState::default(),
+            detail: Vec::new(),
+            scroll: 0,
+            quit: false,
+        };
+        browser.filter();
+        browser
+    }
+    /// Recomputes the visible entries after the tab or the query changed.
+    fn filter(&mut self) {
+        let query = self.query.to_lowercase();
+        self.visible = self.entries[self.tab].iter().enumerate()
+            .filter(|(_, (name, _))| name.to_lowercase().contains(&query))
+            .map(|(index, _)| index)
+            .collect();
+        self.select(0);
+    }
+    fn selected(&self) -> Option<&'a str> {
+        let index = *self.visible.get(self.list.selected()?)?;
+        Some(self.entries[self.tab][index].0)
+    }
+    /// Selects the visible entry at `position` (clamped) and shows its details.
+    fn select(&mut self, position: usize) {
+        let position = (!self.visible.is_empty()).then(|| position.min(self.visible.len() - 1));
+        self.list.select(position);
+        self.scroll = 0;
+        self.detail = match self.selected() {
+            Some(name) => self.details(name),
+            None => Vec::new(),
+        };
+    }
+    fn details(&self, name: &str) -> Vec<String> {
+        match self.tab {
+            CLASSES => {
+                let mut lines = Vec::new();
+                if let Some(size) = self.dsapi.get_class_size(name) {
+                    lines.push(format!("Size: 0x{:x}", size));
+                }
+                if let Some(supers) = self.dsapi.get_super_classes(name).filter(|supers| !supers.is_empty()) {
+                    lines.push(format!("Inherits: {}", supers.join(" > ")));
+                }
+                lines.push(String::new());
+                for (member, info) in self.dsapi.class_members(name) {
+                    let offset = match info.is_bit {
+                        true => format!("0x{:04x}:{}", info.offset, info.bit_offset),
+                        false => format!("0x{:04x}", info.offset),
+                    };
+                    let ty = self.dsapi.get_member_type(name, member).map(|ty| ty.to_string()).unwrap_or_default();
+                    lines.push(format!("{:<10} {:<40} {} ({})", offset, member, ty, info.size));
+                }
+                lines
+            }
+            ENUMS => self.enums.get(name).into_iter().flatten()
+                .map(|(value, value_name)| format!("{:>6}  {}", value, value_name))
+                .collect(),
+            _ => {
+                let offset = self.dsapi.get_offset(name).unwrap_or_default();
+                let mut lines = vec![format!("Offset: 0x{:x}", offset)];
+                if let Some(address) = self.dsapi.get_offset_rebased(name) {
+                    lines.push(format!("Address: 0x{:x}", address));
+                }
+                lines
+            }
+        }
+    }
+    fn handle_key(&mut self, code: KeyCode) {
+        if self.searching {
+            match code {
+                KeyCode::Char(c) => self.query.push(c),
+                KeyCode::Backspace => {
+                    self.query.pop();
+                }
+                KeyCode::Esc => {
+                    self.query.clear();
+                    self.searching = false;
+                }
+                KeyCode::Enter => self.searching = false,
+                _ => return,
+            }
+            self.filter();
+            return;
+        }
+        let position = self.list.selected().unwrap_or_default();
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('/') => self.searching = true,
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.tab = match code {
+                    KeyCode::Tab => (self.tab + 1) % TAB_TITLES.len(),
+                    _ => (self.tab + TAB_TITLES.len() - 1) % TAB_TITLES.len(),
+                };
+                self.filter();
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.select(position + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.select(position.saturating_sub(1)),
+            KeyCode::PageDown => self.select(position + 20),
+            KeyCode::PageUp => self.select(position.saturating_sub(20)),
+            KeyCode::Home => self.select(0),
+            KeyCode::End => self.select(usize::MAX),
+            KeyCode::Char('J') => self.scroll = self.scroll.saturating_add(1).min(self.detail.len() as u16),
+            KeyCode::Char('K') => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+    }
+    fn draw(&mut self, frame: &mut Frame) {
+        let [tabs, body, status] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1), Constraint::Length(1)])
+            .areas(frame.area());
+        let [list, detail] = Layout::horizontal([Constraint::Percentage(35), Constraint::Fill(1)]).areas(body);
+        let highlight = Style::default().add_modifier(Modifier::REVERSED);
+
+        frame.render_widget(Tabs::new(TAB_TITLES).select(self.tab).highlight_style(highlight), tabs);
+
+        let entries = &self.entries[self.tab];
+        let items: Vec<String> = self.visible.iter()
+            .map(|index| format!("{}  {}", entries[*index].0, entries[*index].1))
+            .collect();
+        let title = format!("{} ({}/{})", TAB_TITLES[self.tab], self.visible.len(), entries.len());
+        let widget = List::new(items).block(Block::bordered().title(title)).highlight_style(highlight);
+        frame.render_stateful_widget(widget, list, &mut self.list);
+
+        let title = self.selected().unwrap_or_default().to_string();
+        let widget = Paragraph::new(self.detail.join("\n")).block(Block::bordered().title(title)).scroll((self.scroll, 0));
+        frame.render_widget(widget, detail);
+
+        let line = match (self.searching, self.query.is_empty()) {
+            (true, _) => format!("/{}", self.query),
+            (false, true) => HELP.to_string(),
+            (false, false) => format!("{}  |  filter: {}", HELP, self.query),
+        };
+        frame.render_widget(Paragraph::new(line), status);
+    }
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), String> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame)).map_err(|e| format!("Failed to draw: {}", e))?;
+            match event::read().map_err(|e| format!("Failed to read input: {}", e))? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key(key.code),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DSAPI {
+    /// Opens an interactive browser in the terminal: classes with their members, offsets and types,
+    /// enums with their values, and the global offsets, all searchable. Returns when the user quits with `q`.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// dsapi.browse().unwrap();
+    /// ```
+    pub fn browse(&self) -> Result<(), String> {
+        let mut terminal = ratatui::init();
+        let result = Browser::new(self).run(&mut terminal);
+        ratatui::restore();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn render(browser: &mut Browser) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal.draw(|frame| browser.draw(frame)).unwrap();
+        terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_browser() {
+        let dsapi = crate::tests::fixture_dsapi();
+        let mut browser = Browser::new(&dsapi);
+        for code in [KeyCode::Char('/'), KeyCode::Char('w'), KeyCode::Char('O'), KeyCode::Char('r'), KeyCode::Enter] {
+            browser.handle_key(code);
+        }
+        assert_eq!(browser.selected(), Some("UWorld"));
+        assert!(browser.detail.iter().any(|line| line.starts_with("0x0228     OwningGameInstance")));
+        assert!(browser.detail.iter().any(|line| line.starts_with("0x0230:2")));
+        assert!(render(&mut browser).contains("Size: 0x9e8"));
+
+        browser.handle_key(KeyCode::Tab);
+        assert_eq!(browser.selected(), None);
+        browser.handle_key(KeyCode::Char('/'));
+        browser.handle_key(KeyCode::Esc);
+        browser.handle_key(KeyCode::End);
+        assert_eq!(browser.selected(), browser.enums.keys().last().copied());
+        browser.handle_key(KeyCode::BackTab);
+        browser.handle_key(KeyCode::BackTab);
+        assert!(render(&mut browser).contains("OFFSET_GWORLD  0x14942840"));
+        browser.handle_key(KeyCode::Char('q'));
+        assert!(browser.quit);
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::DSAPI;
+
+/// The type of a class or struct member as described in the blobs, e.g. `TArray<ULocalPlayer*>`.
+/// Blobs store it as `[name, kind, modifier, template arguments]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct MemberType {
+    /// The type name without template arguments, e.g. `TArray` or `int32`.
+    pub name: String,
+    /// `C` for classes, `S` for structs, `E` for enums and `D` for everything else.
+    pub kind: String,
+    /// Pointer or reference suffix, e.g. `*`. Empty for values.
+    pub modifier: String,
+    pub template_args: Vec<MemberType>,
+}
+
+impl MemberType {
+    /// Converts the type array of a member, returning `None` if it is malformed.
+    pub(crate) fn from_json(value: &serde_json::Value) -> Option<Self> {
+        let parts = value.as_array()?;
+        let text = |index: usize| parts.get(index).and_then(|part| part.as_str()).unwrap_or_default().to_string();
+        let name = parts.first()?.as_str()?.to_string();
+        let template_args = parts.get(3)
+            .and_then(|args| args.as_array())
+            .map(|args| args.iter().filter_map(MemberType::from_json).collect())
+            .unwrap_or_default();
+        Some(MemberType { name, kind: text(1), modifier: text(2), template_args })
+    }
+    pub fn is_pointer(&self) -> bool {
+        self.modifier.contains('*')
+    }
+    /// Calls `visit` for this type and all of its template arguments, recursively.
+    pub fn visit(&self, visit: &mut impl FnMut(&MemberType)) {
+        visit(self);
+        for arg in &self.template_args {
+            arg.visit(visit);
+        }
+    }
+}
+
+impl fmt::Display for MemberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.template_args.is_empty() {
+            f.write_str("<")?;
+            for (i, arg) in self.template_args.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}", arg)?;
+            }
+            f.write_str(">")?;
+        }
+        f.write_str(&self.modifier)
+    }
+}
+
+/// Nesting of structs in structs followed by [`DSAPI::type_layout`] before giving up.
+const MAX_STRUCT_DEPTH: usize = 16;
+
+/// Engine structs declared with more alignment than their members need, for SIMD math.
+const KNOWN_ALIGNMENTS: &[(&str, u64)] = &[("FQuat", 16), ("FTransform", 16), ("FVector4", 16), ("FPlane", 16), ("FMatrix", 16)];
+
+impl DSAPI {
+    /// The size and alignment of a value of `ty` in the engine's memory, e.g. the elements of a container.
+    /// `None` for types whose size the dump doesn't tell, like structs it doesn't contain.
+    pub(crate) fn type_layout(&self, ty: &MemberType) -> Option<(u64, u64)> {
+        self.nested_type_layout(ty, 0)
+    }
+    fn nested_type_layout(&self, ty: &MemberType, depth: usize) -> Option<(u64, u64)> {
+        if ty.is_pointer() {
+            return Some((8, 8));
+        }
+        let layout = match ty.name.as_str() {
+            "bool" | "char" | "int8" | "uint8" => (1, 1),
+            "int16" | "uint16" => (2, 2),
+            "int32" | "uint32" | "int" | "float" => (4, 4),
+            "int64" | "uint64" | "double" => (8, 8),
+            "FName" | "TWeakObjectPtr" | "FWeakObjectPtr" => (8, 4),
+            "TObjectPtr" | "TSubclassOf" => (8, 8),
+            "TArray" | "FString" => (0x10, 8),
+            "FText" => (0x18, 8),
+            "TSet" | "TMap" => (0x50, 8),
+            // Most enums in reflected types are `uint8` enums or `TEnumAsByte`.
+            "TEnumAsByte" => (1, 1),
+            _ if ty.kind == "E" => (1, 1),
+            _ if depth < MAX_STRUCT_DEPTH => {
+                let size = *self.class_size_map.get(&self.class_sym(&ty.name)?)?;
+                let align = self.struct_alignment(&ty.name, depth + 1);
+                // Values of a struct are as large as its aligned size, the dump's size lacks the trailing padding.
+                (u64::try_from(size).ok()?.next_multiple_of(align), align)
+            }
+            _ => return None,
+        };
+        Some(layout)
+    }
+    /// The alignment of a struct: the largest alignment of its members and those of its super classes.
+    /// Members of unknown types count with the largest power of two up to 8 their size is a multiple of,
+    /// and so does the size of a struct without any members.
+    pub(crate) fn struct_alignment(&self, struct_name: &str, depth: usize) -> u64 {
+        if let Some((_, align)) = KNOWN_ALIGNMENTS.iter().find(|(name, _)| *name == struct_name) {
+            return *align;
+        }
+        let Some(class) = self.class_sym(struct_name) else {
+            return 1;
+        };
+        let supers = self.class_super_map.get(&class).map(Vec::as_slice).unwrap_or_default();
+        let members_align = std::iter::once(class).chain(supers.iter().copied())
+            .flat_map(|class| {
+                self.class_members(self.names.resolve(class)).into_iter().map(move |(member, info)| {
+                    let ty = self.names.get(member).and_then(|member| self.member_type_map.get(&(class, member)));
+                    match ty.and_then(|ty| self.nested_type_layout(ty, depth)) {
+                        Some((_, align)) => align,
+                        None => 1 << (info.size.max(1) as u64).trailing_zeros().min(3),
+                    }
+                })
+            })
+            .max();
+        members_align.unwrap_or_else(|| {
+            let size = self.class_size_map.get(&class).map_or(1, |size| (*size).max(1) as u64);
+            1 << size.trailing_zeros().min(3)
+        })
+    }
+    /// Returns the alignment of a class or struct in the engine's memory as an `Option<u64>`: the largest
+    /// alignment of its members, including inherited ones, or 16 for the SIMD math structs like `FQuat`.
+    /// Returns `None` if the class is not found.
+    /// # Example:
+    /// ```
+    /// use dumpspace_api::DsApiMock;
+    /// let dsapi = DsApiMock::new()
+    ///     .with_member("FTagEntry", "Id", 0, 8)
+    ///     .with_member("FTagEntry", "Flags", 8, 1)
+    ///     .with_class_size("FTagEntry", 9)
+    ///     .build();
+    /// assert_eq!(dsapi.get_alignment("FTagEntry"), Some(8));
+    /// assert_eq!(dsapi.get_aligned_size("FTagEntry"), Some(0x10));
+    /// ```
+    pub fn get_alignment(&self, class_name: &str) -> Option<u64> {
+        self.class_size_map.contains_key(&self.class_sym(class_name)?).then(|| self.struct_alignment(class_name, 0))
+    }
+    /// Returns the size of a class or struct rounded up to its [alignment](DSAPI::get_alignment) as an `Option<u64>`,
+    /// the distance between elements of an array of it. Dumps store the size without the trailing padding.
+    /// Returns `None` if the class is not found.
+    pub fn get_aligned_size(&self, class_name: &str) -> Option<u64> {
+        let size = u64::try_from(self.get_class_size(class_name)?).ok()?;
+        Some(size.next_multiple_of(self.get_alignment(class_name)?))
+    }
+    /// Returns the type of a class member as an `Option<&MemberType>`.
+    /// Returns `None` if the member is not found or the blob had no valid type for it.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let ty = dsapi.get_member_type("UWorld", "OwningGameInstance").unwrap();
+    /// assert_eq!(ty.to_string(), "UGameInstance*");
+    /// ```
+    pub fn get_member_type(&self, class_name: &str, member_name: &str) -> Option<&MemberType> {
+        self.member_type_map.get(&(self.names.get(class_name)?, self.names.get(member_name)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_member_type() {
+        let value = serde_json::json!(["TMap", "C", "", [["FName", "S", "", []], ["UObject", "C", "*", []]]]);
+        let ty = MemberType::from_json(&value).unwrap();
+        assert_eq!(ty.to_string(), "TMap<FName, UObject*>");
+        assert!(!ty.is_pointer() && ty.template_args[1].is_pointer());
+        let mut names = Vec::new();
+        ty.visit(&mut |ty| names.push(ty.name.clone()));
+        assert_eq!(names, ["TMap", "FName", "UObject"]);
+        assert_eq!(MemberType::from_json(&serde_json::json!(5)), None);
+
+        let dsapi = crate::tests::fixture_dsapi();
+        assert_eq!(dsapi.get_member_type("UWorld", "OwningGameInstance").unwrap().to_string(), "UGameInstance*");
+        assert_eq!(dsapi.get_member_type("UWorld", "bIsWorldInitialized").unwrap().name, "bool");
+        assert_eq!((dsapi.get_alignment("FVector"), dsapi.get_aligned_size("FVector")), (Some(8), Some(24)));
+        assert_eq!(dsapi.get_alignment("UWorld"), Some(8));
+        assert_eq!(dsapi.get_alignment("Missing"), None);
+        let padded = crate::DsApiMock::new()
+            .with_member("FEntry", "Value", 0, 4)
+            .with_member("FEntry", "Flag", 4, 1)
+            .with_class_size("FEntry", 5)
+            .with_super_classes("FChildEntry", &["FEntry"])
+            .with_class_size("FChildEntry", 5)
+            .with_class_size("FQuat", 0x20)
+            .build();
+        assert_eq!(padded.get_aligned_size("FEntry"), Some(8));
+        assert_eq!(padded.get_alignment("FChildEntry"), Some(4));
+        assert_eq!(padded.get_alignment("FQuat"), Some(16));
+    }
+}
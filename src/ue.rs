@@ -0,0 +1,422 @@
+//! Readers for common Unreal Engine containers, built on [`MemoryReader`].
+
+use crate::{DSAPI, MemoryReader, Pod};
+
+/// The header of a `TArray`: a pointer to the elements followed by the element count and capacity.
+/// Read it with [`read_tarray`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TArrayView {
+    pub data: u64,
+    pub num: i32,
+    pub max: i32,
+}
+
+/// Reads the `TArray` header at `address` and checks that it is sane
+/// (non-negative count within the capacity, and a data pointer if it is not empty).
+/// # Example:
+/// ```no_run
+/// # use dumpspace_api::{read_tarray, MemoryReader};
+/// # fn example(reader: &impl MemoryReader, actors_address: u64) -> Result<(), String> {
+/// let actors = read_tarray(reader, actors_address)?;
+/// for actor in actors.read_elements::<u64>(reader)? {
+///     println!("0x{:x}", actor);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_tarray(reader: &impl MemoryReader, address: u64) -> Result<TArrayView, String> {
+    let (data, num, max): (u64, i32, i32) = (
+        reader.read_value(address)?,
+        reader.read_value(address.wrapping_add(8))?,
+        reader.read_value(address.wrapping_add(12))?,
+    );
+    let array = TArrayView { data, num, max };
+    if num < 0 || max < 0 || num > max {
+        return Err(format!("Invalid TArray at 0x{:x}: Num {} Max {}", address, num, max));
+    }
+    if data == 0 && num > 0 {
+        return Err(format!("Invalid TArray at 0x{:x}: null data with {} elements", address, num));
+    }
+    Ok(array)
+}
+
+impl TArrayView {
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.num as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.num == 0
+    }
+    /// Address of the element at `index` for elements that are `stride` bytes apart.
+    /// Returns `None` if `index` is out of bounds or the address would overflow.
+    pub fn element_address(&self, index: usize, stride: u64) -> Option<u64> {
+        if index >= self.len() {
+            return None;
+        }
+        (index as u64).checked_mul(stride)?.checked_add(self.data)
+    }
+    /// Iterates over the addresses of all elements that are `stride` bytes apart.
+    /// Addresses past the end of the address space wrap around, and reading them fails.
+    pub fn element_addresses(&self, stride: u64) -> impl Iterator<Item = u64> {
+        let data = self.data;
+        (0..self.len() as u64).map(move |index| data.wrapping_add(index.wrapping_mul(stride)))
+    }
+    /// Reads the element at `index`, assuming the array holds `T`s.
+    pub fn read_element<T: Pod>(&self, reader: &impl MemoryReader, index: usize) -> Result<T, String> {
+        if index >= self.len() {
+            return Err(format!("TArray index {} out of bounds (Num {})", index, self.num));
+        }
+        let address = self.element_address(index, size_of::<T>() as u64)
+            .ok_or_else(|| format!("TArray element {} at 0x{:x} is out of the address space", index, self.data))?;
+        reader.read_value(address)
+    }
+    /// Reads all elements in one go, assuming the array holds `T`s.
+    /// Fails if the elements would reach past the end of the address space.
+    pub fn read_elements<T: Pod>(&self, reader: &impl MemoryReader) -> Result<Vec<T>, String> {
+        let bytes = (self.len() as u64).checked_mul(size_of::<T>() as u64);
+        if bytes.and_then(|bytes| self.data.checked_add(bytes)).is_none() {
+            return Err(format!("TArray at 0x{:x} with {} elements is out of the address space", self.data, self.num));
+        }
+        self.element_addresses(size_of::<T>() as u64)
+            .map(|address| reader.read_value(address))
+            .collect()
+    }
+}
+
+/// Offset of the allocation flags (a `TBitArray`) in a `TSparseArray`, after the `TArray` of elements.
+const ALLOCATION_FLAGS_OFFSET: u64 = 0x10;
+/// Words of allocation flags stored inline in the `TBitArray` before it moves them to the heap.
+const INLINE_FLAG_WORDS: u64 = 4;
+/// Most slots a [`TSetView`] may have. More almost always mean the address was wrong,
+/// and would make reading the allocation flags allocate megabytes.
+pub const MAX_TSET_SLOTS: i32 = 1 << 24;
+
+/// The sparse array behind a `TSet` or `TMap`: slots of elements, some of them free, and a bitmap
+/// of the allocated ones. Read it with [`read_tset`] or [`read_tmap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TSetView {
+    /// Address of the first slot.
+    pub data: u64,
+    /// Number of slots, allocated or free.
+    pub num: i32,
+    pub max: i32,
+    /// Address of the allocation bitmap, one bit per slot in 32-bit words.
+    pub allocation_flags: u64,
+    /// Number of free slots.
+    pub num_free: i32,
+}
+
+/// Where the parts of a `TSet` element or a `TMap` pair are in a slot of a [`TSetView`].
+/// Every slot also holds the hash chain of its element, so slots are larger than the elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetElementLayout {
+    /// Offset of a `TMap` pair's value from its key, 0 for `TSet`s.
+    pub value_offset: u64,
+    /// Distance between two slots.
+    pub stride: u64,
+}
+
+fn align_up(value: u64, align: u64) -> u64 {
+    value.div_ceil(align) * align
+}
+
+impl SetElementLayout {
+    /// The layout of a `TSet` of elements with the given size and alignment.
+    pub fn set(size: u64, align: u64) -> Self {
+        // `TSetElement` is the element followed by two `int32`s: the next element in the hash bucket and the bucket.
+        let align = align.max(4);
+        SetElementLayout { value_offset: 0, stride: align_up(align_up(size, 4) + 8, align) }
+    }
+    /// The layout of a `TMap`, a `TSet` of `TPair`s of a key and a value with the given sizes and alignments.
+    pub fn map(key_size: u64, key_align: u64, value_size: u64, value_align: u64) -> Self {
+        let value_offset = align_up(key_size, value_align);
+        let pair_align = key_align.max(value_align);
+        let pair_size = align_up(value_offset + value_size, pair_align);
+        SetElementLayout { value_offset, ..SetElementLayout::set(pair_size, pair_align) }
+    }
+}
+
+/// Reads the `TSet` at `address` and checks that it is sane, like [`read_tarray`].
+/// # Example:
+/// ```no_run
+/// # use dumpspace_api::{MemoryReader, read_tset};
+/// # fn example(reader: &impl MemoryReader, set_address: u64) -> Result<(), String> {
+/// // A `TSet<int32>`.
+/// for id in read_tset(reader, set_address)?.read_elements::<i32>(reader)? {
+///     println!("{}", id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_tset(reader: &impl MemoryReader, address: u64) -> Result<TSetView, String> {
+    let elements = read_tarray(reader, address).map_err(|e| format!("Invalid TSet at 0x{:x}: {}", address, e))?;
+    if elements.num > MAX_TSET_SLOTS {
+        return Err(format!("Invalid TSet at 0x{:x}: too many slots: {}", address, elements.num));
+    }
+    let flags = address.wrapping_add(ALLOCATION_FLAGS_OFFSET);
+    let secondary: u64 = reader.read_value(flags.wrapping_add(INLINE_FLAG_WORDS * 4))?;
+    let (num_bits, num_free): (i32, i32) = (reader.read_value(flags.wrapping_add(0x18))?, reader.read_value(flags.wrapping_add(0x24))?);
+    if num_bits < elements.num || num_free < 0 || num_free > elements.num {
+        return Err(format!("Invalid TSet at 0x{:x}: Num {} NumBits {} NumFree {}", address, elements.num, num_bits, num_free));
+    }
+    if secondary == 0 && elements.num as u64 > INLINE_FLAG_WORDS * 32 {
+        return Err(format!("Invalid TSet at 0x{:x}: {} slots without allocation flags", address, elements.num));
+    }
+    Ok(TSetView {
+        data: elements.data,
+        num: elements.num,
+        max: elements.max,
+        allocation_flags: if secondary == 0 { flags } else { secondary },
+        num_free,
+    })
+}
+
+/// Reads the `TMap` at `address`, which is laid out like a `TSet` of key-value pairs.
+/// # Example:
+/// ```no_run
+/// # use dumpspace_api::{DSAPI, MemoryReader, read_tmap};
+/// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, object: u64) -> Result<(), String> {
+/// // `TMap<FName, UObject*> AFortInventory::Items`
+/// let info = dsapi.get_member_offset("AFortInventory", "Items").unwrap();
+/// let layout = dsapi.get_set_element_layout("AFortInventory", "Items")?;
+/// for (key, value) in read_tmap(reader, object + info.offset as u64)?.pair_addresses(reader, layout)? {
+///     println!("{:?} -> 0x{:x}", reader.read_value::<[u32; 2]>(key)?, reader.read_ptr(value)?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_tmap(reader: &impl MemoryReader, address: u64) -> Result<TSetView, String> {
+    read_tset(reader, address).map_err(|e| e.replacen("TSet", "TMap", 1))
+}
+
+impl TSetView {
+    /// Number of elements, without the free slots.
+    pub fn len(&self) -> usize {
+        (self.num - self.num_free) as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the addresses of the allocated slots, skipping the free ones, for slots that are `stride` bytes apart.
+    /// Fails if the set has more than [`MAX_TSET_SLOTS`] slots or they reach past the end of the address space.
+    pub fn element_addresses(&self, reader: &impl MemoryReader, stride: u64) -> Result<Vec<u64>, String> {
+        if !(0..=MAX_TSET_SLOTS).contains(&self.num) || self.num > self.max {
+            return Err(format!("Invalid TSet at 0x{:x}: Num {} Max {}", self.data, self.num, self.max));
+        }
+        let slot_address = |index: usize| (index as u64).checked_mul(stride).and_then(|offset| self.data.checked_add(offset));
+        if self.num > 0 && slot_address(self.num as usize - 1).is_none() {
+            return Err(format!("TSet at 0x{:x} with {} slots is out of the address space", self.data, self.num));
+        }
+        let mut words = vec![0u8; (self.num as usize).div_ceil(32) * 4];
+        reader.read(self.allocation_flags, &mut words)?;
+        let allocated = |index: usize| words[index / 8] & (1 << (index % 8)) != 0;
+        Ok((0..self.num as usize).filter(|index| allocated(*index)).filter_map(slot_address).collect())
+    }
+    /// Reads all elements of a `TSet` of `T`s.
+    pub fn read_elements<T: Pod>(&self, reader: &impl MemoryReader) -> Result<Vec<T>, String> {
+        let layout = SetElementLayout::set(size_of::<T>() as u64, align_of::<T>() as u64);
+        self.element_addresses(reader, layout.stride)?.into_iter().map(|address| reader.read_value(address)).collect()
+    }
+    /// Returns the addresses of the key and the value of every pair of a `TMap`.
+    pub fn pair_addresses(&self, reader: &impl MemoryReader, layout: SetElementLayout) -> Result<Vec<(u64, u64)>, String> {
+        Ok(self.element_addresses(reader, layout.stride)?.into_iter().map(|key| (key, key.wrapping_add(layout.value_offset))).collect())
+    }
+    /// Reads all pairs of a `TMap` of `K`s to `V`s.
+    pub fn read_pairs<K: Pod, V: Pod>(&self, reader: &impl MemoryReader) -> Result<Vec<(K, V)>, String> {
+        let layout = SetElementLayout::map(size_of::<K>() as u64, align_of::<K>() as u64, size_of::<V>() as u64, align_of::<V>() as u64);
+        self.pair_addresses(reader, layout)?.into_iter()
+            .map(|(key, value)| Ok((reader.read_value(key)?, reader.read_value(value)?)))
+            .collect()
+    }
+}
+
+impl DSAPI {
+    /// Returns the layout of the slots of a `TSet` or `TMap` member, from the sizes and alignments of its element
+    /// types in the dump. Fails if the member isn't a `TSet` or `TMap` or the dump lacks the size of a type.
+    pub fn get_set_element_layout(&self, class_name: &str, member_name: &str) -> Result<SetElementLayout, String> {
+        let ty = self.get_member_type(class_name, member_name)
+            .ok_or_else(|| format!("Member type not found: {}::{}", class_name, member_name))?;
+        let layout = |index: usize| {
+            let arg = ty.template_args.get(index).ok_or_else(|| format!("{}::{} has no element type: {}", class_name, member_name, ty))?;
+            self.type_layout(arg).ok_or_else(|| format!("Size of {} not found", arg))
+        };
+        match ty.name.as_str() {
+            "TSet" => {
+                let (size, align) = layout(0)?;
+                Ok(SetElementLayout::set(size, align))
+            }
+            "TMap" => {
+                let ((key_size, key_align), (value_size, value_align)) = (layout(0)?, layout(1)?);
+                Ok(SetElementLayout::map(key_size, key_align, value_size, value_align))
+            }
+            _ => Err(format!("{}::{} is not a TSet or TMap: {}", class_name, member_name, ty)),
+        }
+    }
+}
+
+/// Longest `FString` (in UTF-16 code units, including the terminator) [`read_fstring`] accepts.
+/// Longer strings almost always mean the address was wrong.
+pub const MAX_FSTRING_LEN: usize = 0x10000;
+
+/// Offset of the display string (an `FString`) inside the `ITextData` an `FText` points to,
+/// in most UE4 and UE5 builds.
+pub const FTEXT_STRING_OFFSET: u64 = 0x28;
+
+/// Reads the `FString` at `address`: a `TArray` of UTF-16 code units ending with a null terminator.
+/// Fails if the array is invalid or longer than [`MAX_FSTRING_LEN`]. Invalid UTF-16 is replaced with `U+FFFD`.
+/// # Example:
+/// ```no_run
+/// # use dumpspace_api::{DSAPI, MemoryReader, read_fstring};
+/// # fn example(dsapi: &DSAPI, reader: &impl MemoryReader, player_state: u64) -> Result<(), String> {
+/// let name_offset = dsapi.get_member_offset("APlayerState", "PlayerNamePrivate").unwrap().offset as u64;
+/// println!("{}", read_fstring(reader, player_state + name_offset)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_fstring(reader: &impl MemoryReader, address: u64) -> Result<String, String> {
+    let array = read_tarray(reader, address)?;
+    if array.len() > MAX_FSTRING_LEN {
+        return Err(format!("FString at 0x{:x} is too long: {} characters", address, array.len()));
+    }
+    if array.is_empty() {
+        return Ok(String::new());
+    }
+    let mut bytes = vec![0u8; array.len() * 2];
+    reader.read(array.data, &mut bytes)?;
+    let units: Vec<u16> = bytes.chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .take_while(|unit| *unit != 0)
+        .collect();
+    Ok(String::from_utf16_lossy(&units))
+}
+
+/// Reads the display string of the `FText` at `address`, which points to an `ITextData` holding an `FString`
+/// `string_offset` bytes in. Pass [`FTEXT_STRING_OFFSET`] unless the game uses a different layout.
+pub fn read_ftext(reader: &impl MemoryReader, address: u64, string_offset: u64) -> Result<String, String> {
+    let text_data = reader.read_ptr(address)?;
+    if text_data == 0 {
+        return Ok(String::new());
+    }
+    read_fstring(reader, text_data.wrapping_add(string_offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::tests::Buffer;
+
+    #[test]
+    fn test_read_tarray() {
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x100] };
+        memory.write(0x1000, 0x1040_u64);
+        memory.write(0x1008, 3_i32);
+        memory.write(0x100c, 4_i32);
+        for (index, value) in [10_u32, 20, 30].into_iter().enumerate() {
+            memory.write(0x1040 + index as u64 * 4, value);
+        }
+
+        let array = read_tarray(&memory, 0x1000).unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.read_elements::<u32>(&memory).unwrap(), [10, 20, 30]);
+        assert_eq!(array.read_element::<u32>(&memory, 1), Ok(20));
+        assert!(array.read_element::<u32>(&memory, 3).is_err());
+        assert_eq!(array.element_address(2, 0x10), Some(0x1060));
+
+        memory.write(0x1008, 5_i32);
+        assert!(read_tarray(&memory, 0x1000).is_err());
+        memory.write(0x1000, 0_u64);
+        memory.write(0x1008, 1_i32);
+        assert!(read_tarray(&memory, 0x1000).is_err());
+
+        // Garbage from the target must not overflow address arithmetic.
+        assert!(read_tarray(&memory, u64::MAX - 4).is_err());
+        let garbage = TArrayView { data: u64::MAX - 8, num: 4, max: 4 };
+        assert_eq!(garbage.element_address(3, 8), None);
+        assert_eq!(garbage.element_addresses(8).last(), Some(0xf));
+        assert!(garbage.read_elements::<u64>(&memory).unwrap_err().contains("out of the address space"));
+        assert!(garbage.read_element::<u64>(&memory, 3).unwrap_err().contains("out of the address space"));
+    }
+
+    #[test]
+    fn test_read_tset() {
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x400] };
+        // TMap<int32, uint64> with slots 0 and 2 allocated and slot 1 free.
+        memory.write(0x1000, 0x1100_u64);
+        memory.write(0x1008, 3_i32);
+        memory.write(0x100c, 4_i32);
+        memory.write(0x1010, 0b101_u32);
+        memory.write(0x1028, 3_i32);
+        memory.write(0x1034, 1_i32);
+        let layout = SetElementLayout::map(4, 4, 8, 8);
+        assert_eq!(layout, SetElementLayout { value_offset: 8, stride: 0x18 });
+        memory.write(0x1100, 7_i32);
+        memory.write(0x1108, 70_u64);
+        memory.write(0x1100 + 2 * 0x18, 9_i32);
+        memory.write(0x1108 + 2 * 0x18, 90_u64);
+
+        let map = read_tmap(&memory, 0x1000).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.read_pairs::<i32, u64>(&memory).unwrap(), [(7, 70), (9, 90)]);
+        assert_eq!(map.pair_addresses(&memory, layout).unwrap()[1], (0x1130, 0x1138));
+
+        // The same slots as a TSet<int32>: 4 bytes of element and 8 of hash chain.
+        assert_eq!(SetElementLayout::set(4, 4).stride, 0xc);
+        let set = read_tset(&memory, 0x1000).unwrap();
+        assert_eq!(set.element_addresses(&memory, 0xc).unwrap(), [0x1100, 0x1118]);
+
+        // Allocation flags moved to the heap.
+        memory.write(0x1020, 0x1200_u64);
+        memory.write(0x1200, 0b010_u32);
+        assert_eq!(read_tset(&memory, 0x1000).unwrap().element_addresses(&memory, 0xc).unwrap(), [0x110c]);
+
+        memory.write(0x1034, 4_i32);
+        assert!(read_tmap(&memory, 0x1000).unwrap_err().starts_with("Invalid TMap"));
+
+        // Garbage from the target must neither overflow nor allocate the slot count it claims.
+        memory.write(0x1034, 0_i32);
+        memory.write(0x1008, i32::MAX);
+        memory.write(0x100c, i32::MAX);
+        memory.write(0x1028, i32::MAX);
+        assert!(read_tset(&memory, 0x1000).unwrap_err().contains("too many slots"));
+        let garbage = TSetView { data: 0x1100, num: i32::MAX, max: i32::MAX, allocation_flags: 0x1010, num_free: 0 };
+        assert!(garbage.element_addresses(&memory, 0xc).is_err());
+        let garbage = TSetView { data: u64::MAX - 0x10, num: 3, max: 4, allocation_flags: 0x1010, num_free: 0 };
+        assert!(garbage.element_addresses(&memory, 0xc).unwrap_err().contains("out of the address space"));
+        assert!(read_tset(&memory, u64::MAX - 4).is_err());
+
+        let mut dsapi = crate::tests::fixture_dsapi();
+        dsapi.load_blob(crate::BlobKind::Classes, r#"{"data":[{"AInventory":[
+            {"Items":[["TMap","C","",[["FName","S","",[]],["UObject","C","*",[]]]],40,80,1]},
+            {"Keys":[["TSet","C","",[["FKey","S","",[]]]],120,80,1]}
+        ]},{"FKey":[{"__MDKClassSize":12},{"X":[["float","D","",[]],0,4,1]},{"Y":[["int16","D","",[]],4,2,1]}]}],"updated_at":"0","version":10202}"#).unwrap();
+        assert_eq!(dsapi.get_set_element_layout("AInventory", "Items"), Ok(SetElementLayout { value_offset: 8, stride: 0x18 }));
+        assert_eq!(dsapi.get_set_element_layout("AInventory", "Keys"), Ok(SetElementLayout { value_offset: 0, stride: 0x14 }));
+        assert!(dsapi.get_set_element_layout("UWorld", "OwningGameInstance").unwrap_err().contains("not a TSet or TMap"));
+    }
+
+    #[test]
+    fn test_read_fstring() {
+        let mut memory = Buffer { base: 0x1000, bytes: vec![0; 0x100] };
+        for (index, unit) in "Ninja\0".encode_utf16().enumerate() {
+            memory.write(0x1080 + index as u64 * 2, unit);
+        }
+        // FText at 0x1000 -> ITextData at 0x1020 -> FString at 0x1048
+        memory.write(0x1000, 0x1020_u64);
+        memory.write(0x1020 + FTEXT_STRING_OFFSET, 0x1080_u64);
+        memory.write(0x1020 + FTEXT_STRING_OFFSET + 8, 6_i32);
+        memory.write(0x1020 + FTEXT_STRING_OFFSET + 12, 8_i32);
+
+        assert_eq!(read_fstring(&memory, 0x1048), Ok("Ninja".to_string()));
+        assert_eq!(read_ftext(&memory, 0x1000, FTEXT_STRING_OFFSET), Ok("Ninja".to_string()));
+        assert_eq!(read_fstring(&memory, 0x10c0), Ok(String::new()));
+
+        memory.write(0x1048 + 8, MAX_FSTRING_LEN as i32 + 1);
+        memory.write(0x1048 + 12, MAX_FSTRING_LEN as i32 + 1);
+        assert!(read_fstring(&memory, 0x1048).is_err());
+
+        // A garbage text data pointer fails to read instead of overflowing.
+        memory.write(0x1000, u64::MAX - 8);
+        assert!(read_ftext(&memory, 0x1000, FTEXT_STRING_OFFSET).is_err());
+    }
+}
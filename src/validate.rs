@@ -0,0 +1,135 @@
+use crate::{DSAPI, Diagnostic};
+
+/// Global offsets at or above this are absolute addresses rather than offsets from the module base.
+const UNREBASED_THRESHOLD: u64 = 0x1_0000_0000;
+
+/// The result of [`DSAPI::validate`].
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+/// An inconsistency found by [`DSAPI::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// The member ends after the end of its class.
+    MemberOutOfBounds { class: String, member: String, end: i64, class_size: i32 },
+    /// The class inherits from a class that is not in the dump.
+    MissingSuperClass { class: String, super_class: String },
+    /// The enum has more than one name for the value.
+    DuplicateEnumValue { enum_name: String, value: i64 },
+    /// The global offset looks like an absolute address instead of an offset from the module base.
+    UnrebasedOffset { name: String, offset: u64 },
+}
+
+impl ValidationReport {
+    /// Returns `true` if no issues were found.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ValidationIssue::MemberOutOfBounds { class, member, end, class_size } => {
+                write!(f, "{}::{} ends at 0x{:x}, after the class size 0x{:x}", class, member, end, class_size)
+            }
+            ValidationIssue::MissingSuperClass { class, super_class } => write!(f, "{} inherits from missing class {}", class, super_class),
+            ValidationIssue::DuplicateEnumValue { enum_name, value } => write!(f, "{} has several names for {}", enum_name, value),
+            ValidationIssue::UnrebasedOffset { name, offset } => write!(f, "{} = 0x{:x} looks like an absolute address", name, offset),
+        }
+    }
+}
+
+impl DSAPI {
+    /// Cross-checks the dump for inconsistencies: members that extend past their class size, super classes
+    /// that are not in the dump, enums with duplicate values and global offsets that were not rebased.
+    /// Issues are sorted by kind, then by name.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let report = dsapi.validate();
+    /// for issue in &report.issues {
+    ///     eprintln!("{}", issue);
+    /// }
+    /// assert!(report.is_clean());
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let mut out_of_bounds = Vec::new();
+        for (class, member, info) in self.members() {
+            let Some(class_size) = self.get_class_size(class) else { continue };
+            let end = info.offset + info.size;
+            if end > class_size as i64 {
+                out_of_bounds.push(ValidationIssue::MemberOutOfBounds { class: class.to_string(), member: member.to_string(), end, class_size });
+            }
+        }
+
+        let mut missing_supers = Vec::new();
+        for (class, supers) in &self.class_super_map {
+            for super_class in supers {
+                if !self.class_super_map.contains_key(super_class) && !self.class_size_map.contains_key(super_class) {
+                    missing_supers.push(ValidationIssue::MissingSuperClass {
+                        class: self.names.resolve(*class).to_string(),
+                        super_class: self.names.resolve(*super_class).to_string(),
+                    });
+                }
+            }
+        }
+
+        let duplicate_enum_values: Vec<_> = self.diagnostics.iter()
+            .filter_map(|diagnostic| match diagnostic {
+                Diagnostic::DuplicateEnumValue { enum_name, value, .. } => {
+                    Some(ValidationIssue::DuplicateEnumValue { enum_name: enum_name.clone(), value: *value })
+                }
+                _ => None,
+            })
+            .collect();
+
+        let unrebased: Vec<_> = self.offset_map.iter()
+            .filter(|(_, offset)| **offset >= UNREBASED_THRESHOLD)
+            .map(|(name, offset)| ValidationIssue::UnrebasedOffset { name: name.clone(), offset: *offset })
+            .collect();
+
+        let key = |issue: &ValidationIssue| match issue {
+            ValidationIssue::MemberOutOfBounds { class, member, .. } => (class.clone(), member.clone()),
+            ValidationIssue::MissingSuperClass { class, super_class } => (class.clone(), super_class.clone()),
+            ValidationIssue::DuplicateEnumValue { enum_name, value } => (enum_name.clone(), format!("{:020}", value)),
+            ValidationIssue::UnrebasedOffset { name, .. } => (name.clone(), String::new()),
+        };
+        let mut issues = Vec::new();
+        for mut group in [out_of_bounds, missing_supers, duplicate_enum_values, unrebased] {
+            group.sort_by_key(key);
+            issues.extend(group);
+        }
+        ValidationReport { issues }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlobKind, parse_blob};
+
+    #[test]
+    fn test_validate() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        assert!(dsapi.validate().is_clean(), "{:?}", dsapi.validate());
+
+        let classes = r#"{"data":[{"ABroken":[{"__InheritInfo":["AMissing","UObject"]},{"__MDKClassSize":16},{"Value":[["int32","D","",[]],14,4,1]}]}],"updated_at":"0","version":10202}"#;
+        dsapi.parse_class_info(BlobKind::Classes, parse_blob(classes.as_bytes()).unwrap()).unwrap();
+        let enums = r#"{"data":[{"EDup":[[{"EDup__A":0},{"EDup__B":0}],"uint8"]}],"updated_at":"0","version":10202}"#;
+        dsapi.parse_enum_info(parse_blob(enums.as_bytes()).unwrap());
+        dsapi.offset_map.insert("OFFSET_ABSOLUTE".to_string(), 0x7ff6_1494_2840);
+
+        let report = dsapi.validate();
+        assert_eq!(report.issues, [
+            ValidationIssue::MemberOutOfBounds { class: "ABroken".to_string(), member: "Value".to_string(), end: 18, class_size: 16 },
+            ValidationIssue::MissingSuperClass { class: "ABroken".to_string(), super_class: "AMissing".to_string() },
+            ValidationIssue::DuplicateEnumValue { enum_name: "EDup".to_string(), value: 0 },
+            ValidationIssue::UnrebasedOffset { name: "OFFSET_ABSOLUTE".to_string(), offset: 0x7ff6_1494_2840 },
+        ]);
+    }
+}
@@ -0,0 +1,116 @@
+//! JavaScript bindings for `wasm32-unknown-unknown`, enabled with the `wasm` feature.
+//! The blocking HTTP client isn't available in browsers, so blobs are downloaded with `fetch`
+//! and decompressed in Rust.
+//! ```js
+//! import init, { DSAPI } from "./pkg/dumpspace_api.js";
+//! await init();
+//! const dsapi = await DSAPI.load("6b77eceb");
+//! console.log(dsapi.getMemberOffset("UWorld", "OwningGameInstance")?.offset);
+//! ```
+
+use js_sys::{Promise, Uint8Array};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::{BlobKind, DEFAULT_BASE_URL, DSAPI, GameList, OffsetInfo};
+
+#[wasm_bindgen]
+extern "C" {
+    /// The global `fetch`, available in windows and workers alike.
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_url(url: &str) -> Promise;
+}
+
+async fn fetch_bytes(url: &str) -> Result<Vec<u8>, String> {
+    let response = JsFuture::from(fetch_url(url)).await
+        .map_err(|e| format!("Failed to fetch URL {}: {:?}", url, e))?;
+    let response: web_sys::Response = response.dyn_into()
+        .map_err(|_| format!("Failed to fetch URL {}: not a Response", url))?;
+    if !response.ok() {
+        return Err(format!("Request failed with status: {}", response.status()));
+    }
+    let buffer = response.array_buffer().map_err(|e| format!("Failed to read response: {:?}", e))?;
+    let buffer = JsFuture::from(buffer).await.map_err(|e| format!("Failed to read response: {:?}", e))?;
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
+
+/// A downloaded dump, exported to JavaScript as `DSAPI`.
+#[wasm_bindgen(js_name = DSAPI)]
+pub struct WasmDsApi {
+    inner: DSAPI,
+}
+
+/// The offset info of a class member, exported to JavaScript as `OffsetInfo`.
+#[wasm_bindgen(js_name = OffsetInfo)]
+#[derive(Debug, Clone, Copy)]
+pub struct WasmOffsetInfo {
+    pub offset: i64,
+    pub size: i64,
+    #[wasm_bindgen(js_name = isBit)]
+    pub is_bit: bool,
+    #[wasm_bindgen(js_name = bitOffset)]
+    pub bit_offset: i32,
+}
+
+impl From<OffsetInfo> for WasmOffsetInfo {
+    fn from(info: OffsetInfo) -> Self {
+        WasmOffsetInfo { offset: info.offset, size: info.size, is_bit: info.is_bit, bit_offset: info.bit_offset }
+    }
+}
+
+#[wasm_bindgen(js_class = DSAPI)]
+impl WasmDsApi {
+    /// Downloads the game list and the dump of a game, from `base_url` or the dumpspace server.
+    pub async fn load(game_id: String, base_url: Option<String>) -> Result<WasmDsApi, JsError> {
+        let base_url = base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/').to_string();
+        let game_list = fetch_bytes(&format!("{}/Games/GameList.json", base_url)).await
+            .map_err(|e| JsError::new(&format!("Failed to fetch game list: {}", e)))?;
+        let game_list: GameList = serde_json::from_slice(&game_list)
+            .map_err(|e| JsError::new(&format!("Failed to parse JSON: {}", e)))?;
+        let game = game_list.get_game_by_hash(&game_id)
+            .ok_or_else(|| JsError::new(&format!("Game not found: {}", game_id)))?
+            .clone();
+
+        let options = crate::DownloadOptions { base_url, ..Default::default() };
+        let mut inner = DSAPI::empty(game, None, game_list, options);
+        for blob in BlobKind::ALL {
+            let url = inner.options.blob_url(&inner.engine, &inner.location, blob);
+            let compressed = fetch_bytes(&url).await
+                .map_err(|e| JsError::new(&format!("Failed to download {}: {}", blob.file_name(), e)))?;
+            let decoder = crate::decompress::BlobDecoder::new(compressed.as_slice()).map_err(|e| JsError::new(&e.to_string()))?;
+            inner.ingest_blob(blob, decoder).map_err(|e| JsError::new(&e))?;
+        }
+        inner.diagnose_key_collisions();
+        inner.downloaded_at = inner.game.uploaded;
+        Ok(WasmDsApi { inner })
+    }
+    #[wasm_bindgen(js_name = getMemberOffset)]
+    pub fn get_member_offset(&self, class_name: &str, member_name: &str) -> Option<WasmOffsetInfo> {
+        self.inner.get_member_offset(class_name, member_name).map(WasmOffsetInfo::from)
+    }
+    #[wasm_bindgen(js_name = getClassSize)]
+    pub fn get_class_size(&self, class_name: &str) -> Option<i32> {
+        self.inner.get_class_size(class_name)
+    }
+    #[wasm_bindgen(js_name = getEnumName)]
+    pub fn get_enum_name(&self, enum_name: &str, enum_value: i64) -> Option<String> {
+        self.inner.get_enum_name(enum_name, enum_value)
+    }
+    /// Returns a global offset, e.g. `OFFSET_GWORLD`, as a `BigInt`.
+    #[wasm_bindgen(js_name = getOffset)]
+    pub fn get_offset(&self, offset_name: &str) -> Option<u64> {
+        self.inner.get_offset(offset_name)
+    }
+    #[wasm_bindgen(js_name = getSuperClasses)]
+    pub fn get_super_classes(&self, class_name: &str) -> Option<Vec<String>> {
+        self.inner.get_super_classes(class_name)
+    }
+    /// The whole parsed dump as normalized JSON, see `DSAPI::export_json`.
+    #[wasm_bindgen(js_name = exportJson)]
+    pub fn export_json(&self) -> Result<String, JsError> {
+        let mut json = Vec::new();
+        self.inner.export_json(&mut json).map_err(|e| JsError::new(&e))?;
+        String::from_utf8(json).map_err(|e| JsError::new(&e.to_string()))
+    }
+}
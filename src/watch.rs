@@ -0,0 +1,218 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::{DSAPI, DumpDiff, Game, GameList, SharedDsApi};
+
+/// Handle to a background thread started with [`DSAPI::watch`] that keeps a `DSAPI` up to date.
+/// The thread is stopped when the handle is dropped.
+#[derive(Debug)]
+pub struct Watcher {
+    api: SharedDsApi,
+    poller: Poller,
+}
+
+impl Watcher {
+    pub(crate) fn spawn<F>(api: SharedDsApi, interval: Duration, mut callback: F) -> Self
+    where
+        F: FnMut(&DSAPI, &DumpDiff) + Send + 'static,
+    {
+        let thread_api = api.clone();
+        let poller = Poller::spawn(interval, move || {
+            let current = thread_api.load();
+            if let Ok(Some(refreshed)) = current.fetch_update() {
+                let diff = current.diff(&refreshed);
+                thread_api.store(refreshed);
+                callback(&thread_api.load(), &diff);
+            }
+        });
+        Watcher { api, poller }
+    }
+    /// The watched `DSAPI`. Lookups through it always see the newest applied dump.
+    pub fn api(&self) -> SharedDsApi {
+        self.api.clone()
+    }
+    /// Stops the background thread and waits for it to exit.
+    pub fn stop(mut self) {
+        self.poller.shutdown();
+    }
+}
+
+/// Handle to a background thread started with [`DSAPI::on_new_dump`] that watches the game list for new uploads.
+/// The thread is stopped when the handle is dropped.
+#[derive(Debug)]
+pub struct DumpNotifier {
+    poller: Poller,
+}
+
+impl DumpNotifier {
+    /// Stops the background thread and waits for it to exit.
+    pub fn stop(mut self) {
+        self.poller.shutdown();
+    }
+}
+
+/// A background thread that calls a function every interval until it is stopped or dropped.
+#[derive(Debug)]
+struct Poller {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Poller {
+    fn spawn(interval: Duration, mut tick: impl FnMut() + Send + 'static) -> Self {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = std::thread::spawn(move || loop {
+            match stopped.recv_timeout(interval) {
+                Err(RecvTimeoutError::Timeout) => tick(),
+                _ => return,
+            }
+        });
+        Poller { stop: Some(stop), thread: Some(thread) }
+    }
+    fn shutdown(&mut self) {
+        // Dropping the sender wakes the thread up immediately.
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Poller {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl DSAPI {
+    /// Moves the `DSAPI` into a background thread that checks for a newer dump every `interval`.
+    /// When one is found it is downloaded and parsed without blocking readers, swapped in,
+    /// and `callback` is called with the new data and the [`DumpDiff`] against the previous dump.
+    /// Failed checks (e.g. the server being unreachable) are silently retried on the next interval.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// use std::time::Duration;
+    /// let mut dsapi = DSAPI::new("6b77eceb", None);
+    /// dsapi.download_content().unwrap();
+    /// let watcher = dsapi.watch(Duration::from_secs(600), |_, diff| {
+    ///     println!("New dump, {} members changed", diff.members.len());
+    /// });
+    /// println!("{:?}", watcher.api().get_offset("OFFSET_GWORLD"));
+    /// ```
+    pub fn watch<F>(self, interval: Duration, callback: F) -> Watcher
+    where
+        F: FnMut(&DSAPI, &DumpDiff) + Send + 'static,
+    {
+        Watcher::spawn(SharedDsApi::new(self), interval, callback)
+    }
+    /// Checks the game list every `interval` and calls `callback` with the game whenever a dump newer than
+    /// the one this `DSAPI` was created with is uploaded. Only the game list is downloaded, so this is
+    /// cheap enough to leave running, e.g. to regenerate offsets after a patch. `download_content` is not required.
+    /// Failed checks (e.g. the server being unreachable) are silently retried on the next interval.
+    /// # Example:
+    /// ```no_run
+    /// use dumpspace_api::DSAPI;
+    /// use std::time::Duration;
+    /// let dsapi = DSAPI::builder().game_id("6b77eceb").build().unwrap();
+    /// let _notifier = dsapi.on_new_dump(Duration::from_secs(300), |game| {
+    ///     println!("New dump of {} uploaded at {}", game.name, game.uploaded);
+    /// });
+    /// std::thread::park();
+    /// ```
+    pub fn on_new_dump<F>(&self, interval: Duration, mut callback: F) -> DumpNotifier
+    where
+        F: FnMut(&Game) + Send + 'static,
+    {
+        let (options, hash) = (self.options.clone(), self.game.hash.clone());
+        let mut last_uploaded = self.game.uploaded;
+        let poller = Poller::spawn(interval, move || {
+            let Ok(game_list) = GameList::fetch(&options) else {
+                return;
+            };
+            if let Some(game) = game_list.get_game_by_hash(&hash).filter(|game| game.uploaded > last_uploaded) {
+                last_uploaded = game.uploaded;
+                callback(game);
+            }
+        });
+        DumpNotifier { poller }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_on_new_dump() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let uploaded = Arc::new(AtomicU64::new(1718035200));
+        let server_uploaded = uploaded.clone();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let list = format!(r#"{{"games":[{{"hash":"fixture","name":"Fixture","engine":"","location":"","uploaded":{},"uploader":{{"name":"","link":""}}}}]}}"#, server_uploaded.load(Ordering::SeqCst));
+                let _ = request.respond(tiny_http::Response::from_string(list));
+            }
+        });
+
+        let mut dsapi = crate::tests::fixture_dsapi();
+        dsapi.options.base_url = format!("http://127.0.0.1:{}", port);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let callback_seen = seen.clone();
+        let notifier = dsapi.on_new_dump(Duration::from_millis(10), move |game| callback_seen.lock().unwrap().push(game.uploaded));
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(seen.lock().unwrap().is_empty());
+        uploaded.store(1718035201, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(200));
+        notifier.stop();
+        assert_eq!(*seen.lock().unwrap(), [1718035201]);
+    }
+
+    #[test]
+    fn test_watch() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        dsapi.options.base_url = crate::tests::fixture_server();
+        dsapi.offset_map.insert("OFFSET_GWORLD".to_string(), 0x1000);
+        let diffs = Arc::new(Mutex::new(Vec::new()));
+        let callback_diffs = diffs.clone();
+        let watcher = dsapi.watch(Duration::from_millis(10), move |api, diff| {
+            callback_diffs.lock().unwrap().push((api.get_offset("OFFSET_GWORLD"), diff.offsets.clone()));
+        });
+        for _ in 0..200 {
+            if !diffs.lock().unwrap().is_empty() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        // A few more ticks, which must not apply the same dump again.
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(watcher.api().get_offset("OFFSET_GWORLD"), Some(0x14942840));
+        // The callback holds the other reference, it is dropped once the thread exited.
+        watcher.stop();
+        assert_eq!(Arc::strong_count(&diffs), 1);
+
+        let diffs = diffs.lock().unwrap();
+        assert_eq!(diffs.len(), 1);
+        let (offset, changes) = &diffs[0];
+        assert_eq!(*offset, Some(0x14942840));
+        assert_eq!(changes.len(), 1);
+        assert_eq!((changes[0].name.as_str(), changes[0].old, changes[0].new), ("OFFSET_GWORLD", Some(0x1000), Some(0x14942840)));
+    }
+
+    #[test]
+    fn test_watcher_drop_joins() {
+        let mut dsapi = crate::tests::fixture_dsapi();
+        dsapi.options.base_url = crate::tests::fixture_server();
+        let marker = Arc::new(());
+        let callback_marker = marker.clone();
+        let watcher = dsapi.watch(Duration::from_secs(3600), move |_, _| {
+            let _ = &callback_marker;
+        });
+        drop(watcher);
+        assert_eq!(Arc::strong_count(&marker), 1);
+    }
+}
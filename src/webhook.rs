@@ -0,0 +1,143 @@
+//! Posting new dumps to Discord and Slack incoming webhooks.
+
+use serde_json::json;
+
+use crate::{DSAPIBuilder, DumpDiff, Game};
+use crate::builder::DownloadOptions;
+
+/// Discord rejects messages longer than this; Slack allows more, so the shorter limit is used for both.
+const MAX_MESSAGE_LEN: usize = 2000;
+
+/// Changes listed by name in the message before the rest are only counted.
+const LISTED_CHANGES: usize = 10;
+
+/// A Discord or Slack incoming webhook that is told about new dumps, e.g. from [`crate::DSAPI::on_new_dump`] or [`crate::DSAPI::watch`].
+/// The posted JSON has the message in both `content` (Discord) and `text` (Slack), the game in `game`,
+/// and the full [`DumpDiff`] in `diff` if there is one. Both services ignore the fields they don't know.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, Webhook};
+/// use std::time::Duration;
+/// let webhook = Webhook::new("https://discord.com/api/webhooks/...").unwrap();
+/// let dsapi = DSAPI::builder().game_id("6b77eceb").build().unwrap();
+/// let _notifier = dsapi.on_new_dump(Duration::from_secs(300), move |game| {
+///     if let Err(e) = webhook.notify(game, None) {
+///         eprintln!("{}", e);
+///     }
+/// });
+/// std::thread::park();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    url: String,
+    options: DownloadOptions,
+}
+
+impl Webhook {
+    /// Creates a webhook that posts with the timeout and retries of [`crate::configure`].
+    pub fn new(url: &str) -> Result<Self, String> {
+        Ok(Webhook { url: url.to_string(), options: DSAPIBuilder::configured_options()? })
+    }
+    /// Posts a message about the new dump of `game`, listing what changed if `diff` is given.
+    pub fn notify(&self, game: &Game, diff: Option<&DumpDiff>) -> Result<(), String> {
+        self.options.post_json(&self.url, &payload(game, diff).to_string())
+    }
+}
+
+/// The JSON body posted for a new dump.
+fn payload(game: &Game, diff: Option<&DumpDiff>) -> serde_json::Value {
+    let message = truncate(message(game, diff));
+    json!({
+        "content": message,
+        "text": message,
+        "game": { "hash": game.hash, "name": game.name, "engine": game.engine, "uploaded": game.uploaded },
+        "diff": diff,
+    })
+}
+
+fn message(game: &Game, diff: Option<&DumpDiff>) -> String {
+    let mut message = format!("New dump of {} ({}) uploaded at {}", game.name, game.hash, game.uploaded);
+    let Some(diff) = diff else {
+        return message;
+    };
+    message += &format!(
+        "\n{} members, {} class sizes and {} global offsets changed",
+        diff.members.len(),
+        diff.class_sizes.len(),
+        diff.offsets.len()
+    );
+    // Global offsets matter most to readers of the message, so they come first.
+    let changes = diff.offsets.iter()
+        .map(|change| format!("{}: {} -> {}", change.name, hex(change.old), hex(change.new)))
+        .chain(diff.class_sizes.iter().map(|change| format!("sizeof({}): {} -> {}", change.class, hex(change.old), hex(change.new))))
+        .chain(diff.members.iter().map(|change| {
+            let (old, new) = (change.old.as_ref().map(|info| info.offset), change.new.as_ref().map(|info| info.offset));
+            format!("{}::{}: {} -> {}", change.class, change.member, hex(old), hex(new))
+        }));
+    for change in changes.take(LISTED_CHANGES) {
+        message += "\n";
+        message += &change;
+    }
+    let total = diff.offsets.len() + diff.class_sizes.len() + diff.members.len();
+    if total > LISTED_CHANGES {
+        message += &format!("\n... and {} more", total - LISTED_CHANGES);
+    }
+    message
+}
+
+fn hex(value: Option<impl std::fmt::LowerHex>) -> String {
+    match value {
+        Some(value) => format!("0x{:x}", value),
+        None => "none".to_string(),
+    }
+}
+
+fn truncate(mut message: String) -> String {
+    if message.len() > MAX_MESSAGE_LEN {
+        let mut end = MAX_MESSAGE_LEN - 3;
+        while !message.is_char_boundary(end) {
+            end -= 1;
+        }
+        message.truncate(end);
+        message += "...";
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DsApiMock, OffsetInfo};
+
+    #[test]
+    fn test_webhook() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let received = std::thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let _ = request.respond(tiny_http::Response::empty(204));
+            body
+        });
+
+        let old = DsApiMock::new().with_offset("OFFSET_GWORLD", 0x1000).with_member("UWorld", "OwningGameInstance", 0x228, 8).build();
+        let new = DsApiMock::new().with_offset("OFFSET_GWORLD", 0x2000).with_member_info("UWorld", "OwningGameInstance", OffsetInfo::member(0x230, 8)).build();
+        let diff = old.diff(&new);
+        let game = Game::new("6b77eceb", "Fortnite", "Unreal-Engine-5", "Fortnite").with_uploaded(1718035200);
+        Webhook::new(&format!("http://127.0.0.1:{}/hook", port)).unwrap().notify(&game, Some(&diff)).unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&received.join().unwrap()).unwrap();
+        let content = body["content"].as_str().unwrap();
+        assert!(content.starts_with("New dump of Fortnite (6b77eceb)"));
+        assert!(content.contains("OFFSET_GWORLD: 0x1000 -> 0x2000"));
+        assert!(content.contains("UWorld::OwningGameInstance: 0x228 -> 0x230"));
+        assert_eq!(body["text"], body["content"]);
+        assert_eq!(body["game"]["hash"], "6b77eceb");
+        assert_eq!(body["diff"]["offsets"][0]["new"], 0x2000);
+
+        let unreachable = Webhook::new("http://127.0.0.1:9/hook").unwrap();
+        assert!(unreachable.notify(&game, None).is_err());
+        assert_eq!(truncate("é".repeat(MAX_MESSAGE_LEN)).len(), MAX_MESSAGE_LEN - 1);
+    }
+}
@@ -0,0 +1,167 @@
+//! [`MemoryReader`] for another process on Windows, through `ReadProcessMemory`.
+
+use windows_sys::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+use windows_sys::Win32::System::Diagnostics::Debug::ReadProcessMemory;
+use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, MODULEENTRY32W, Module32FirstW, Module32NextW, PROCESSENTRY32W, Process32FirstW,
+    Process32NextW, TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32, TH32CS_SNAPPROCESS,
+};
+use windows_sys::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ};
+
+use crate::{DSAPI, MemoryReader, detect};
+
+/// Reads the memory of a running process with `ReadProcessMemory`. Requires the `windows-reader` feature.
+/// # Example:
+/// ```no_run
+/// use dumpspace_api::{DSAPI, MemoryReader, WinProcessReader};
+/// let mut dsapi = DSAPI::new("6b77eceb", None);
+/// dsapi.download_content().unwrap();
+/// // Finds the game's process and sets the module base of `dsapi` to its executable.
+/// let reader = WinProcessReader::attach(&mut dsapi).unwrap();
+/// let world = reader.read_ptr(dsapi.get_offset_rebased("OFFSET_GWORLD").unwrap()).unwrap();
+/// let game_instance: u64 = dsapi.read_member(&reader, world, "UWorld", "OwningGameInstance").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct WinProcessReader {
+    handle: HANDLE,
+    pid: u32,
+}
+
+// SAFETY: a process handle can be used from any thread, and the reader only ever reads through it.
+unsafe impl Send for WinProcessReader {}
+unsafe impl Sync for WinProcessReader {}
+
+/// A Toolhelp snapshot, closed when dropped.
+struct Snapshot(HANDLE);
+
+impl Snapshot {
+    fn new(flags: u32, pid: u32) -> Result<Self, String> {
+        // SAFETY: plain FFI call, the handle is checked before use.
+        let handle = unsafe { CreateToolhelp32Snapshot(flags, pid) };
+        if handle == INVALID_HANDLE_VALUE {
+            return Err(format!("Failed to enumerate processes: {}", std::io::Error::last_os_error()));
+        }
+        Ok(Snapshot(handle))
+    }
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        // SAFETY: the handle is valid and owned by the snapshot.
+        unsafe { CloseHandle(self.0) };
+    }
+}
+
+/// Converts a NUL-terminated UTF-16 buffer.
+fn from_wide(wide: &[u16]) -> String {
+    let len = wide.iter().position(|c| *c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len])
+}
+
+/// Returns the IDs and executable names of all running processes.
+fn processes() -> Result<Vec<(u32, String)>, String> {
+    let snapshot = Snapshot::new(TH32CS_SNAPPROCESS, 0)?;
+    let mut entry = PROCESSENTRY32W { dwSize: size_of::<PROCESSENTRY32W>() as u32, ..Default::default() };
+    let mut processes = Vec::new();
+    // SAFETY: `entry` is initialized with its size as the API requires.
+    let mut found = unsafe { Process32FirstW(snapshot.0, &mut entry) } != 0;
+    while found {
+        processes.push((entry.th32ProcessID, from_wide(&entry.szExeFile)));
+        // SAFETY: as above.
+        found = unsafe { Process32NextW(snapshot.0, &mut entry) } != 0;
+    }
+    Ok(processes)
+}
+
+impl WinProcessReader {
+    /// Opens the process with the given ID for reading.
+    pub fn open(pid: u32) -> Result<Self, String> {
+        // SAFETY: plain FFI call, the handle is checked before use.
+        let handle = unsafe { OpenProcess(PROCESS_VM_READ | PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) };
+        if handle.is_null() {
+            return Err(format!("Failed to open process {}: {}", pid, std::io::Error::last_os_error()));
+        }
+        Ok(WinProcessReader { handle, pid })
+    }
+    /// Opens the first running process with the given executable name, e.g. `FortniteClient-Win64-Shipping.exe`.
+    /// The name is compared case-insensitively.
+    pub fn open_by_name(executable: &str) -> Result<Self, String> {
+        let (pid, _) = processes()?.into_iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(executable))
+            .ok_or_else(|| format!("Process not found: {}", executable))?;
+        WinProcessReader::open(pid)
+    }
+    /// Opens the running process of the game `dsapi` was created for, matching executable names like
+    /// [`GameList::find_game_by_executable`](crate::GameList::find_game_by_executable), and sets the
+    /// module base of `dsapi` to the process's executable so the `*_rebased` getters work right away.
+    pub fn attach(dsapi: &mut DSAPI) -> Result<Self, String> {
+        let (pid, _) = processes()?.into_iter()
+            .find(|(_, name)| detect::executable_matches(name, dsapi.game()))
+            .ok_or_else(|| format!("No running process found for {}", dsapi.game().name))?;
+        let reader = WinProcessReader::open(pid)?;
+        dsapi.set_module_base(reader.main_module_base()?);
+        Ok(reader)
+    }
+    /// Returns the ID of the process.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+    /// Returns the base address of a module loaded in the process, e.g. `GameAssembly.dll`.
+    /// The name is compared case-insensitively.
+    pub fn module_base(&self, module_name: &str) -> Result<u64, String> {
+        self.find_module(|name| name.eq_ignore_ascii_case(module_name))
+            .ok_or_else(|| format!("Module {} not found", module_name))
+    }
+    /// Returns the base address of the process's executable.
+    pub fn main_module_base(&self) -> Result<u64, String> {
+        // The executable is always the first module of a snapshot.
+        self.find_module(|_| true).ok_or_else(|| "Executable module not found".to_string())
+    }
+    fn find_module(&self, matches: impl Fn(&str) -> bool) -> Option<u64> {
+        let snapshot = Snapshot::new(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, self.pid).ok()?;
+        let mut entry = MODULEENTRY32W { dwSize: size_of::<MODULEENTRY32W>() as u32, ..Default::default() };
+        // SAFETY: `entry` is initialized with its size as the API requires.
+        let mut found = unsafe { Module32FirstW(snapshot.0, &mut entry) } != 0;
+        while found {
+            if matches(&from_wide(&entry.szModule)) {
+                return Some(entry.modBaseAddr as u64);
+            }
+            // SAFETY: as above.
+            found = unsafe { Module32NextW(snapshot.0, &mut entry) } != 0;
+        }
+        None
+    }
+}
+
+impl MemoryReader for WinProcessReader {
+    fn read(&self, address: u64, buf: &mut [u8]) -> Result<(), String> {
+        let mut read = 0;
+        // SAFETY: `buf` is valid for `buf.len()` bytes, the foreign address is checked by the kernel.
+        let ok = unsafe { ReadProcessMemory(self.handle, address as *const _, buf.as_mut_ptr().cast(), buf.len(), &mut read) } != 0;
+        if !ok || read != buf.len() {
+            return Err(format!("Failed to read 0x{:x}: {}", address, std::io::Error::last_os_error()));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for WinProcessReader {
+    fn drop(&mut self) {
+        // SAFETY: the handle is valid and owned by the reader.
+        unsafe { CloseHandle(self.handle) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_own_process() {
+        let value = 0x1234_5678_u32;
+        let reader = WinProcessReader::open(std::process::id()).unwrap();
+        assert_eq!(reader.read_value::<u32>(&value as *const u32 as u64), Ok(value));
+        assert_ne!(reader.main_module_base().unwrap(), 0);
+        assert!(reader.read_value::<u32>(0).is_err());
+    }
+}